@@ -0,0 +1,7 @@
+mod create_audit_log_event;
+mod create_audit_log_export;
+mod get_audit_log_export;
+
+pub use create_audit_log_event::*;
+pub use create_audit_log_export::*;
+pub use get_audit_log_export::*;
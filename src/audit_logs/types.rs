@@ -0,0 +1,5 @@
+mod audit_log_event;
+mod audit_log_export;
+
+pub use audit_log_event::*;
+pub use audit_log_export::*;
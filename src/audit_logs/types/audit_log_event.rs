@@ -0,0 +1,83 @@
+use std::net::IpAddr;
+
+use serde::Serialize;
+
+use crate::{Metadata, Timestamp};
+
+/// The entity that performed the action recorded by an [`AuditLogEvent`].
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditLogActor<'a> {
+    /// The unique identifier of the actor, e.g. a user ID.
+    pub id: &'a str,
+
+    /// The type of the actor, e.g. `user`.
+    #[serde(rename = "type")]
+    pub r#type: &'a str,
+
+    /// The display name of the actor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    /// Additional metadata about the actor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// A resource affected by the action recorded by an [`AuditLogEvent`].
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditLogTarget<'a> {
+    /// The unique identifier of the target.
+    pub id: &'a str,
+
+    /// The type of the target, e.g. `document`.
+    #[serde(rename = "type")]
+    pub r#type: &'a str,
+
+    /// The display name of the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    /// Additional metadata about the target.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The context in which the action recorded by an [`AuditLogEvent`] took place.
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditLogContext<'a> {
+    /// The IP address the action was performed from.
+    pub location: &'a IpAddr,
+
+    /// The user agent of the client that performed the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<&'a str>,
+}
+
+/// An action to be recorded in an organization's Audit Log.
+///
+/// [WorkOS Docs: Audit Log Event](https://workos.com/docs/reference/audit-logs/event)
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditLogEvent<'a> {
+    /// The action that was performed, e.g. `user.signed_in`.
+    pub action: &'a str,
+
+    /// The timestamp indicating when the action occurred.
+    pub occurred_at: &'a Timestamp,
+
+    /// The version of the event schema the event conforms to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+
+    /// The entity that performed the action.
+    pub actor: AuditLogActor<'a>,
+
+    /// The resources affected by the action.
+    pub targets: Vec<AuditLogTarget<'a>>,
+
+    /// The context in which the action took place.
+    pub context: AuditLogContext<'a>,
+
+    /// Additional metadata about the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
@@ -0,0 +1,100 @@
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{KnownOrUnknown, Timestamps};
+
+/// The ID of an [`AuditLogExport`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct AuditLogExportId(String);
+
+/// The state of an [`AuditLogExport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditLogExportState {
+    /// The export is being generated.
+    Pending,
+
+    /// The export is ready to be downloaded.
+    Ready,
+
+    /// The export could not be generated.
+    Error,
+}
+
+/// [WorkOS Docs: Audit Log Export](https://workos.com/docs/reference/audit-logs/export)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AuditLogExport {
+    /// The unique ID of the export.
+    pub id: AuditLogExportId,
+
+    /// The state of the export.
+    pub state: KnownOrUnknown<AuditLogExportState, String>,
+
+    /// The URL of the CSV file, once the export is ready.
+    ///
+    /// The URL is short-lived, so fetch the export again for a fresh URL if needed.
+    pub url: Option<Url>,
+
+    /// The timestamps for the export.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
+
+impl AuditLogExport {
+    /// Returns the URL of the CSV file if the export is ready.
+    pub fn ready_url(&self) -> Option<&Url> {
+        match self.state {
+            KnownOrUnknown::Known(AuditLogExportState::Ready) => self.url.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn export(state: &str, url: Option<&str>) -> AuditLogExport {
+        serde_json::from_value(json!({
+            "object": "audit_log_export",
+            "id": "audit_log_export_01GBZK5MP7TD1YCFQHFR22180V",
+            "state": state,
+            "url": url,
+            "created_at": "2022-09-02T17:14:57.094Z",
+            "updated_at": "2022-09-02T17:14:57.094Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_returns_the_url_once_ready() {
+        let export = export(
+            "ready",
+            Some("https://exports.audit-logs.com/audit-log-exports/export.csv"),
+        );
+
+        assert_eq!(
+            export.ready_url().map(Url::as_str),
+            Some("https://exports.audit-logs.com/audit-log-exports/export.csv")
+        )
+    }
+
+    #[test]
+    fn it_returns_no_url_while_pending() {
+        assert_eq!(export("pending", None).ready_url(), None)
+    }
+
+    #[test]
+    fn it_keeps_unknown_states() {
+        assert_eq!(
+            export("archived", None).state,
+            KnownOrUnknown::Unknown("archived".to_string())
+        )
+    }
+}
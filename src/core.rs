@@ -1,9 +1,68 @@
+mod api_error;
+mod audit;
+#[cfg(feature = "sso")]
+mod authorization_url;
+#[cfg(feature = "user-management")]
+mod batch;
+#[cfg(feature = "user-management")]
+mod concurrency;
+#[cfg(feature = "debug-curl")]
+mod curl;
+mod deprecation;
+#[cfg(not(target_arch = "wasm32"))]
+mod dry_run;
 mod error;
+mod healthcheck;
 mod http;
+#[cfg(not(target_arch = "wasm32"))]
+mod http_backend;
+mod metadata;
+#[cfg(not(target_arch = "wasm32"))]
+mod middleware;
+#[cfg(any(feature = "fga", feature = "organizations", feature = "vault"))]
+mod query;
+mod raw;
 mod response;
+mod retry;
+mod runtime;
+#[cfg(any(feature = "roles", feature = "jwt"))]
+mod stale_if_error;
+#[cfg(any(feature = "directory-sync", feature = "events"))]
+mod streaming;
 mod types;
 
+pub use api_error::*;
+pub use audit::*;
+#[cfg(feature = "sso")]
+pub(crate) use authorization_url::*;
+#[cfg(feature = "user-management")]
+pub use batch::*;
+#[cfg(feature = "user-management")]
+pub(crate) use concurrency::*;
+#[cfg(feature = "debug-curl")]
+pub use curl::*;
+pub(crate) use deprecation::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use dry_run::*;
 pub use error::*;
+pub use healthcheck::*;
 pub(crate) use http::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use http_backend::*;
+pub use metadata::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use middleware::*;
+#[cfg(any(feature = "fga", feature = "organizations", feature = "vault"))]
+pub(crate) use query::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use raw::*;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use raw::*;
 pub(crate) use response::*;
+pub use retry::*;
+pub use runtime::*;
+#[cfg(any(feature = "roles", feature = "jwt"))]
+pub(crate) use stale_if_error::*;
+#[cfg(any(feature = "directory-sync", feature = "events"))]
+pub(crate) use streaming::*;
 pub use types::*;
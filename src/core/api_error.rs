@@ -0,0 +1,165 @@
+use std::fmt;
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+/// An error response returned by the WorkOS API.
+///
+/// Returned as [`WorkOsError::Api`](crate::WorkOsError::Api) when a request fails with a
+/// standard WorkOS error body that the operation does not handle itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkOsApiError {
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+
+    /// The machine-readable error code, such as `invalid_request_parameters`.
+    pub code: Option<String>,
+
+    /// The human-readable description of the error.
+    pub message: Option<String>,
+
+    /// The individual errors that caused the request to fail, such as failed validations.
+    pub errors: Vec<WorkOsApiErrorDetail>,
+
+    /// The ID WorkOS assigned to the request, from the `X-Request-ID` header.
+    ///
+    /// Include this when contacting WorkOS support about the error.
+    pub request_id: Option<String>,
+}
+
+/// An individual error within a [`WorkOsApiError`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct WorkOsApiErrorDetail {
+    /// The machine-readable error code.
+    #[serde(default)]
+    pub code: Option<String>,
+
+    /// The request field the error relates to, if any.
+    #[serde(default)]
+    pub field: Option<String>,
+
+    /// The human-readable description of the error.
+    #[serde(default, alias = "error")]
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    code: Option<String>,
+    message: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+    #[serde(default)]
+    errors: Vec<WorkOsApiErrorDetail>,
+}
+
+impl WorkOsApiError {
+    /// Parses a WorkOS error body, returning `None` if the body is not one.
+    ///
+    /// Both the standard `code`/`message` shape and the OAuth `error`/`error_description`
+    /// shape are recognized.
+    pub(crate) fn from_body(
+        status: StatusCode,
+        request_id: Option<String>,
+        body: &str,
+    ) -> Option<Self> {
+        let body = serde_json::from_str::<ErrorBody>(body).ok()?;
+
+        let code = body.code.or(body.error);
+        let message = body.message.or(body.error_description);
+        if code.is_none() && message.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            status,
+            code,
+            message,
+            errors: body.errors,
+            request_id,
+        })
+    }
+}
+
+impl fmt::Display for WorkOsApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WorkOS API returned {}", self.status)?;
+        if let Some(code) = &self.code {
+            write!(f, " ({code})")?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, " [request ID: {request_id}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for WorkOsApiError {}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_a_standard_error_body() {
+        let body = json!({
+            "code": "invalid_request_parameters",
+            "message": "Validation failed",
+            "errors": [{ "code": "email_required", "field": "email" }]
+        })
+        .to_string();
+
+        let error = WorkOsApiError::from_body(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Some("req_123".into()),
+            &body,
+        )
+        .unwrap();
+
+        assert_eq!(error.code.as_deref(), Some("invalid_request_parameters"));
+        assert_eq!(error.message.as_deref(), Some("Validation failed"));
+        assert_eq!(
+            error.errors,
+            vec![WorkOsApiErrorDetail {
+                code: Some("email_required".to_string()),
+                field: Some("email".to_string()),
+                message: None,
+            }]
+        );
+        assert_eq!(
+            error.to_string(),
+            "WorkOS API returned 422 Unprocessable Entity (invalid_request_parameters): Validation failed [request ID: req_123]"
+        );
+    }
+
+    #[test]
+    fn it_parses_an_oauth_error_body() {
+        let body = json!({
+            "error": "invalid_grant",
+            "error_description": "The code has expired."
+        })
+        .to_string();
+
+        let error = WorkOsApiError::from_body(StatusCode::BAD_REQUEST, None, &body).unwrap();
+
+        assert_eq!(error.code.as_deref(), Some("invalid_grant"));
+        assert_eq!(error.message.as_deref(), Some("The code has expired."));
+    }
+
+    #[test]
+    fn it_ignores_bodies_that_are_not_workos_errors() {
+        assert_eq!(
+            WorkOsApiError::from_body(StatusCode::BAD_GATEWAY, None, "<html>Bad Gateway</html>"),
+            None
+        );
+        assert_eq!(
+            WorkOsApiError::from_body(StatusCode::BAD_REQUEST, None, "{}"),
+            None
+        );
+    }
+}
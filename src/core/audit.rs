@@ -0,0 +1,360 @@
+use std::error::Error as StdError;
+use std::io::Write;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use reqwest::{Request, Response, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::core::is_mutating;
+
+/// An error returned from an [`AuditSink`].
+pub type AuditSinkError = Box<dyn StdError + Send + Sync>;
+
+/// A record of a mutating call made to the WorkOS API through the SDK.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct AuditEntry {
+    /// The time at which the request was sent.
+    pub timestamp: DateTime<Utc>,
+
+    /// The HTTP method of the request.
+    pub method: String,
+
+    /// The path of the request.
+    pub path: String,
+
+    /// The IDs of the WorkOS entities referenced by the request.
+    pub entity_ids: Vec<String>,
+
+    /// The WorkOS request ID, if a response was received.
+    pub request_id: Option<String>,
+
+    /// The HTTP status of the response, if a response was received.
+    pub status: Option<u16>,
+
+    /// The caller-supplied actor responsible for the request.
+    pub actor: Option<String>,
+}
+
+/// A destination for [`AuditEntry`] records.
+///
+/// Install a sink with [`WorkOsBuilder::audit_sink`](crate::WorkOsBuilder::audit_sink) to
+/// record every mutating call made through the client. Failures to record an entry are logged
+/// and do not fail the underlying request.
+///
+/// Entries are recorded on Tokio's blocking thread pool, so sinks may perform blocking I/O
+/// such as writing to a file.
+pub trait AuditSink: Send + Sync + 'static {
+    /// Records the entry.
+    fn record(&self, entry: &AuditEntry) -> Result<(), AuditSinkError>;
+}
+
+/// An [`AuditSink`] that writes each entry to a writer as a line of JSON.
+///
+/// # Examples
+///
+/// ```
+/// use std::fs::OpenOptions;
+///
+/// use workos_sdk::{ApiKey, JsonLinesAuditSink, WorkOs};
+///
+/// # fn run() -> std::io::Result<()> {
+/// let file = OpenOptions::new()
+///     .create(true)
+///     .append(true)
+///     .open("workos-audit.jsonl")?;
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .audit_sink(JsonLinesAuditSink::new(file))
+///     .build()
+///     .unwrap();
+///
+/// let admin = workos.with_audit_actor("admin@example.com");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct JsonLinesAuditSink<W> {
+    writer: Mutex<W>,
+}
+
+impl<W> JsonLinesAuditSink<W>
+where
+    W: Write + Send + 'static,
+{
+    /// Returns a new [`JsonLinesAuditSink`] that writes to the provided writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    /// Consumes the sink and returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<W> AuditSink for JsonLinesAuditSink<W>
+where
+    W: Write + Send + 'static,
+{
+    fn record(&self, entry: &AuditEntry) -> Result<(), AuditSinkError> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+
+        let mut writer = self
+            .writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        writer.write_all(&line)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// An [`AuditEntry`] for a request that has not yet completed.
+pub(crate) struct PendingAuditEntry {
+    entry: AuditEntry,
+}
+
+impl PendingAuditEntry {
+    /// Returns a pending entry for the request, or `None` if the request is not mutating.
+    pub(crate) fn for_request(request: &Request, actor: Option<&str>) -> Option<Self> {
+        if !is_mutating(request) {
+            return None;
+        }
+
+        let mut entity_ids = request
+            .url()
+            .path_segments()
+            .into_iter()
+            .flatten()
+            .filter(|segment| is_workos_id(segment))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        if let Some(Value::Object(body)) = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .and_then(|bytes| serde_json::from_slice(bytes).ok())
+        {
+            for (key, value) in body {
+                if let Value::String(value) = value
+                    && (key == "id" || key.ends_with("_id"))
+                    && is_workos_id(&value)
+                    && !entity_ids.contains(&value)
+                {
+                    entity_ids.push(value);
+                }
+            }
+        }
+
+        Some(Self {
+            entry: AuditEntry {
+                timestamp: Utc::now(),
+                method: request.method().to_string(),
+                path: request.url().path().to_string(),
+                entity_ids,
+                request_id: None,
+                status: None,
+                actor: actor.map(str::to_string),
+            },
+        })
+    }
+
+    /// Completes the entry with the details of the response, if one was received.
+    pub(crate) fn complete(
+        mut self,
+        response: Option<&Response>,
+        status: Option<StatusCode>,
+    ) -> AuditEntry {
+        self.entry.request_id = response
+            .and_then(|response| response.headers().get("x-request-id"))
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        self.entry.status = response
+            .map(|response| response.status())
+            .or(status)
+            .map(|status| status.as_u16());
+        self.entry
+    }
+}
+
+/// Returns whether the value looks like a WorkOS ID, such as `user_01E4ZCR3C56J083X43JQXF3JK5`.
+pub(crate) fn is_workos_id(value: &str) -> bool {
+    value.rsplit_once('_').is_some_and(|(prefix, ulid)| {
+        !prefix.is_empty()
+            && prefix.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+            && ulid.len() == 26
+            && ulid
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{ApiKey, WorkOs};
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedBuffer {
+        fn lines(&self) -> Vec<Value> {
+            String::from_utf8(self.0.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect()
+        }
+    }
+
+    #[test]
+    fn it_recognizes_workos_ids() {
+        assert!(is_workos_id("user_01E4ZCR3C56J083X43JQXF3JK5"));
+        assert!(is_workos_id("org_membership_01EHZNVPK3SFK441A1RGBFSHRT"));
+        assert!(!is_workos_id("organization_memberships"));
+        assert!(!is_workos_id("user_management"));
+    }
+
+    #[tokio::test]
+    async fn it_records_mutating_requests_as_json_lines() {
+        let mut server = mockito::Server::new_async().await;
+        let buffer = SharedBuffer::default();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .audit_sink(JsonLinesAuditSink::new(buffer.clone()))
+            .build()
+            .unwrap()
+            .with_audit_actor("admin@example.com");
+
+        server
+            .mock(
+                "PUT",
+                "/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(200)
+            .with_header("X-Request-ID", "req_123")
+            .create_async()
+            .await;
+
+        server
+            .mock(
+                "GET",
+                "/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let url = workos
+            .base_url()
+            .join("/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5")
+            .unwrap();
+        workos
+            .send::<()>(workos.client().put(url.clone()).json(&json!({
+                "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                "first_name": "Marcelina"
+            })))
+            .await
+            .unwrap();
+        workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        let lines = buffer.lines();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["method"], json!("PUT"));
+        assert_eq!(
+            lines[0]["path"],
+            json!("/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert_eq!(
+            lines[0]["entity_ids"],
+            json!([
+                "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "org_01EHZNVPK3SFK441A1RGBFSHRT"
+            ])
+        );
+        assert_eq!(lines[0]["request_id"], json!("req_123"));
+        assert_eq!(lines[0]["status"], json!(200));
+        assert_eq!(lines[0]["actor"], json!("admin@example.com"));
+    }
+
+    #[tokio::test]
+    async fn it_does_not_record_authentication_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let buffer = SharedBuffer::default();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .audit_sink(JsonLinesAuditSink::new(buffer.clone()))
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let url = workos
+            .base_url()
+            .join("/user_management/authenticate")
+            .unwrap();
+        workos
+            .send::<()>(workos.client().post(url).json(&json!({
+                "grant_type": "authorization_code",
+                "code": "abc123"
+            })))
+            .await
+            .unwrap();
+
+        assert!(buffer.lines().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_records_requests_that_fail_to_send() {
+        let buffer = SharedBuffer::default();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url("http://127.0.0.1:1")
+            .unwrap()
+            .audit_sink(JsonLinesAuditSink::new(buffer.clone()))
+            .build()
+            .unwrap();
+
+        let url = workos
+            .base_url()
+            .join("/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .unwrap();
+        let _ = workos.send::<()>(workos.client().delete(url)).await;
+
+        let lines = buffer.lines();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["method"], json!("DELETE"));
+        assert_eq!(lines[0]["status"], Value::Null);
+        assert_eq!(lines[0]["actor"], Value::Null);
+    }
+}
@@ -0,0 +1,101 @@
+use url::{ParseError, Url};
+
+use crate::sso::CodeChallenge;
+
+/// A builder for OAuth 2.0 authorization URLs.
+///
+/// Shared by the SSO and User Management `get_authorization_url` operations, so that both
+/// encode and validate their parameters the same way.
+#[derive(Debug)]
+pub(crate) struct AuthorizationUrlBuilder {
+    url: Url,
+}
+
+impl AuthorizationUrlBuilder {
+    /// Returns a new builder for the authorization endpoint at `path`.
+    ///
+    /// Returns an error if the redirect URI is not an absolute URL.
+    pub(crate) fn new(
+        base_url: &Url,
+        path: &str,
+        client_id: &str,
+        redirect_uri: &str,
+    ) -> Result<Self, ParseError> {
+        Url::parse(redirect_uri)?;
+
+        let mut url = base_url.join(path)?;
+        url.query_pairs_mut()
+            .clear()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", client_id)
+            .append_pair("redirect_uri", redirect_uri);
+
+        Ok(Self { url })
+    }
+
+    /// Appends a parameter.
+    pub(crate) fn param(mut self, key: &str, value: &str) -> Self {
+        self.url.query_pairs_mut().append_pair(key, value);
+        self
+    }
+
+    /// Appends a parameter if a value is present.
+    pub(crate) fn optional_param(self, key: &str, value: Option<&str>) -> Self {
+        match value {
+            Some(value) => self.param(key, value),
+            None => self,
+        }
+    }
+
+    /// Appends the PKCE parameters if a code challenge is present.
+    pub(crate) fn code_challenge(self, code_challenge: Option<&CodeChallenge>) -> Self {
+        match code_challenge {
+            Some(CodeChallenge::S256(code_challenge)) => self
+                .param("code_challenge", code_challenge)
+                .param("code_challenge_method", "S256"),
+            None => self,
+        }
+    }
+
+    /// Returns the authorization URL.
+    pub(crate) fn build(self) -> Url {
+        self.url
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_encodes_parameters() {
+        let url = AuthorizationUrlBuilder::new(
+            &Url::parse("https://api.workos.com").unwrap(),
+            "/sso/authorize",
+            "client_123456789",
+            "https://your-app.com/callback?next=/dashboard",
+        )
+        .unwrap()
+        .param("connection", "conn_1234")
+        .optional_param("state", Some("a b&c"))
+        .optional_param("login_hint", None)
+        .build();
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback%3Fnext%3D%2Fdashboard&connection=conn_1234&state=a+b%26c"
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_relative_redirect_uri() {
+        let result = AuthorizationUrlBuilder::new(
+            &Url::parse("https://api.workos.com").unwrap(),
+            "/sso/authorize",
+            "client_123456789",
+            "/callback",
+        );
+
+        assert_eq!(result.unwrap_err(), ParseError::RelativeUrlWithoutBase)
+    }
+}
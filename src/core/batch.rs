@@ -0,0 +1,145 @@
+use std::future::Future;
+
+use reqwest::StatusCode;
+
+use crate::core::{retry_while_rate_limited, run_concurrently};
+use crate::{WorkOsError, WorkOsResult};
+
+/// The default number of requests a batch operation sends concurrently.
+pub(crate) const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+/// An item of a batch operation that could not be created.
+#[derive(Debug)]
+pub struct BatchFailure<E> {
+    /// The index of the item in the batch.
+    pub index: usize,
+
+    /// The error returned when creating the item.
+    pub error: WorkOsError<E>,
+}
+
+/// The outcome of a batch operation, such as
+/// [`ImportUsers`](crate::user_management::ImportUsers).
+#[derive(Debug)]
+pub struct BatchReport<T, E> {
+    /// The index of each item in the batch that was created, with the created value.
+    pub created: Vec<(usize, T)>,
+
+    /// The indices of the items in the batch that were not created because they already
+    /// exist.
+    pub conflicts: Vec<usize>,
+
+    /// The items that could not be created.
+    pub failures: Vec<BatchFailure<E>>,
+}
+
+impl<T, E> Default for BatchReport<T, E> {
+    fn default() -> Self {
+        Self {
+            created: Vec::new(),
+            conflicts: Vec::new(),
+            failures: Vec::new(),
+        }
+    }
+}
+
+impl<T, E> BatchReport<T, E> {
+    /// Returns whether every item was either created or already existed.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Runs the operation creating each item of a batch, with at most `concurrency` requests
+/// in flight, and reports the outcome of each.
+///
+/// Rate limited requests are retried after the delay requested by the API.
+pub(crate) async fn run_batch<O, Fut, T, E>(
+    operations: impl IntoIterator<Item = O>,
+    concurrency: Option<usize>,
+) -> BatchReport<T, E>
+where
+    O: FnMut() -> Fut,
+    Fut: Future<Output = WorkOsResult<T, E>>,
+{
+    let requests = operations
+        .into_iter()
+        .map(retry_while_rate_limited)
+        .collect::<Vec<_>>();
+    let results =
+        run_concurrently(requests, concurrency.unwrap_or(DEFAULT_BATCH_CONCURRENCY)).await;
+
+    let mut report = BatchReport::default();
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(created) => report.created.push((index, created)),
+            Err(error) if is_conflict(&error) => report.conflicts.push(index),
+            Err(error) => report.failures.push(BatchFailure { index, error }),
+        }
+    }
+
+    report
+}
+
+/// Returns whether the error means that the item already exists, from the status or
+/// error codes of the response.
+fn is_conflict<E>(error: &WorkOsError<E>) -> bool {
+    let is_conflict_code =
+        |code: &str| code.contains("already_exists") || code == "email_not_available";
+
+    match error {
+        WorkOsError::Api(err) => {
+            err.status == StatusCode::CONFLICT
+                || err.code.as_deref().is_some_and(is_conflict_code)
+                || err
+                    .errors
+                    .iter()
+                    .filter_map(|detail| detail.code.as_deref())
+                    .any(is_conflict_code)
+        }
+        WorkOsError::RequestError(err) => err.status() == Some(StatusCode::CONFLICT),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{RequestError, WorkOsApiError};
+
+    #[test]
+    fn it_detects_conflicts_from_the_status_or_error_code() {
+        assert!(is_conflict(&WorkOsError::<()>::Api(WorkOsApiError {
+            status: StatusCode::CONFLICT,
+            code: Some("conflict".to_string()),
+            message: Some("The resource already exists.".to_string()),
+            errors: Vec::new(),
+            request_id: None,
+        })));
+        assert!(is_conflict(&WorkOsError::<()>::Api(WorkOsApiError {
+            status: StatusCode::BAD_REQUEST,
+            code: Some("organization_membership_already_exists".to_string()),
+            message: Some("The user is already a member of the organization.".to_string()),
+            errors: Vec::new(),
+            request_id: None,
+        })));
+        assert!(is_conflict(&WorkOsError::<()>::RequestError(
+            RequestError::new("conflict").with_status(StatusCode::CONFLICT)
+        )));
+    }
+
+    #[test]
+    fn it_does_not_detect_conflicts_from_error_messages() {
+        assert!(!is_conflict(&WorkOsError::<()>::Api(WorkOsApiError {
+            status: StatusCode::BAD_REQUEST,
+            code: Some("invalid_request_parameters".to_string()),
+            message: Some("organization_membership_already_exists".to_string()),
+            errors: Vec::new(),
+            request_id: None,
+        })));
+        assert!(!is_conflict(&WorkOsError::<()>::RequestError(
+            RequestError::new("organization_membership_already_exists")
+                .with_status(StatusCode::BAD_REQUEST)
+        )));
+    }
+}
@@ -0,0 +1,55 @@
+use std::future::Future;
+
+use futures_util::stream::{self, StreamExt};
+
+/// Runs the futures with at most `limit` of them in flight at once, returning their outputs
+/// in the order the futures were provided.
+pub(crate) async fn run_concurrently<F: Future>(
+    futures: impl IntoIterator<Item = F>,
+    limit: usize,
+) -> Vec<F::Output> {
+    let mut outputs = stream::iter(futures.into_iter().enumerate())
+        .map(|(index, future)| async move { (index, future.await) })
+        .buffer_unordered(limit.max(1))
+        .collect::<Vec<_>>()
+        .await;
+
+    outputs.sort_by_key(|(index, _)| *index);
+    outputs.into_iter().map(|(_, output)| output).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_limits_the_number_of_futures_in_flight() {
+        let in_flight = Cell::new(0);
+        let max_in_flight = Cell::new(0);
+
+        let outputs = run_concurrently(
+            (0..10).map(|i| {
+                let in_flight = &in_flight;
+                let max_in_flight = &max_in_flight;
+
+                async move {
+                    in_flight.set(in_flight.get() + 1);
+                    max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+                    for _ in 0..(10 - i) {
+                        tokio::task::yield_now().await;
+                    }
+                    in_flight.set(in_flight.get() - 1);
+
+                    i * 2
+                }
+            }),
+            3,
+        )
+        .await;
+
+        assert_eq!(outputs, (0..10).map(|i| i * 2).collect::<Vec<_>>());
+        assert_eq!(max_in_flight.get(), 3);
+    }
+}
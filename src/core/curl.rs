@@ -0,0 +1,190 @@
+use reqwest::Request;
+use reqwest::header::AUTHORIZATION;
+use serde_json::Value;
+
+/// The placeholder that replaces the API key in rendered commands.
+const API_KEY_PLACEHOLDER: &str = "Bearer $WORKOS_API_KEY";
+
+/// The request body fields whose values are replaced with `<redacted>` in rendered commands.
+const SECRET_BODY_FIELDS: &[&str] = &[
+    "client_secret",
+    "code",
+    "code_verifier",
+    "password",
+    "password_hash",
+    "pending_authentication_token",
+    "refresh_token",
+    "token",
+];
+
+/// Renders a request as an equivalent `curl` command.
+///
+/// Secrets are redacted from the output so that it can be shared safely, for example
+/// with WorkOS support: the `Authorization` header refers to the `WORKOS_API_KEY`
+/// environment variable instead of the API key, and the values of secret fields in
+/// JSON request bodies (passwords, codes, tokens) are replaced with `<redacted>`.
+///
+/// When the `tracing` feature is also enabled, the command for every request sent by
+/// the client is logged at the `DEBUG` level with the `workos_sdk::curl` target.
+///
+/// # Examples
+///
+/// ```
+/// use workos_sdk::curl_command;
+///
+/// let request = reqwest::Client::new()
+///     .post("https://api.workos.com/organizations")
+///     .bearer_auth("sk_example_123456789")
+///     .json(&serde_json::json!({ "name": "Foo Corp" }))
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     curl_command(&request),
+///     "curl -X POST 'https://api.workos.com/organizations' \\\n  \
+///      -H 'authorization: Bearer $WORKOS_API_KEY' \\\n  \
+///      -H 'content-type: application/json' \\\n  \
+///      --data-raw '{\"name\":\"Foo Corp\"}'"
+/// );
+/// ```
+pub fn curl_command(request: &Request) -> String {
+    let mut parts = vec![format!(
+        "curl -X {} {}",
+        request.method(),
+        shell_quote(request.url().as_str())
+    )];
+
+    for (name, value) in request.headers() {
+        let value = if name == AUTHORIZATION {
+            API_KEY_PLACEHOLDER.to_string()
+        } else {
+            String::from_utf8_lossy(value.as_bytes()).into_owned()
+        };
+
+        parts.push(format!(
+            "-H {}",
+            shell_quote(&format!("{}: {}", name, value))
+        ));
+    }
+
+    if let Some(body) = request.body() {
+        match body.as_bytes() {
+            Some(bytes) => parts.push(format!("--data-raw {}", shell_quote(&redact_body(bytes)))),
+            None => parts.push("--data-raw '<non-replayable body>'".to_string()),
+        }
+    }
+
+    parts.join(" \\\n  ")
+}
+
+fn redact_body(bytes: &[u8]) -> String {
+    match serde_json::from_slice::<Value>(bytes) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SECRET_BODY_FIELDS.contains(&key.as_str()) && !value.is_null() {
+                    *value = Value::String("<redacted>".to_string());
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn log_curl_command(request: &Request) {
+    if tracing::enabled!(target: "workos_sdk::curl", tracing::Level::DEBUG) {
+        tracing::debug!(
+            target: "workos_sdk::curl",
+            command = curl_command(request),
+            "equivalent curl command"
+        );
+    }
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_curl_command(request: &Request) {
+    if log::log_enabled!(target: "workos_sdk::curl", log::Level::Debug) {
+        log::debug!(
+            target: "workos_sdk::curl",
+            "equivalent curl command command={}",
+            curl_command(request)
+        );
+    }
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+pub(crate) fn log_curl_command(request: &Request) {
+    let _ = request;
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_renders_a_get_request() {
+        let request = reqwest::Client::new()
+            .get("https://api.workos.com/organizations?limit=10")
+            .bearer_auth("sk_example_123456789")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            curl_command(&request),
+            "curl -X GET 'https://api.workos.com/organizations?limit=10' \\\n  -H 'authorization: Bearer $WORKOS_API_KEY'"
+        )
+    }
+
+    #[test]
+    fn it_redacts_secrets_from_the_body() {
+        let request = reqwest::Client::new()
+            .post("https://api.workos.com/user_management/authenticate")
+            .json(&json!({
+                "client_id": "client_123456789",
+                "client_secret": "sk_example_123456789",
+                "grant_type": "password",
+                "email": "marcelina@example.com",
+                "password": "i8uv6g34kd490s",
+            }))
+            .build()
+            .unwrap();
+
+        let command = curl_command(&request);
+
+        assert!(!command.contains("sk_example_123456789"));
+        assert!(!command.contains("i8uv6g34kd490s"));
+        assert!(command.contains(r#""client_secret":"<redacted>""#));
+        assert!(command.contains(r#""grant_type":"password""#));
+        assert!(command.contains(r#""email":"marcelina@example.com""#));
+    }
+
+    #[test]
+    fn it_escapes_single_quotes() {
+        let request = reqwest::Client::new()
+            .post("https://api.workos.com/organizations")
+            .json(&json!({ "name": "Foo's Corp" }))
+            .build()
+            .unwrap();
+
+        assert!(curl_command(&request).ends_with(r#"--data-raw '{"name":"Foo'\''s Corp"}'"#));
+    }
+}
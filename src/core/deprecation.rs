@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Method;
+use reqwest::header::{HeaderMap, LINK};
+use url::Url;
+
+use crate::core::{Instant, is_workos_id};
+
+/// The minimum time between two warnings for the same deprecated endpoint.
+const DEPRECATION_WARNING_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// When each deprecation warning was last emitted, keyed by endpoint.
+static LAST_WARNED: Mutex<BTreeMap<String, Instant>> = Mutex::new(BTreeMap::new());
+
+/// A deprecation signalled by the WorkOS API through response headers.
+///
+/// The API marks deprecated endpoints with the `Deprecation` header, and may include a
+/// `Sunset` header with the date after which the endpoint will stop working and a `Link`
+/// header pointing at migration documentation.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct DeprecationNotice {
+    /// The endpoint, with IDs replaced by placeholders, e.g. `GET /organizations/:id`.
+    pub endpoint: String,
+
+    /// The value of the `Deprecation` header.
+    pub deprecation: String,
+
+    /// The value of the `Sunset` header, if present.
+    pub sunset: Option<String>,
+
+    /// A link to migration documentation, if present.
+    pub migration_hint: Option<String>,
+}
+
+impl DeprecationNotice {
+    /// Returns the deprecation notice for a response, if its headers signal a deprecation.
+    pub(crate) fn from_response(method: &Method, url: &Url, headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        let deprecation = header("deprecation")?;
+
+        let path = url
+            .path()
+            .split('/')
+            .map(|segment| {
+                if is_workos_id(segment) {
+                    ":id"
+                } else {
+                    segment
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+
+        let migration_hint = headers
+            .get_all(LINK)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .find(|link| {
+                link.contains("rel=\"deprecation\"")
+                    || link.contains("rel=deprecation")
+                    || link.contains("rel=\"successor-version\"")
+            })
+            .and_then(|link| {
+                let start = link.find('<')? + 1;
+                let end = link.find('>')?;
+                link.get(start..end).map(str::to_string)
+            });
+
+        Some(Self {
+            endpoint: format!("{} {}", method, path),
+            deprecation,
+            sunset: header("sunset"),
+            migration_hint,
+        })
+    }
+}
+
+/// Returns whether a warning for the endpoint should be emitted, recording it if so.
+fn should_warn(endpoint: &str, now: Instant) -> bool {
+    let mut last_warned = LAST_WARNED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    match last_warned.get(endpoint) {
+        Some(last) if now.duration_since(*last) < DEPRECATION_WARNING_INTERVAL => false,
+        _ => {
+            last_warned.insert(endpoint.to_string(), now);
+            true
+        }
+    }
+}
+
+/// Emits a warning if the response signals a deprecation, at most once per endpoint per
+/// [`DEPRECATION_WARNING_INTERVAL`].
+pub(crate) fn warn_if_deprecated(method: &Method, url: &Url, headers: &HeaderMap) {
+    if let Some(notice) = DeprecationNotice::from_response(method, url, headers)
+        && should_warn(&notice.endpoint, Instant::now())
+    {
+        log_deprecation(&notice);
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn log_deprecation(notice: &DeprecationNotice) {
+    tracing::warn!(
+        target: "workos_sdk::deprecation",
+        endpoint = tracing::field::display(&notice.endpoint),
+        deprecation = tracing::field::display(&notice.deprecation),
+        sunset = notice.sunset.as_deref(),
+        migration_hint = notice.migration_hint.as_deref(),
+        "WorkOS endpoint is deprecated"
+    );
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+fn log_deprecation(notice: &DeprecationNotice) {
+    log::warn!(
+        target: "workos_sdk::deprecation",
+        "WorkOS endpoint is deprecated endpoint={} deprecation={} sunset={:?} migration_hint={:?}",
+        notice.endpoint,
+        notice.deprecation,
+        notice.sunset,
+        notice.migration_hint
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+fn log_deprecation(notice: &DeprecationNotice) {
+    let _ = notice;
+}
+
+#[cfg(test)]
+mod test {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_deprecation_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Deprecation", HeaderValue::from_static("@1735689600"));
+        headers.insert(
+            "Sunset",
+            HeaderValue::from_static("Wed, 31 Dec 2025 23:59:59 GMT"),
+        );
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                "<https://workos.com/docs/reference>; rel=\"help\", <https://workos.com/docs/migrate>; rel=\"deprecation\"",
+            ),
+        );
+
+        let notice = DeprecationNotice::from_response(
+            &Method::GET,
+            &Url::parse("https://api.workos.com/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+                .unwrap(),
+            &headers,
+        );
+
+        assert_eq!(
+            notice,
+            Some(DeprecationNotice {
+                endpoint: "GET /organizations/:id".to_string(),
+                deprecation: "@1735689600".to_string(),
+                sunset: Some("Wed, 31 Dec 2025 23:59:59 GMT".to_string()),
+                migration_hint: Some("https://workos.com/docs/migrate".to_string()),
+            })
+        )
+    }
+
+    #[test]
+    fn it_ignores_responses_without_a_deprecation_header() {
+        let notice = DeprecationNotice::from_response(
+            &Method::GET,
+            &Url::parse("https://api.workos.com/organizations").unwrap(),
+            &HeaderMap::new(),
+        );
+
+        assert_eq!(notice, None)
+    }
+
+    #[test]
+    fn it_rate_limits_warnings_per_endpoint() {
+        let now = Instant::now();
+        let endpoint = "DELETE /test/deprecation/rate_limit";
+
+        assert!(should_warn(endpoint, now));
+        assert!(!should_warn(endpoint, now + Duration::from_secs(60)));
+        assert!(should_warn("DELETE /test/deprecation/other", now));
+        assert!(should_warn(
+            endpoint,
+            now + DEPRECATION_WARNING_INTERVAL + Duration::from_secs(1)
+        ));
+    }
+}
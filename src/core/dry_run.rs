@@ -0,0 +1,272 @@
+use chrono::Utc;
+use reqwest::{Method, Request, Response, StatusCode};
+use serde_json::{Value, json};
+
+use crate::core::{is_mutating, is_workos_id, response_from_http};
+
+/// A mutating request intercepted by dry-run mode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DryRunRequest {
+    /// The HTTP method of the request.
+    pub method: Method,
+
+    /// The path of the request.
+    pub path: String,
+
+    /// The JSON body of the request, if it had one.
+    pub body: Option<Value>,
+}
+
+impl DryRunRequest {
+    /// Returns the dry-run request for the provided request, or `None` if it is not mutating.
+    pub(crate) fn from_request(request: &Request) -> Option<Self> {
+        if !is_mutating(request) {
+            return None;
+        }
+
+        Some(Self {
+            method: request.method().clone(),
+            path: request.url().path().to_string(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .and_then(|bytes| serde_json::from_slice(bytes).ok()),
+        })
+    }
+}
+
+/// Produces simulated responses for mutating requests in dry-run mode.
+///
+/// Install a responder with [`WorkOsBuilder::dry_run`](crate::WorkOsBuilder::dry_run). Any
+/// closure taking a [`DryRunRequest`] and returning an `http::Response<Vec<u8>>` can be used
+/// as a responder, which allows operations to return precisely shaped typed responses.
+pub trait DryRunResponder: Send + Sync + 'static {
+    /// Returns the simulated response for the request.
+    fn respond(&self, request: &DryRunRequest) -> http::Response<Vec<u8>>;
+}
+
+impl<F> DryRunResponder for F
+where
+    F: Fn(&DryRunRequest) -> http::Response<Vec<u8>> + Send + Sync + 'static,
+{
+    fn respond(&self, request: &DryRunRequest) -> http::Response<Vec<u8>> {
+        self(request)
+    }
+}
+
+/// A [`DryRunResponder`] that simulates responses by echoing the request.
+///
+/// - `DELETE` requests receive an empty `204 No Content` response.
+/// - Other requests receive a `200 OK` response containing the request body, along with an
+///   `id` and `created_at`/`updated_at` timestamps when not already present. The `id` is taken
+///   from the request path when it contains a WorkOS ID.
+///
+/// Operations whose responses contain fields not present in their requests will fail to
+/// deserialize an echoed response; use a custom [`DryRunResponder`] for those.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EchoDryRunResponder;
+
+impl DryRunResponder for EchoDryRunResponder {
+    fn respond(&self, request: &DryRunRequest) -> http::Response<Vec<u8>> {
+        if request.method == Method::DELETE {
+            return http::Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .body(Vec::new())
+                .expect("static response parts are valid");
+        }
+
+        let mut body = match &request.body {
+            Some(Value::Object(body)) => body.clone(),
+            _ => Default::default(),
+        };
+
+        let id = request
+            .path
+            .rsplit('/')
+            .find(|segment| is_workos_id(segment))
+            .unwrap_or("dry_run")
+            .to_string();
+        let now = Utc::now().to_rfc3339();
+
+        body.entry("id").or_insert_with(|| json!(id));
+        body.entry("created_at").or_insert_with(|| json!(now));
+        body.entry("updated_at").or_insert_with(|| json!(now));
+
+        http::Response::builder()
+            .status(StatusCode::OK)
+            .header("Content-Type", "application/json")
+            .body(Value::Object(body).to_string().into_bytes())
+            .expect("static response parts are valid")
+    }
+}
+
+/// Returns the simulated response for the request, or `None` if it should be sent.
+pub(crate) fn simulate_response(
+    responder: &dyn DryRunResponder,
+    request: &Request,
+) -> Option<Response> {
+    DryRunRequest::from_request(request).map(|dry_run_request| {
+        response_from_http(responder.respond(&dry_run_request), request.url().clone())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ApiKey, WorkOs};
+
+    #[tokio::test]
+    async fn it_simulates_mutating_requests_without_sending_them() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .dry_run(EchoDryRunResponder)
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("PUT", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let url = workos
+            .base_url()
+            .join("/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .unwrap();
+        let response = workos
+            .send::<()>(
+                workos
+                    .client()
+                    .put(url)
+                    .json(&json!({ "name": "Foo Corp" })),
+            )
+            .await
+            .unwrap();
+
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(body["id"], json!("org_01EHZNVPK3SFK441A1RGBFSHRT"));
+        assert_eq!(body["name"], json!("Foo Corp"));
+        assert!(body["created_at"].is_string());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_simulates_deletes_with_an_empty_response() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url("http://127.0.0.1:1")
+            .unwrap()
+            .dry_run(EchoDryRunResponder)
+            .build()
+            .unwrap();
+
+        let url = workos
+            .base_url()
+            .join("/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .unwrap();
+        let response = workos
+            .send::<()>(workos.client().delete(url))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn it_uses_a_custom_responder() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url("http://127.0.0.1:1")
+            .unwrap()
+            .dry_run(|request: &DryRunRequest| {
+                http::Response::builder()
+                    .status(201)
+                    .body(request.path.clone().into_bytes())
+                    .unwrap()
+            })
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/organizations").unwrap();
+        let response = workos.send::<()>(workos.client().post(url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.text().await.unwrap(), "/organizations");
+    }
+
+    #[tokio::test]
+    async fn it_sends_read_requests_in_dry_run_mode() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .dry_run(EchoDryRunResponder)
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations")
+            .with_status(200)
+            .with_body("live")
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/organizations").unwrap();
+        let response = workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        assert_eq!(response.text().await.unwrap(), "live");
+    }
+
+    #[tokio::test]
+    async fn it_sends_authentication_and_check_requests_in_dry_run_mode() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .dry_run(EchoDryRunResponder)
+            .build()
+            .unwrap();
+
+        let authenticate = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body("authenticated")
+            .create_async()
+            .await;
+        let check = server
+            .mock("POST", "/fga/v1/check")
+            .with_status(200)
+            .with_body("checked")
+            .create_async()
+            .await;
+
+        let url = workos
+            .base_url()
+            .join("/user_management/authenticate")
+            .unwrap();
+        let response = workos
+            .send::<()>(
+                workos
+                    .client()
+                    .post(url)
+                    .json(&json!({ "grant_type": "authorization_code", "code": "abc123" })),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), "authenticated");
+
+        let url = workos.base_url().join("/fga/v1/check").unwrap();
+        let response = workos
+            .send::<()>(workos.client().post(url).json(&json!({ "checks": [] })))
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), "checked");
+
+        authenticate.assert_async().await;
+        check.assert_async().await;
+    }
+}
@@ -1,14 +1,30 @@
+use std::convert::Infallible;
 use std::error::Error as StdError;
 use std::fmt;
 
-use reqwest::Error as ReqwestError;
+use reqwest::{Error as ReqwestError, StatusCode};
 use thiserror::Error;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::HttpBackendError;
+use crate::WorkOsApiError;
+use crate::core::is_connect_error;
+
 /// Additional context for HTTP failures.
 #[derive(Debug)]
 pub struct RequestError {
     message: String,
-    source: Option<ReqwestError>,
+    status: Option<StatusCode>,
+    body: Option<String>,
+    source: Option<RequestErrorSource>,
+}
+
+/// The error that caused a [`RequestError`].
+#[derive(Debug)]
+enum RequestErrorSource {
+    Reqwest(ReqwestError),
+    #[cfg(not(target_arch = "wasm32"))]
+    Backend(HttpBackendError),
 }
 
 impl RequestError {
@@ -16,6 +32,8 @@ impl RequestError {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
+            status: None,
+            body: None,
             source: None,
         }
     }
@@ -24,14 +42,84 @@ impl RequestError {
     pub fn with_source(message: impl Into<String>, source: ReqwestError) -> Self {
         Self {
             message: message.into(),
-            source: Some(source),
+            status: source.status(),
+            body: None,
+            source: Some(RequestErrorSource::Reqwest(source)),
+        }
+    }
+
+    /// Creates a new `RequestError` including the error returned from a custom
+    /// [`HttpBackend`](crate::HttpBackend) that failed to send the request.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn with_backend_source(
+        message: impl Into<String>,
+        source: HttpBackendError,
+    ) -> Self {
+        Self {
+            message: message.into(),
+            status: source
+                .downcast_ref::<ReqwestError>()
+                .and_then(ReqwestError::status),
+            body: None,
+            source: Some(RequestErrorSource::Backend(source)),
         }
     }
 
+    /// Sets the HTTP status code of the response that caused this error.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the response body that caused this error.
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
     /// Returns the human-readable message associated with this error.
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Returns the HTTP status code of the response that caused this error, if any.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// Returns the response body that caused this error, if it was captured.
+    ///
+    /// The body is captured when a response cannot be decoded, and may be truncated.
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Returns the [`ErrorClassification`] of this error.
+    pub fn classification(&self) -> ErrorClassification {
+        if let Some(status) = self.status {
+            return ErrorClassification::from_status(status);
+        }
+
+        let source = match &self.source {
+            Some(RequestErrorSource::Reqwest(source)) => source,
+            #[cfg(not(target_arch = "wasm32"))]
+            Some(RequestErrorSource::Backend(source)) => match source.downcast_ref() {
+                Some(source) => source,
+                // A backend that failed without a response, e.g. because the connection was
+                // refused, may well succeed when the request is sent again.
+                None => return ErrorClassification::Transient,
+            },
+            None => return ErrorClassification::Permanent,
+        };
+
+        if source.is_timeout() || is_connect_error(source) || source.is_request() {
+            ErrorClassification::Transient
+        } else if source.is_builder() {
+            ErrorClassification::ConfigurationError
+        } else {
+            ErrorClassification::Permanent
+        }
+    }
 }
 
 impl fmt::Display for RequestError {
@@ -42,9 +130,11 @@ impl fmt::Display for RequestError {
 
 impl StdError for RequestError {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        self.source
-            .as_ref()
-            .map(|source| source as &(dyn StdError + 'static))
+        match self.source.as_ref()? {
+            RequestErrorSource::Reqwest(source) => Some(source),
+            #[cfg(not(target_arch = "wasm32"))]
+            RequestErrorSource::Backend(source) => Some(source.as_ref()),
+        }
     }
 }
 
@@ -77,6 +167,20 @@ pub enum WorkOsError<E> {
         retry_after: Option<f32>,
     },
 
+    /// The WorkOS API rejected the request with a standard error response.
+    ///
+    /// # Migration
+    ///
+    /// This is a breaking change from 0.2: error responses with a WorkOS error body used
+    /// to be returned as [`WorkOsError::RequestError`], and now only responses without
+    /// one are. Code that matched `RequestError` to inspect failed API calls should also
+    /// match this variant, using [`WorkOsApiError::status`] in place of
+    /// [`RequestError::status`] and [`WorkOsApiError::code`] and
+    /// [`WorkOsApiError::message`] in place of parsing [`RequestError::body`].
+    /// [`WorkOsError::classification`] covers both variants.
+    #[error(transparent)]
+    Api(WorkOsApiError),
+
     /// An error occurred while parsing a URL.
     #[error("URL parse error")]
     UrlParseError(#[from] url::ParseError),
@@ -90,6 +194,94 @@ pub enum WorkOsError<E> {
     RequestError(#[from] RequestError),
 }
 
+impl<E: OperationError> WorkOsError<E> {
+    /// Returns the [`ErrorClassification`] of this error.
+    ///
+    /// This allows generic retry and alerting layers to handle errors without
+    /// knowing every operation-specific error code. Operation-specific errors are
+    /// classified by [`OperationError::classification`].
+    pub fn classification(&self) -> ErrorClassification {
+        match self {
+            WorkOsError::Operation(err) => err.classification(),
+            WorkOsError::Unauthorized => ErrorClassification::ConfigurationError,
+            WorkOsError::RateLimited { .. } => ErrorClassification::Transient,
+            WorkOsError::Api(err) => ErrorClassification::from_status(err.status),
+            WorkOsError::UrlParseError(_) => ErrorClassification::ConfigurationError,
+            WorkOsError::IpAddrParseError(_) => ErrorClassification::UserError,
+            WorkOsError::RequestError(err) => err.classification(),
+        }
+    }
+}
+
+impl<E> WorkOsError<E> {
+    /// Converts the operation-specific error with the provided function, keeping every
+    /// other variant as is.
+    ///
+    /// This is useful when composing operations that return different error types.
+    pub fn map_operation<F>(self, map: impl FnOnce(E) -> F) -> WorkOsError<F> {
+        match self {
+            WorkOsError::Operation(err) => WorkOsError::Operation(map(err)),
+            WorkOsError::Unauthorized => WorkOsError::Unauthorized,
+            WorkOsError::RateLimited { retry_after } => WorkOsError::RateLimited { retry_after },
+            WorkOsError::Api(err) => WorkOsError::Api(err),
+            WorkOsError::UrlParseError(err) => WorkOsError::UrlParseError(err),
+            WorkOsError::IpAddrParseError(err) => WorkOsError::IpAddrParseError(err),
+            WorkOsError::RequestError(err) => WorkOsError::RequestError(err),
+        }
+    }
+}
+
+/// An operation-specific error, returned in [`WorkOsError::Operation`].
+pub trait OperationError {
+    /// Returns the [`ErrorClassification`] of this error.
+    ///
+    /// Defaults to [`ErrorClassification::UserError`], since most operation-specific errors
+    /// are the WorkOS API rejecting the input of the request. Errors that are decoded from
+    /// a response with a status that says otherwise, or that are raised locally for other
+    /// reasons, override this.
+    fn classification(&self) -> ErrorClassification {
+        ErrorClassification::UserError
+    }
+}
+
+impl OperationError for () {}
+
+impl OperationError for Infallible {}
+
+/// A coarse classification of a [`WorkOsError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorClassification {
+    /// The request was rejected because of the input it contained, e.g. invalid credentials.
+    ///
+    /// Retrying with the same input will not succeed.
+    UserError,
+
+    /// The SDK or the WorkOS environment is misconfigured, e.g. an invalid API key or base URL.
+    ConfigurationError,
+
+    /// The failure is likely temporary, e.g. a timeout, a refused connection, or a rate limit.
+    ///
+    /// The request may succeed if retried.
+    Transient,
+
+    /// The failure is not expected to resolve by retrying.
+    Permanent,
+}
+
+impl ErrorClassification {
+    pub(crate) fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::REQUEST_TIMEOUT | StatusCode::TOO_MANY_REQUESTS => Self::Transient,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::ConfigurationError,
+            StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND | StatusCode::UNPROCESSABLE_ENTITY => {
+                Self::UserError
+            }
+            status if status.is_server_error() => Self::Transient,
+            _ => Self::Permanent,
+        }
+    }
+}
+
 /// A WorkOS SDK result.
 pub type WorkOsResult<T, E> = Result<T, WorkOsError<E>>;
 
@@ -98,3 +290,65 @@ impl<E> From<ReqwestError> for WorkOsError<E> {
         WorkOsError::RequestError(RequestError::from(error))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_classifies_variants_without_a_response() {
+        assert_eq!(
+            WorkOsError::Operation(()).classification(),
+            ErrorClassification::UserError
+        );
+        assert_eq!(
+            WorkOsError::<()>::Unauthorized.classification(),
+            ErrorClassification::ConfigurationError
+        );
+        assert_eq!(
+            WorkOsError::<()>::RateLimited { retry_after: None }.classification(),
+            ErrorClassification::Transient
+        );
+    }
+
+    #[test]
+    fn it_classifies_operation_errors_by_their_own_classification() {
+        struct VersionConflict;
+
+        impl OperationError for VersionConflict {
+            fn classification(&self) -> ErrorClassification {
+                ErrorClassification::from_status(StatusCode::CONFLICT)
+            }
+        }
+
+        assert_eq!(
+            WorkOsError::Operation(VersionConflict).classification(),
+            ErrorClassification::Permanent
+        );
+    }
+
+    #[test]
+    fn it_classifies_request_errors_by_status() {
+        let classify = |status| {
+            WorkOsError::<()>::RequestError(RequestError::new("failed").with_status(status))
+                .classification()
+        };
+
+        assert_eq!(
+            classify(StatusCode::BAD_GATEWAY),
+            ErrorClassification::Transient
+        );
+        assert_eq!(
+            classify(StatusCode::FORBIDDEN),
+            ErrorClassification::ConfigurationError
+        );
+        assert_eq!(
+            classify(StatusCode::UNPROCESSABLE_ENTITY),
+            ErrorClassification::UserError
+        );
+        assert_eq!(
+            classify(StatusCode::CONFLICT),
+            ErrorClassification::Permanent
+        );
+    }
+}
@@ -1,10 +1,46 @@
+use std::fmt;
+#[cfg(not(target_arch = "wasm32"))]
+use std::pin::Pin;
+#[cfg(not(target_arch = "wasm32"))]
+use std::task::{Context, Poll, Waker};
 use std::time::Duration;
 
-use reqwest::{Body, Method, Response, StatusCode, header::HeaderMap};
+use bytes::Bytes;
+#[cfg(not(target_arch = "wasm32"))]
+use http_body::Body as _;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Body;
+use reqwest::{Method, Request, Response, StatusCode, header::HeaderMap};
 use url::Url;
 
 pub(crate) const MAX_BODY_LOG_BYTES: usize = 8 * 1024;
 
+/// The target of records emitted through the `log` crate, when `tracing` is not enabled.
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) const LOG_TARGET: &str = "workos_sdk";
+
+/// The paths of `POST` endpoints that authenticate or check access without changing any
+/// WorkOS data.
+const NON_MUTATING_POST_PATHS: &[&str] = &[
+    "/fga/v1/check",
+    "/sso/token",
+    "/user_management/authenticate",
+    "/widgets/token",
+];
+
+/// Returns whether the request may change data in WorkOS.
+///
+/// Requests are mutating unless they are `GET`, `HEAD`, or `OPTIONS` requests, or `POST`
+/// requests to an endpoint that only authenticates or checks access, such as
+/// `/user_management/authenticate` or `/fga/v1/check`.
+pub(crate) fn is_mutating(request: &Request) -> bool {
+    match *request.method() {
+        Method::GET | Method::HEAD | Method::OPTIONS => false,
+        Method::POST => !NON_MUTATING_POST_PATHS.contains(&request.url().path()),
+        _ => true,
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct ResponseLogContext {
     pub method: Method,
@@ -13,12 +49,44 @@ pub(crate) struct ResponseLogContext {
     pub duration: Duration,
 }
 
+/// Attaches the context to the response, for errors raised while reading it.
+///
+/// Responses on `wasm32` have no extensions, so there the context is not kept.
 pub(crate) fn store_response_context(response: &mut Response, context: ResponseLogContext) {
+    #[cfg(not(target_arch = "wasm32"))]
     response.extensions_mut().insert(context);
+
+    #[cfg(target_arch = "wasm32")]
+    let _ = (response, context);
 }
 
 pub(crate) fn response_context(response: &Response) -> Option<ResponseLogContext> {
-    response.extensions().get::<ResponseLogContext>().cloned()
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        response.extensions().get::<ResponseLogContext>().cloned()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = response;
+        None
+    }
+}
+
+/// Returns whether the request failed to connect to the server.
+///
+/// The `reqwest` client for `wasm32` does not report connection failures separately.
+pub(crate) fn is_connect_error(err: &reqwest::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        err.is_connect()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = err;
+        false
+    }
 }
 
 pub(crate) fn collect_error_chain(err: &reqwest::Error) -> Vec<String> {
@@ -99,13 +167,62 @@ pub(crate) fn sanitize_headers(headers: &HeaderMap) -> Vec<(String, String)> {
         .collect()
 }
 
-pub(crate) fn extract_request_body(body: &Body) -> Option<String> {
-    match body.as_bytes() {
-        Some(bytes) => Some(truncate_for_log(
-            &String::from_utf8_lossy(bytes),
-            MAX_BODY_LOG_BYTES,
-        )),
-        None => Some("<non-replayable body>".to_string()),
+/// A handle to the body of a request, which is only turned into text when a log line that
+/// includes it is written.
+///
+/// Capturing the body does not copy it, so requests are not slowed down when the body is
+/// never logged.
+#[derive(Clone, Debug, Default)]
+pub(crate) enum LoggedRequestBody {
+    #[default]
+    Empty,
+    Bytes(Bytes),
+    NonReplayable,
+}
+
+impl LoggedRequestBody {
+    /// Returns a handle to the body of the request, leaving the request unchanged.
+    pub(crate) fn capture(request: &mut Request) -> Self {
+        let Some(body) = request.body_mut() else {
+            return Self::Empty;
+        };
+        if body.as_bytes().is_none() {
+            return Self::NonReplayable;
+        }
+
+        // A body with bytes yields them as a single frame without copying, after which the
+        // request is given a new body sharing the same bytes.
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut context = Context::from_waker(Waker::noop());
+            let bytes = match Pin::new(&mut *body).poll_frame(&mut context) {
+                Poll::Ready(Some(Ok(frame))) => frame.into_data().unwrap_or_default(),
+                _ => Bytes::new(),
+            };
+            *body = Body::from(bytes.clone());
+
+            Self::Bytes(bytes)
+        }
+
+        // The body of a request on `wasm32` is not an `http_body::Body`, so its bytes are
+        // copied instead.
+        #[cfg(target_arch = "wasm32")]
+        {
+            Self::Bytes(Bytes::copy_from_slice(body.as_bytes().unwrap_or_default()))
+        }
+    }
+}
+
+impl fmt::Display for LoggedRequestBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoggedRequestBody::Empty => f.write_str("<empty>"),
+            LoggedRequestBody::Bytes(bytes) => f.write_str(&truncate_for_log(
+                &String::from_utf8_lossy(bytes),
+                MAX_BODY_LOG_BYTES,
+            )),
+            LoggedRequestBody::NonReplayable => f.write_str("<non-replayable body>"),
+        }
     }
 }
 
@@ -135,23 +252,37 @@ pub(crate) fn log_request(
     method: &str,
     url: &Url,
     headers: &[(String, String)],
-    body: Option<&str>,
+    body: &LoggedRequestBody,
 ) {
     tracing::debug!(
         method = tracing::field::display(method),
         url = tracing::field::display(url),
         request_headers = tracing::field::debug(headers),
-        request_body = body.unwrap_or("<empty>"),
+        request_body = tracing::field::display(body),
         "sending request"
     );
 }
 
-#[cfg(not(feature = "tracing"))]
+#[cfg(all(feature = "log", not(feature = "tracing")))]
 pub(crate) fn log_request(
     method: &str,
     url: &Url,
     headers: &[(String, String)],
-    body: Option<&str>,
+    body: &LoggedRequestBody,
+) {
+    log::debug!(
+        target: LOG_TARGET,
+        "sending request method={method} url={url} request_headers={headers:?} request_body={}",
+        body
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+pub(crate) fn log_request(
+    method: &str,
+    url: &Url,
+    headers: &[(String, String)],
+    body: &LoggedRequestBody,
 ) {
     let _ = (method, url, headers, body);
 }
@@ -162,7 +293,7 @@ pub(crate) fn log_request_failure(
     method: &str,
     url: &Url,
     headers: &[(String, String)],
-    body: Option<&str>,
+    body: &LoggedRequestBody,
     duration: Duration,
     err: &reqwest::Error,
     error_causes: &[String],
@@ -173,12 +304,12 @@ pub(crate) fn log_request_failure(
             method = tracing::field::display(method),
             url = tracing::field::display(url),
             request_headers = tracing::field::debug(headers),
-            request_body = body.unwrap_or("<empty>"),
+            request_body = tracing::field::display(body),
             elapsed_ms = duration.as_millis(),
             error = tracing::field::display(err),
             error_is_timeout = err.is_timeout(),
             error_is_request = err.is_request(),
-            error_is_connect = err.is_connect(),
+            error_is_connect = is_connect_error(err),
             error_is_body = err.is_body(),
             error_is_decode = err.is_decode(),
             error_is_builder = err.is_builder(),
@@ -190,12 +321,12 @@ pub(crate) fn log_request_failure(
             method = tracing::field::display(method),
             url = tracing::field::display(url),
             request_headers = tracing::field::debug(headers),
-            request_body = body.unwrap_or("<empty>"),
+            request_body = tracing::field::display(body),
             elapsed_ms = duration.as_millis(),
             error = tracing::field::display(err),
             error_is_timeout = err.is_timeout(),
             error_is_request = err.is_request(),
-            error_is_connect = err.is_connect(),
+            error_is_connect = is_connect_error(err),
             error_is_body = err.is_body(),
             error_is_decode = err.is_decode(),
             error_is_builder = err.is_builder(),
@@ -205,13 +336,36 @@ pub(crate) fn log_request_failure(
     }
 }
 
-#[cfg(not(feature = "tracing"))]
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn log_request_failure(
+    method: &str,
+    url: &Url,
+    headers: &[(String, String)],
+    body: &LoggedRequestBody,
+    duration: Duration,
+    err: &reqwest::Error,
+    error_causes: &[String],
+    error_hint: Option<&str>,
+) {
+    log::error!(
+        target: LOG_TARGET,
+        "request failed method={method} url={url} request_headers={headers:?} request_body={} elapsed_ms={} error={err} error_is_timeout={} error_is_connect={} error_chain={error_causes:?} error_hint={}",
+        body,
+        duration.as_millis(),
+        err.is_timeout(),
+        is_connect_error(err),
+        error_hint.unwrap_or("<none>")
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn log_request_failure(
     method: &str,
     url: &Url,
     headers: &[(String, String)],
-    body: Option<&str>,
+    body: &LoggedRequestBody,
     duration: Duration,
     err: &reqwest::Error,
     error_causes: &[String],
@@ -229,6 +383,93 @@ pub(crate) fn log_request_failure(
     );
 }
 
+#[cfg(feature = "tracing")]
+pub(crate) fn log_audit_sink_failure(
+    method: &str,
+    url: &Url,
+    err: &(dyn std::error::Error + Send + Sync),
+) {
+    tracing::error!(
+        method = tracing::field::display(method),
+        url = tracing::field::display(url),
+        error = tracing::field::display(err),
+        "failed to record audit entry"
+    );
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_audit_sink_failure(
+    method: &str,
+    url: &Url,
+    err: &(dyn std::error::Error + Send + Sync),
+) {
+    log::error!(
+        target: LOG_TARGET,
+        "failed to record audit entry method={method} url={url} error={err}"
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+pub(crate) fn log_audit_sink_failure(
+    method: &str,
+    url: &Url,
+    err: &(dyn std::error::Error + Send + Sync),
+) {
+    let _ = (method, url, err);
+}
+
+#[cfg(feature = "tracing")]
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn log_backend_request_failure(
+    method: &str,
+    url: &Url,
+    headers: &[(String, String)],
+    body: &LoggedRequestBody,
+    duration: Duration,
+    err: &(dyn std::error::Error + Send + Sync),
+) {
+    tracing::error!(
+        method = tracing::field::display(method),
+        url = tracing::field::display(url),
+        request_headers = tracing::field::debug(headers),
+        request_body = tracing::field::display(body),
+        elapsed_ms = duration.as_millis(),
+        error = tracing::field::display(err),
+        "request failed"
+    );
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn log_backend_request_failure(
+    method: &str,
+    url: &Url,
+    headers: &[(String, String)],
+    body: &LoggedRequestBody,
+    duration: Duration,
+    err: &(dyn std::error::Error + Send + Sync),
+) {
+    log::error!(
+        target: LOG_TARGET,
+        "request failed method={method} url={url} request_headers={headers:?} request_body={} elapsed_ms={} error={err}",
+        body,
+        duration.as_millis()
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn log_backend_request_failure(
+    method: &str,
+    url: &Url,
+    headers: &[(String, String)],
+    body: &LoggedRequestBody,
+    duration: Duration,
+    err: &(dyn std::error::Error + Send + Sync),
+) {
+    let _ = (method, url, headers, body, duration, err);
+}
+
 #[cfg(feature = "tracing")]
 pub(crate) fn log_response_success(
     method: &str,
@@ -247,7 +488,22 @@ pub(crate) fn log_response_success(
     );
 }
 
-#[cfg(not(feature = "tracing"))]
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_response_success(
+    method: &str,
+    url: &Url,
+    status: StatusCode,
+    headers: &[(String, String)],
+    duration: Duration,
+) {
+    log::debug!(
+        target: LOG_TARGET,
+        "received response method={method} url={url} status={status} response_headers={headers:?} elapsed_ms={}",
+        duration.as_millis()
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
 pub(crate) fn log_response_success(
     method: &str,
     url: &Url,
@@ -276,7 +532,22 @@ pub(crate) fn log_response_status(
     );
 }
 
-#[cfg(not(feature = "tracing"))]
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_response_status(
+    method: &str,
+    url: &Url,
+    status: StatusCode,
+    headers: &[(String, String)],
+    duration: Duration,
+) {
+    log::debug!(
+        target: LOG_TARGET,
+        "received non-success response method={method} url={url} status={status} response_headers={headers:?} elapsed_ms={}",
+        duration.as_millis()
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
 pub(crate) fn log_response_status(
     method: &str,
     url: &Url,
@@ -305,7 +576,22 @@ pub(crate) fn log_response_unauthorized(
     );
 }
 
-#[cfg(not(feature = "tracing"))]
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_response_unauthorized(
+    method: &str,
+    url: &Url,
+    status: StatusCode,
+    headers: &[(String, String)],
+    duration: Duration,
+) {
+    log::warn!(
+        target: LOG_TARGET,
+        "unauthorized response method={method} url={url} status={status} response_headers={headers:?} elapsed_ms={}",
+        duration.as_millis()
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
 pub(crate) fn log_response_unauthorized(
     method: &str,
     url: &Url,
@@ -336,7 +622,23 @@ pub(crate) fn log_response_error_with_body(
     );
 }
 
-#[cfg(not(feature = "tracing"))]
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_response_error_with_body(
+    method: &str,
+    url: &Url,
+    status: StatusCode,
+    headers: &[(String, String)],
+    body: &str,
+    duration: Duration,
+) {
+    log::error!(
+        target: LOG_TARGET,
+        "error response method={method} url={url} status={status} response_headers={headers:?} response_body={body} elapsed_ms={}",
+        duration.as_millis()
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
 pub(crate) fn log_response_error_with_body(
     method: &str,
     url: &Url,
@@ -368,7 +670,23 @@ pub(crate) fn log_response_error_body_failed(
     );
 }
 
-#[cfg(not(feature = "tracing"))]
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_response_error_body_failed(
+    method: &str,
+    url: &Url,
+    status: StatusCode,
+    headers: &[(String, String)],
+    error: &str,
+    duration: Duration,
+) {
+    log::error!(
+        target: LOG_TARGET,
+        "error response (body unavailable) method={method} url={url} status={status} response_headers={headers:?} error={error} elapsed_ms={}",
+        duration.as_millis()
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
 pub(crate) fn log_response_error_body_failed(
     method: &str,
     url: &Url,
@@ -379,3 +697,38 @@ pub(crate) fn log_response_error_body_failed(
 ) {
     let _ = (method, url, status, headers, error, duration);
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_captures_the_request_body_without_consuming_it() {
+        let mut request = reqwest::Client::new()
+            .post("https://api.workos.com/user_management/users")
+            .body(r#"{"email":"marcelina@example.com"}"#)
+            .build()
+            .unwrap();
+
+        let body = LoggedRequestBody::capture(&mut request);
+
+        assert_eq!(
+            body.to_string(),
+            r#"{"email":"marcelina@example.com"}"#.to_string()
+        );
+        assert_eq!(
+            request.body().and_then(Body::as_bytes),
+            Some(br#"{"email":"marcelina@example.com"}"#.as_slice())
+        );
+    }
+
+    #[test]
+    fn it_truncates_long_request_bodies_when_displayed() {
+        let body = LoggedRequestBody::Bytes(Bytes::from("a".repeat(MAX_BODY_LOG_BYTES + 1)));
+
+        assert_eq!(
+            body.to_string(),
+            format!("{}…", "a".repeat(MAX_BODY_LOG_BYTES))
+        );
+    }
+}
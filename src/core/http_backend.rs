@@ -0,0 +1,318 @@
+use std::error::Error as StdError;
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Request, Response, ResponseBuilderExt};
+use url::Url;
+
+use crate::RequestError;
+
+/// An error returned from an [`HttpBackend`].
+pub type HttpBackendError = Box<dyn StdError + Send + Sync>;
+
+/// The transport used to send requests to the WorkOS API.
+///
+/// By default, the client sends requests with its own [`reqwest::Client`]. Implement this
+/// trait to supply an alternative backend, such as a different HTTP stack or a FIPS-validated
+/// TLS implementation, and install it with
+/// [`WorkOsBuilder::http_backend`](crate::WorkOsBuilder::http_backend).
+///
+/// Errors returned by the backend are treated as transient, so requests that are safe to
+/// repeat are retried under the [`RetryPolicy`](crate::RetryPolicy). The
+/// [`timeout`](crate::WorkOsBuilder::timeout) configured on the client is applied around each
+/// call to the backend, but the backend must apply its own connect timeout.
+///
+/// # Examples
+///
+/// ```
+/// use async_trait::async_trait;
+/// use workos_sdk::{ApiKey, HttpBackend, HttpBackendError, WorkOs};
+///
+/// struct StaticBackend;
+///
+/// #[async_trait]
+/// impl HttpBackend for StaticBackend {
+///     async fn execute(
+///         &self,
+///         request: http::Request<Vec<u8>>,
+///     ) -> Result<http::Response<Vec<u8>>, HttpBackendError> {
+///         Ok(http::Response::builder()
+///             .status(200)
+///             .body(b"{}".to_vec())?)
+///     }
+/// }
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .http_backend(StaticBackend)
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait HttpBackend: Send + Sync + 'static {
+    /// Sends the request and returns the response.
+    async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HttpBackendError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl HttpBackend for reqwest::Client {
+    async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, HttpBackendError> {
+        let response = reqwest::Client::execute(self, Request::try_from(request)?).await?;
+
+        let mut builder = http::Response::builder()
+            .status(response.status())
+            .version(response.version());
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(response.headers().clone());
+        }
+
+        Ok(builder.body(response.bytes().await?.to_vec())?)
+    }
+}
+
+/// Sends the request through the provided backend, converting to and from `reqwest` types.
+///
+/// The request fails with a timeout if the backend does not respond within the timeout.
+pub(crate) async fn execute_with_backend(
+    backend: &dyn HttpBackend,
+    request: Request,
+    timeout: Option<Duration>,
+) -> Result<Response, RequestError> {
+    let url = request.url().clone();
+
+    let body = match request.body() {
+        Some(body) => body
+            .as_bytes()
+            .ok_or_else(|| {
+                RequestError::new(format!(
+                    "request to {url} failed: streaming request bodies are not supported by \
+                     custom HTTP backends"
+                ))
+            })?
+            .to_vec(),
+        None => Vec::new(),
+    };
+
+    let mut builder = http::Request::builder()
+        .method(request.method().clone())
+        .uri(url.as_str())
+        .version(request.version());
+    if let Some(headers) = builder.headers_mut() {
+        headers.extend(request.headers().clone());
+    }
+    let request = builder
+        .body(body)
+        .map_err(|err| RequestError::new(format!("request to {url} failed: {err}")))?;
+
+    let response = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, backend.execute(request))
+            .await
+            .unwrap_or_else(|_| Err("request timed out".into())),
+        None => backend.execute(request).await,
+    }
+    .map_err(|err| {
+        RequestError::with_backend_source(format!("request to {url} failed: {err}"), err)
+    })?;
+
+    Ok(response_from_http(response, url))
+}
+
+/// Converts a response that did not come from `reqwest`, keeping the URL of the request so
+/// that it can be reported in errors.
+pub(crate) fn response_from_http(response: http::Response<Vec<u8>>, url: Url) -> Response {
+    let mut response = response;
+    let (parts, ()) = http::Response::builder()
+        .url(url)
+        .body(())
+        .expect("a response with only a URL is valid")
+        .into_parts();
+    response.extensions_mut().extend(parts.extensions);
+
+    Response::from(response)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::{ApiKey, ErrorClassification, RetryPolicy, WorkOs};
+
+    type RecordedRequest = (String, String, Vec<u8>);
+
+    #[derive(Default)]
+    struct RecordingBackend {
+        requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl HttpBackend for RecordingBackend {
+        async fn execute(
+            &self,
+            request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, HttpBackendError> {
+            self.requests.lock().unwrap().push((
+                request.method().to_string(),
+                request.uri().to_string(),
+                request.body().clone(),
+            ));
+
+            Ok(http::Response::builder()
+                .status(200)
+                .header("Content-Type", "application/json")
+                .body(br#"{"ok":true}"#.to_vec())?)
+        }
+    }
+
+    #[tokio::test]
+    async fn it_sends_requests_through_a_custom_backend() {
+        let backend = RecordingBackend::default();
+        let requests = backend.requests.clone();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .http_backend(backend)
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let response = workos
+            .send::<()>(workos.client().post(url).body("hello"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.url().as_str(), "https://api.workos.com/health");
+        assert_eq!(response.text().await.unwrap(), r#"{"ok":true}"#);
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            &[(
+                "POST".to_string(),
+                "https://api.workos.com/health".to_string(),
+                b"hello".to_vec()
+            )]
+        )
+    }
+
+    struct FlakyBackend {
+        failures: Mutex<u32>,
+    }
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl HttpBackend for FlakyBackend {
+        async fn execute(
+            &self,
+            _request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, HttpBackendError> {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err("connection refused".into());
+            }
+
+            Ok(http::Response::builder().status(200).body(Vec::new())?)
+        }
+    }
+
+    #[tokio::test]
+    async fn it_retries_requests_that_a_custom_backend_failed_to_send() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .http_backend(FlakyBackend {
+                failures: Mutex::new(1),
+            })
+            .retry_policy(RetryPolicy::new(2).base_delay(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let response = workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn it_reports_custom_backend_failures_as_transient_with_the_url() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .http_backend(FlakyBackend {
+                failures: Mutex::new(1),
+            })
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let err = workos
+            .send::<()>(workos.client().get(url))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.classification(), ErrorClassification::Transient);
+        assert_eq!(
+            err.to_string(),
+            "request to https://api.workos.com/health failed: connection refused"
+        );
+    }
+
+    struct SlowBackend;
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl HttpBackend for SlowBackend {
+        async fn execute(
+            &self,
+            _request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, HttpBackendError> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+
+            Ok(http::Response::builder().status(200).body(Vec::new())?)
+        }
+    }
+
+    #[tokio::test]
+    async fn it_applies_the_timeout_to_custom_backends() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .http_backend(SlowBackend)
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let err = workos
+            .send::<()>(workos.client().get(url))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.classification(), ErrorClassification::Transient);
+    }
+
+    #[tokio::test]
+    async fn it_supports_reqwest_as_a_backend() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/health")
+            .with_status(200)
+            .with_body("healthy")
+            .create_async()
+            .await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .http_backend(reqwest::Client::new())
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let response = workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        assert_eq!(response.text().await.unwrap(), "healthy")
+    }
+}
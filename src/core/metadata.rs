@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+
+/// Metadata about an HTTP response received from the WorkOS API.
+///
+/// Include the [`request_id`](ResponseMetadata::request_id) when contacting WorkOS
+/// support about a specific request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseMetadata {
+    /// The ID WorkOS assigned to the request, from the `X-Request-ID` header.
+    pub request_id: Option<String>,
+
+    /// The number of requests remaining in the current rate limit window, from the
+    /// `X-RateLimit-Remaining` header.
+    pub rate_limit_remaining: Option<u64>,
+
+    /// The value of the `X-RateLimit-Reset` header, indicating when the current rate
+    /// limit window resets.
+    pub rate_limit_reset: Option<u64>,
+
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+
+    /// The time elapsed between sending the request and receiving the response headers.
+    pub elapsed: Duration,
+}
+
+impl ResponseMetadata {
+    pub(crate) fn from_response(
+        status: StatusCode,
+        headers: &HeaderMap,
+        elapsed: Duration,
+    ) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+        };
+
+        Self {
+            request_id: header("x-request-id").map(str::to_owned),
+            rate_limit_remaining: header("x-ratelimit-remaining").and_then(|v| v.parse().ok()),
+            rate_limit_reset: header("x-ratelimit-reset").and_then(|v| v.parse().ok()),
+            status,
+            elapsed,
+        }
+    }
+}
+
+/// The result of an operation along with metadata about the response it came from.
+///
+/// Returned from [`WorkOs::with_metadata`](crate::WorkOs::with_metadata).
+#[derive(Debug)]
+pub struct WithMetadata<T> {
+    /// The result of the operation.
+    pub value: T,
+
+    /// Metadata about the last response received by the operation, or `None` if no
+    /// response was received, e.g. because the connection failed.
+    pub metadata: Option<ResponseMetadata>,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{Value, json};
+
+    use crate::{ApiKey, ResponseExt, WorkOs, WorkOsError, WorkOsResult};
+
+    async fn get_organization(workos: WorkOs) -> WorkOsResult<Value, ()> {
+        let url = workos
+            .base_url()
+            .join("/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")?;
+
+        Ok(workos
+            .send(workos.client().get(url).bearer_auth(workos.key()))
+            .await?
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .json::<Value>()
+            .await?)
+    }
+
+    #[tokio::test]
+    async fn it_returns_response_metadata_alongside_the_value() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(200)
+            .with_header("X-Request-ID", "req_123")
+            .with_header("X-RateLimit-Remaining", "42")
+            .with_header("X-RateLimit-Reset", "1700000000")
+            .with_body(json!({ "name": "Foo Corporation" }).to_string())
+            .create_async()
+            .await;
+
+        let result = workos.with_metadata(get_organization).await;
+
+        assert_eq!(result.value.unwrap()["name"], "Foo Corporation");
+
+        let metadata = result.metadata.unwrap();
+        assert_eq!(metadata.request_id.as_deref(), Some("req_123"));
+        assert_eq!(metadata.rate_limit_remaining, Some(42));
+        assert_eq!(metadata.rate_limit_reset, Some(1700000000));
+        assert_eq!(metadata.status, 200);
+    }
+
+    #[tokio::test]
+    async fn it_returns_response_metadata_alongside_errors() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(401)
+            .with_header("X-Request-ID", "req_456")
+            .create_async()
+            .await;
+
+        let result = workos.with_metadata(get_organization).await;
+
+        matches::assert_matches!(result.value, Err(WorkOsError::Unauthorized));
+
+        let metadata = result.metadata.unwrap();
+        assert_eq!(metadata.request_id.as_deref(), Some("req_456"));
+        assert_eq!(metadata.rate_limit_remaining, None);
+        assert_eq!(metadata.status, 401);
+    }
+}
@@ -0,0 +1,161 @@
+use async_trait::async_trait;
+use reqwest::{Method, Request, Response};
+
+/// Hooks that run around every request sent by the client.
+///
+/// Middleware is installed with [`WorkOsBuilder::middleware`](crate::WorkOsBuilder::middleware)
+/// and applies to every operation, which makes it a central place to inject custom headers,
+/// propagate trace contexts or record metrics. When several middleware are installed,
+/// `before_request` runs in the order they were installed and `after_response` in reverse
+/// order. Both run once per attempt when requests are retried.
+///
+/// # Examples
+///
+/// ```
+/// use async_trait::async_trait;
+/// use reqwest::header::HeaderValue;
+/// use workos_sdk::{ApiKey, Middleware, WorkOs};
+///
+/// struct TraceParent(&'static str);
+///
+/// #[async_trait]
+/// impl Middleware for TraceParent {
+///     async fn before_request(
+///         &self,
+///         request: &mut reqwest::Request,
+///     ) -> Option<http::Response<Vec<u8>>> {
+///         request
+///             .headers_mut()
+///             .insert("traceparent", HeaderValue::from_static(self.0));
+///         None
+///     }
+/// }
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .middleware(TraceParent(
+///         "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+///     ))
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Middleware: Send + Sync + 'static {
+    /// Called before the request is sent.
+    ///
+    /// The request may be modified, e.g. to add headers. Returning a response skips
+    /// sending the request, and the operation receives that response instead, which is
+    /// useful for stubbing the WorkOS API in tests.
+    async fn before_request(&self, _request: &mut Request) -> Option<http::Response<Vec<u8>>> {
+        None
+    }
+
+    /// Called after a response is received, before the operation handles it.
+    async fn after_response(&self, _method: &Method, _response: &mut Response) {}
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use reqwest::StatusCode;
+    use reqwest::header::HeaderValue;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{ApiKey, WorkOs};
+
+    struct AddHeader;
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl Middleware for AddHeader {
+        async fn before_request(&self, request: &mut Request) -> Option<http::Response<Vec<u8>>> {
+            request
+                .headers_mut()
+                .insert("X-Custom", HeaderValue::from_static("custom"));
+            None
+        }
+    }
+
+    struct CountResponses(Arc<AtomicUsize>);
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl Middleware for CountResponses {
+        async fn after_response(&self, method: &Method, response: &mut Response) {
+            assert_eq!(method, Method::GET);
+            assert_eq!(response.status(), StatusCode::OK);
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    struct Stub;
+
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    impl Middleware for Stub {
+        async fn before_request(&self, _request: &mut Request) -> Option<http::Response<Vec<u8>>> {
+            Some(
+                http::Response::builder()
+                    .status(StatusCode::OK)
+                    .body(json!({ "stubbed": true }).to_string().into_bytes())
+                    .unwrap(),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn it_runs_middleware_around_each_request() {
+        let mut server = mockito::Server::new_async().await;
+        let responses = Arc::new(AtomicUsize::new(0));
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .middleware(AddHeader)
+            .middleware(CountResponses(responses.clone()))
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/health")
+            .match_header("X-Custom", "custom")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/health").unwrap();
+        workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(responses.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn it_short_circuits_requests() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .middleware(Stub)
+            .build()
+            .unwrap();
+
+        let mock = server.mock("GET", "/health").expect(0).create_async().await;
+
+        let url = workos.base_url().join("/health").unwrap();
+        let body = workos
+            .send::<()>(workos.client().get(url))
+            .await
+            .unwrap()
+            .json::<serde_json::Value>()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(body, json!({ "stubbed": true }));
+    }
+}
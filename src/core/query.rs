@@ -0,0 +1,151 @@
+use serde::{Serialize, Serializer, ser};
+use serde_json::Value;
+
+/// Serializes a set of parameters into query string pairs.
+///
+/// All list operations send their parameters through this wrapper so that they share
+/// the same shape on the wire:
+///
+/// - `None` values are omitted rather than sent as empty parameters.
+/// - Flattened parameters (such as pagination) appear at the top level.
+/// - Sequences are sent as repeated parameters (`key=a&key=b`).
+/// - IDs and other newtypes are sent as their inner value.
+///
+/// Parameters are emitted in lexicographic order by key, so the resulting query string is
+/// deterministic.
+#[derive(Debug)]
+pub(crate) struct QueryParams<'a, T: ?Sized>(pub &'a T);
+
+impl<T> Serialize for QueryParams<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let params = match serde_json::to_value(self.0).map_err(ser::Error::custom)? {
+            Value::Object(params) => params,
+            Value::Null => Default::default(),
+            value => {
+                return Err(ser::Error::custom(format!(
+                    "query parameters must be a struct or map, found: {}",
+                    value
+                )));
+            }
+        };
+
+        let mut pairs = Vec::with_capacity(params.len());
+
+        for (key, value) in params {
+            match value {
+                Value::Array(values) => {
+                    for value in values {
+                        if let Some(value) = to_query_value(&key, value)? {
+                            pairs.push((key.clone(), value));
+                        }
+                    }
+                }
+                value => {
+                    if let Some(value) = to_query_value(&key, value)? {
+                        pairs.push((key, value));
+                    }
+                }
+            }
+        }
+
+        pairs.serialize(serializer)
+    }
+}
+
+fn to_query_value<E: ser::Error>(key: &str, value: Value) -> Result<Option<String>, E> {
+    match value {
+        Value::Null => Ok(None),
+        Value::Bool(value) => Ok(Some(value.to_string())),
+        Value::Number(value) => Ok(Some(value.to_string())),
+        Value::String(value) => Ok(Some(value)),
+        Value::Array(_) | Value::Object(_) => Err(E::custom(format!(
+            "unsupported nested value for query parameter '{}'",
+            key
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Serialize;
+
+    use super::*;
+    use crate::{PaginationOrder, PaginationParams};
+
+    fn query_string<T: Serialize>(params: &T) -> String {
+        reqwest::Client::new()
+            .get("https://api.workos.com/")
+            .query(&QueryParams(params))
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[derive(Serialize)]
+    struct Id(String);
+
+    #[derive(Serialize)]
+    struct Params<'a> {
+        #[serde(flatten)]
+        pagination: PaginationParams<'a>,
+        id: Option<&'a Id>,
+        search: Option<&'a str>,
+        domains: Option<Vec<&'a str>>,
+    }
+
+    #[test]
+    fn it_omits_none_values() {
+        let params = Params {
+            pagination: Default::default(),
+            id: None,
+            search: None,
+            domains: None,
+        };
+
+        assert_eq!(query_string(&params), "order=desc")
+    }
+
+    #[test]
+    fn it_flattens_pagination_and_unwraps_newtypes() {
+        let params = Params {
+            pagination: PaginationParams {
+                order: &PaginationOrder::Asc,
+                after: Some("cursor_123"),
+                before: None,
+                limit: Some(25),
+            },
+            id: Some(&Id("org_123".to_string())),
+            search: Some("foo bar"),
+            domains: None,
+        };
+
+        assert_eq!(
+            query_string(&params),
+            "after=cursor_123&id=org_123&limit=25&order=asc&search=foo+bar"
+        )
+    }
+
+    #[test]
+    fn it_repeats_sequence_values() {
+        let params = Params {
+            pagination: Default::default(),
+            id: None,
+            search: None,
+            domains: Some(vec!["foo-corp.com", "bar-corp.com"]),
+        };
+
+        assert_eq!(
+            query_string(&params),
+            "domains=foo-corp.com&domains=bar-corp.com&order=desc"
+        )
+    }
+}
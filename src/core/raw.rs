@@ -0,0 +1,241 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::Response;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::StatusCode;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::header::HeaderMap;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::core::response_from_http;
+use crate::{ResponseMetadata, WithMetadata};
+
+/// A typed result along with the raw HTTP response it was deserialized from.
+///
+/// Returned from [`WorkOs::with_raw`](crate::WorkOs::with_raw).
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct WithRaw<T> {
+    /// The deserialized result of the operation.
+    pub value: T,
+
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+
+    /// The headers of the response.
+    pub headers: HeaderMap,
+
+    /// The response body, exactly as it was returned by the WorkOS API.
+    pub body: String,
+
+    /// Metadata about the response, such as the WorkOS request ID.
+    pub metadata: ResponseMetadata,
+}
+
+struct CapturedResponse {
+    metadata: ResponseMetadata,
+    #[cfg(not(target_arch = "wasm32"))]
+    headers: HeaderMap,
+    #[cfg(not(target_arch = "wasm32"))]
+    body: Option<String>,
+}
+
+/// Records the most recent response received by a client.
+///
+/// Backs both [`WorkOs::with_raw`](crate::WorkOs::with_raw) and
+/// [`WorkOs::with_metadata`](crate::WorkOs::with_metadata); only the former buffers
+/// response bodies.
+#[derive(Clone)]
+pub(crate) struct ResponseCapture {
+    captured: Arc<Mutex<Option<CapturedResponse>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    buffer_body: bool,
+}
+
+impl ResponseCapture {
+    /// Returns a capture that records the metadata, headers and body of each response.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn raw() -> Self {
+        Self {
+            captured: Arc::default(),
+            buffer_body: true,
+        }
+    }
+
+    /// Returns a capture that records only the metadata of each response.
+    pub(crate) fn metadata() -> Self {
+        Self {
+            captured: Arc::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            buffer_body: false,
+        }
+    }
+
+    /// Records the response, replacing any previous one, and returns an equivalent
+    /// response for the operation to consume.
+    ///
+    /// The response body is buffered if this capture records bodies.
+    pub(crate) async fn record(
+        &self,
+        response: Response,
+        elapsed: Duration,
+    ) -> Result<Response, reqwest::Error> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let metadata = ResponseMetadata::from_response(status, &headers, elapsed);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.buffer_body {
+            return self.record_body(response, metadata).await;
+        }
+
+        *self.lock() = Some(CapturedResponse {
+            metadata,
+            #[cfg(not(target_arch = "wasm32"))]
+            headers,
+            #[cfg(not(target_arch = "wasm32"))]
+            body: None,
+        });
+
+        Ok(response)
+    }
+
+    /// Records the response along with its buffered body.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn record_body(
+        &self,
+        response: Response,
+        metadata: ResponseMetadata,
+    ) -> Result<Response, reqwest::Error> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let version = response.version();
+        let url = response.url().clone();
+        let body = response.bytes().await?;
+
+        let mut rebuilt = http::Response::new(body.to_vec());
+        *rebuilt.status_mut() = status;
+        *rebuilt.version_mut() = version;
+        *rebuilt.headers_mut() = headers.clone();
+
+        *self.lock() = Some(CapturedResponse {
+            metadata,
+            headers,
+            body: Some(String::from_utf8_lossy(&body).into_owned()),
+        });
+
+        Ok(response_from_http(rebuilt, url))
+    }
+
+    /// Combines the value with the most recently captured response, if any.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn attach_raw<T>(&self, value: T) -> Option<WithRaw<T>> {
+        let captured = self.lock().take()?;
+
+        Some(WithRaw {
+            value,
+            status: captured.metadata.status,
+            headers: captured.headers,
+            body: captured.body?,
+            metadata: captured.metadata,
+        })
+    }
+
+    /// Combines the value with the metadata of the most recently captured response, if any.
+    pub(crate) fn attach_metadata<T>(&self, value: T) -> WithMetadata<T> {
+        WithMetadata {
+            value,
+            metadata: self.lock().take().map(|captured| captured.metadata),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<CapturedResponse>> {
+        self.captured
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use serde_json::Value;
+
+    use crate::{ApiKey, ResponseExt, WorkOs, WorkOsError, WorkOsResult};
+
+    async fn get_organization(workos: WorkOs) -> WorkOsResult<Value, ()> {
+        let url = workos
+            .base_url()
+            .join("/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")?;
+
+        Ok(workos
+            .send(workos.client().get(url).bearer_auth(workos.key()))
+            .await?
+            .handle_unauthorized_or_generic_error()
+            .await?
+            .json::<Value>()
+            .await?)
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_raw_body_alongside_the_value() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let body = json!({
+            "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "object": "organization",
+            "name": "Foo Corporation",
+            "allow_profiles_outside_organization": false,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "domains": [],
+            "unknown_field": "kept verbatim"
+        })
+        .to_string();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(200)
+            .with_header("X-Request-ID", "req_123")
+            .with_body(&body)
+            .create_async()
+            .await;
+
+        let raw = workos.with_raw(get_organization).await.unwrap();
+
+        assert_eq!(raw.value["name"], "Foo Corporation");
+        assert_eq!(raw.status, 200);
+        assert_eq!(raw.headers["x-request-id"], "req_123");
+        assert_eq!(raw.body, body);
+        assert_eq!(raw.metadata.request_id.as_deref(), Some("req_123"));
+    }
+
+    #[tokio::test]
+    async fn it_returns_errors_unchanged() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let result = workos.with_raw(get_organization).await;
+
+        matches::assert_matches!(result, Err(WorkOsError::Unauthorized))
+    }
+}
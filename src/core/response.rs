@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use reqwest::{Response, StatusCode};
+use serde::de::DeserializeOwned;
 use url::Url;
 
 use crate::core::{
@@ -9,12 +10,13 @@ use crate::core::{
     log_response_error_with_body, log_response_unauthorized, response_context, sanitize_headers,
     truncate_for_log,
 };
-use crate::{RequestError, WorkOsError, WorkOsResult};
+use crate::{RequestError, WorkOsApiError, WorkOsError, WorkOsResult};
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ResponseExt
 where
-    Self: Sized + Send,
+    Self: Sized,
 {
     /// Handles an unauthorized error from the WorkOS API by converting it into a
     /// [`WorkOsError::Unauthorized`] response.
@@ -26,9 +28,17 @@ where
 
     /// Handles an unauthorized or generic error from the WorkOS API.
     async fn handle_unauthorized_or_generic_error<E: Send>(self) -> WorkOsResult<Self, E>;
+
+    /// Deserializes the JSON response body.
+    ///
+    /// Unlike [`Response::json`], a body that cannot be decoded is kept, truncated, on the
+    /// returned [`RequestError`] along with the path of the field that failed, so API drift
+    /// can be diagnosed.
+    async fn decode_json<T: DeserializeOwned>(self) -> Result<T, RequestError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ResponseExt for Response {
     async fn handle_unauthorized_error<E: Send>(self) -> WorkOsResult<Self, E> {
         if self.status() == StatusCode::UNAUTHORIZED {
@@ -70,6 +80,30 @@ impl ResponseExt for Response {
             .handle_generic_error()
             .await
     }
+
+    async fn decode_json<T: DeserializeOwned>(self) -> Result<T, RequestError> {
+        let status = self.status();
+        let (method, url) = match response_context(&self) {
+            Some(context) => (context.method.to_string(), context.url),
+            None => ("UNKNOWN".to_string(), self.url().clone()),
+        };
+        let body = self.text().await?;
+
+        let deserializer = &mut serde_json::Deserializer::from_str(&body);
+        // Successful responses may hold credentials, such as access tokens, so the body is
+        // only attached to the error and left out of its message.
+        serde_path_to_error::deserialize(deserializer).map_err(|err| {
+            RequestError::new(format!(
+                "failed to decode response body from {} {} at `{}`: {}",
+                method,
+                url,
+                err.path(),
+                err.inner()
+            ))
+            .with_status(status)
+            .with_body(truncate_for_log(&body, MAX_BODY_LOG_BYTES))
+        })
+    }
 }
 
 pub(crate) async fn response_to_request_error<E>(response: Response) -> WorkOsError<E> {
@@ -78,17 +112,27 @@ pub(crate) async fn response_to_request_error<E>(response: Response) -> WorkOsEr
     let context_clone = context.clone();
     let fallback_url = response.url().clone();
     let fallback_headers = sanitize_headers(response.headers());
+    let request_id = response
+        .headers()
+        .get("x-request-id")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
 
     match response.text().await {
         Ok(body) => {
             let truncated = truncate_for_log(&body, MAX_BODY_LOG_BYTES);
-            build_request_error_from_body(
+            let error = build_request_error_from_body(
                 context,
                 &fallback_url,
                 &fallback_headers,
                 status,
                 &truncated,
-            )
+            );
+
+            match WorkOsApiError::from_body(status, request_id, &body) {
+                Some(api_error) => WorkOsError::Api(api_error),
+                None => error,
+            }
         }
         Err(err) => {
             let display_err = err.to_string();
@@ -121,7 +165,7 @@ pub(crate) async fn response_to_request_error<E>(response: Response) -> WorkOsEr
                 method, url_ref, status, display_err
             );
 
-            WorkOsError::RequestError(RequestError::with_source(message, err))
+            WorkOsError::RequestError(RequestError::with_source(message, err).with_status(status))
         }
     }
 }
@@ -152,7 +196,7 @@ pub(crate) fn build_request_error_from_body<E>(
                 ctx.duration,
             );
             let message = format_error_message(ctx.method.as_str(), &ctx.url, status, body);
-            WorkOsError::RequestError(RequestError::new(message))
+            WorkOsError::RequestError(RequestError::new(message).with_status(status))
         }
         None => {
             log_response_error_with_body(
@@ -164,7 +208,54 @@ pub(crate) fn build_request_error_from_body<E>(
                 Duration::default(),
             );
             let message = format_error_message("UNKNOWN", fallback_url, status, body);
-            WorkOsError::RequestError(RequestError::new(message))
+            WorkOsError::RequestError(RequestError::new(message).with_status(status))
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Organization {
+        #[allow(dead_code)]
+        domains: Vec<Domain>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Domain {
+        #[allow(dead_code)]
+        domain: String,
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_body_and_path_when_decoding_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        let body =
+            json!({ "domains": [{ "domain": "foo-corp.com" }, { "domain": 42 }] }).to_string();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(200)
+            .with_body(&body)
+            .create_async()
+            .await;
+
+        let response = reqwest::get(format!(
+            "{}/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT",
+            server.url()
+        ))
+        .await
+        .unwrap();
+        let err = response.decode_json::<Organization>().await.unwrap_err();
+
+        assert_eq!(err.body(), Some(body.as_str()));
+        assert!(err.message().contains("at `domains[1].domain`"), "{err}");
+        assert!(!err.to_string().contains("foo-corp.com"), "{err}");
+    }
+}
@@ -0,0 +1,626 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+use reqwest::{Method, Request, StatusCode};
+use url::Url;
+
+use crate::core::{IDEMPOTENCY_KEY_HEADER, SystemTime, sleep};
+use crate::{ErrorClassification, WorkOsError, WorkOsResult};
+
+/// The maximum number of times a rate limited operation is retried by
+/// [`retry_while_rate_limited`].
+const MAX_RATE_LIMIT_RETRIES: u32 = 5;
+
+/// The delay before retrying a rate limited operation when the API does not provide one.
+const DEFAULT_RATE_LIMIT_DELAY: Duration = Duration::from_secs(1);
+
+tokio::task_local! {
+    /// Set while [`retry_while_rate_limited`] runs an operation, so that rate limited
+    /// requests are only retried there and not by the client's [`RetryPolicy`] too.
+    static RATE_LIMITS_RETRIED_BY_CALLER: ();
+}
+
+/// The policy used to retry requests that failed with a transient error.
+///
+/// Requests are retried when they fail with an error classified as
+/// [`ErrorClassification::Transient`], e.g. a timeout, a refused connection, a rate limit,
+/// or a server error. The delay before each retry doubles, starting at the base delay,
+/// and never exceeds the maximum delay. Rate limited requests are retried after the delay
+/// requested by their `Retry-After` header, unless it exceeds the maximum delay, in which
+/// case [`WorkOsError::RateLimited`] is returned without retrying.
+///
+/// Only requests that are safe to repeat are retried: `GET`, `HEAD`, `OPTIONS`, `PUT`, and
+/// `DELETE` requests, and other requests that carry an
+/// [`IdempotencyKey`](crate::IdempotencyKey). Other requests, such as a `POST` that
+/// authenticates with a single-use code, may already have been applied by WorkOS when they
+/// time out or fail with a server error, so they are only retried when rate limited.
+///
+/// Use [`WorkOs::without_retries`](crate::WorkOs::without_retries) to send a single
+/// request without retrying it.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use reqwest::StatusCode;
+/// use workos_sdk::{ApiKey, RetryPolicy, WorkOs};
+///
+/// let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+///     .retry_policy(
+///         RetryPolicy::new(3)
+///             .base_delay(Duration::from_millis(100))
+///             .jitter(true)
+///             .retry_on_status([StatusCode::BAD_GATEWAY, StatusCode::SERVICE_UNAVAILABLE]),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_on_status: Option<Vec<StatusCode>>,
+}
+
+impl RetryPolicy {
+    /// Returns a policy that sends each request at most `max_attempts` times.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: false,
+            retry_on_status: None,
+        }
+    }
+
+    /// Sets the delay before the first retry. Defaults to 200 milliseconds.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the maximum delay before a retry. Defaults to 5 seconds.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets whether each exponential delay is randomized to between half and all of its
+    /// value, so that clients failing at the same time do not retry in lockstep.
+    /// Defaults to `false`.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the response statuses that are retried, instead of every status classified
+    /// as [`ErrorClassification::Transient`].
+    ///
+    /// Requests that fail without a response, e.g. because the connection was refused,
+    /// are still retried when their error is transient.
+    pub fn retry_on_status(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.retry_on_status = Some(statuses.into_iter().collect());
+        self
+    }
+
+    /// Returns the maximum number of times a request is sent.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the exponential delay before retrying the given failed attempt, starting at 1.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay.mul_f64(0.5 + random_fraction() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// Returns the delay before retrying the given failed attempt, or `None` if the request
+    /// should not be retried.
+    ///
+    /// The `Retry-After` value of a rate limited response is honored in full and takes
+    /// precedence over the exponential delay. When it exceeds the maximum delay, the request
+    /// is not retried, so that the rate limit is reported to the caller instead of being
+    /// made worse by retrying early.
+    pub(crate) fn retry_delay(&self, attempt: u32, retry_after: Option<f32>) -> Option<Duration> {
+        match retry_after.and_then(|seconds| Duration::try_from_secs_f32(seconds).ok()) {
+            Some(delay) if delay > self.max_delay => None,
+            Some(delay) => Some(delay),
+            None => Some(self.delay(attempt)),
+        }
+    }
+
+    /// Returns whether a failed attempt with the given classification and status is retried.
+    pub(crate) fn should_retry(
+        &self,
+        classification: ErrorClassification,
+        status: Option<StatusCode>,
+    ) -> bool {
+        match (&self.retry_on_status, status) {
+            (Some(statuses), Some(status)) => statuses.contains(&status),
+            _ => classification == ErrorClassification::Transient,
+        }
+    }
+}
+
+/// Returns whether the request can be sent again without risking it being applied twice.
+pub(crate) fn is_idempotent(request: &Request) -> bool {
+    matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    ) || request.headers().contains_key(IDEMPOTENCY_KEY_HEADER)
+}
+
+/// Runs the operation, running it again after the delay requested by the API while it is
+/// rate limited.
+///
+/// This is used by long-running jobs, such as streaming pages or importing users, so that
+/// they keep making progress under a rate limit. Rate limited requests sent by the
+/// operation are not retried by the client's [`RetryPolicy`], which still retries other
+/// transient failures, so the two never stack.
+pub(crate) async fn retry_while_rate_limited<F, Fut, T, E>(mut operation: F) -> WorkOsResult<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = WorkOsResult<T, E>>,
+{
+    let mut retries = 0;
+    loop {
+        match RATE_LIMITS_RETRIED_BY_CALLER.scope((), operation()).await {
+            Err(WorkOsError::RateLimited { retry_after }) if retries < MAX_RATE_LIMIT_RETRIES => {
+                retries += 1;
+
+                let delay = retry_after
+                    .and_then(|seconds| Duration::try_from_secs_f32(seconds).ok())
+                    .unwrap_or(DEFAULT_RATE_LIMIT_DELAY);
+                sleep(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Returns whether rate limited requests are retried by an enclosing
+/// [`retry_while_rate_limited`], rather than by the client's [`RetryPolicy`].
+pub(crate) fn rate_limits_retried_by_caller() -> bool {
+    RATE_LIMITS_RETRIED_BY_CALLER.try_with(|_| ()).is_ok()
+}
+
+/// Returns a pseudo-random number in `[0, 1)`, good enough to spread out retries.
+fn random_fraction() -> f64 {
+    let random = RandomState::new().hash_one(SystemTime::now());
+
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// Describes a retry about to be made, passed to the hook registered with
+/// [`WorkOsBuilder::on_retry`](crate::WorkOsBuilder::on_retry).
+#[derive(Debug)]
+pub struct RetryEvent<'a> {
+    /// The attempt that failed, starting at 1.
+    pub attempt: u32,
+
+    /// The delay before the next attempt.
+    pub delay: Duration,
+
+    /// The classification of the failure.
+    pub classification: ErrorClassification,
+
+    /// The HTTP status of the failed attempt, if a response was received.
+    pub status: Option<StatusCode>,
+
+    /// The HTTP method of the request.
+    pub method: &'a Method,
+
+    /// The URL of the request.
+    pub url: &'a Url,
+}
+
+/// A hook that is called before each retry.
+pub(crate) type OnRetry = dyn Fn(&RetryEvent<'_>) + Send + Sync;
+
+#[cfg(feature = "tracing")]
+pub(crate) fn log_retry(event: &RetryEvent<'_>) {
+    tracing::warn!(
+        method = tracing::field::display(event.method),
+        url = tracing::field::display(event.url),
+        attempt = event.attempt,
+        delay_ms = event.delay.as_millis(),
+        classification = tracing::field::debug(event.classification),
+        status = event.status.map(|status| status.as_u16()),
+        "retrying request"
+    );
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_retry(event: &RetryEvent<'_>) {
+    log::warn!(
+        target: crate::core::LOG_TARGET,
+        "retrying request method={} url={} attempt={} delay_ms={} classification={:?} status={:?}",
+        event.method,
+        event.url,
+        event.attempt,
+        event.delay.as_millis(),
+        event.classification,
+        event.status.map(|status| status.as_u16())
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+pub(crate) fn log_retry(event: &RetryEvent<'_>) {
+    let _ = event;
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::{ApiKey, WorkOs};
+
+    #[test]
+    fn it_doubles_the_delay_up_to_the_maximum() {
+        let policy = RetryPolicy::new(5)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300));
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn it_honors_retry_after_up_to_the_maximum_delay() {
+        let policy = RetryPolicy::new(5)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300));
+
+        assert_eq!(
+            policy.retry_delay(1, Some(0.25)),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(policy.retry_delay(1, Some(10.0)), None);
+        assert_eq!(
+            policy.retry_delay(2, None),
+            Some(Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn it_randomizes_the_delay_with_jitter() {
+        let policy = RetryPolicy::new(5)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300))
+            .jitter(true);
+
+        for _ in 0..100 {
+            let delay = policy.delay(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+
+            let delay = policy.delay(5);
+            assert!(delay >= Duration::from_millis(150) && delay <= Duration::from_millis(300));
+        }
+
+        assert_eq!(
+            policy.retry_delay(1, Some(0.25)),
+            Some(Duration::from_millis(250))
+        );
+    }
+
+    #[test]
+    fn it_only_retries_the_configured_statuses() {
+        let policy = RetryPolicy::new(3).retry_on_status([StatusCode::BAD_GATEWAY]);
+
+        assert!(policy.should_retry(
+            ErrorClassification::Transient,
+            Some(StatusCode::BAD_GATEWAY)
+        ));
+        assert!(!policy.should_retry(
+            ErrorClassification::Transient,
+            Some(StatusCode::SERVICE_UNAVAILABLE)
+        ));
+        assert!(policy.should_retry(ErrorClassification::Transient, None));
+        assert!(!policy.should_retry(ErrorClassification::Permanent, None));
+    }
+
+    #[tokio::test]
+    async fn it_retries_transient_failures_and_reports_each_retry() {
+        let mut server = mockito::Server::new_async().await;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .on_retry(move |event: &RetryEvent<'_>| {
+                recorded.lock().unwrap().push((
+                    event.attempt,
+                    event.classification,
+                    event.status,
+                    event.url.path().to_string(),
+                ));
+            })
+            .build()
+            .unwrap();
+
+        let failing = server
+            .mock("GET", "/organizations")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let succeeding = server
+            .mock("GET", "/organizations")
+            .with_status(200)
+            .with_body("{}")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/organizations").unwrap();
+        let response = workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        failing.assert_async().await;
+        succeeding.assert_async().await;
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                (
+                    1,
+                    ErrorClassification::Transient,
+                    Some(StatusCode::SERVICE_UNAVAILABLE),
+                    "/organizations".to_string()
+                ),
+                (
+                    2,
+                    ErrorClassification::Transient,
+                    Some(StatusCode::SERVICE_UNAVAILABLE),
+                    "/organizations".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_other_failures() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/organizations")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/organizations").unwrap();
+        let response = workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_requests_sent_without_retries() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/organizations")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let workos = workos.without_retries();
+        let url = workos.base_url().join("/organizations").unwrap();
+        let response = workos.send::<()>(workos.client().get(url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_retries_posts_with_an_idempotency_key() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        let failing = server
+            .mock("POST", "/organizations")
+            .match_header(IDEMPOTENCY_KEY_HEADER, "key_123")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let succeeding = server
+            .mock("POST", "/organizations")
+            .match_header(IDEMPOTENCY_KEY_HEADER, "key_123")
+            .with_status(201)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/organizations").unwrap();
+        let response = workos
+            .send::<()>(
+                workos
+                    .client()
+                    .post(url)
+                    .header(IDEMPOTENCY_KEY_HEADER, "key_123"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        failing.assert_async().await;
+        succeeding.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_retries_rate_limited_posts_without_an_idempotency_key() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(2).base_delay(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        let rate_limited = server
+            .mock("POST", "/organizations")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(1)
+            .create_async()
+            .await;
+        let succeeding = server
+            .mock("POST", "/organizations")
+            .with_status(201)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/organizations").unwrap();
+        let response = workos.send::<()>(workos.client().post(url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        rate_limited.assert_async().await;
+        succeeding.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_rate_limits_that_ask_to_wait_longer_than_the_maximum_delay() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).max_delay(Duration::from_secs(5)))
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/organizations")
+            .with_status(429)
+            .with_header("Retry-After", "60")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = workos.base_url().join("/organizations").unwrap();
+        let result = workos.send::<()>(workos.client().get(url)).await;
+
+        matches::assert_matches!(
+            result,
+            Err(WorkOsError::RateLimited {
+                retry_after: Some(60.0)
+            })
+        );
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_posts_without_an_idempotency_key() {
+        let mut server = mockito::Server::new_async().await;
+
+        let retries = Arc::new(Mutex::new(0));
+        let counted = retries.clone();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .on_retry(move |_: &RetryEvent<'_>| *counted.lock().unwrap() += 1)
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = workos
+            .base_url()
+            .join("/user_management/authenticate")
+            .unwrap();
+        let response = workos.send::<()>(workos.client().post(url)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        mock.assert_async().await;
+        assert_eq!(*retries.lock().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_posts_without_an_idempotency_key_that_time_out() {
+        // Accepts connections but never responds.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let retries = Arc::new(Mutex::new(0));
+        let counted = retries.clone();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&base_url)
+            .unwrap()
+            .timeout(Duration::from_millis(50))
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .on_retry(move |_: &RetryEvent<'_>| *counted.lock().unwrap() += 1)
+            .build()
+            .unwrap();
+
+        let url = workos
+            .base_url()
+            .join("/user_management/authenticate")
+            .unwrap();
+        let result = workos.send::<()>(workos.client().post(url)).await;
+
+        assert_eq!(
+            result.unwrap_err().classification(),
+            ErrorClassification::Transient
+        );
+        assert_eq!(*retries.lock().unwrap(), 0);
+        drop(listener);
+    }
+}
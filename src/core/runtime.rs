@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+// `wasm32-unknown-unknown` has no system clock, so there the time is read from the
+// JavaScript host.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::{Instant, SystemTime};
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::{Instant, SystemTime};
+
+/// Waits until the duration has elapsed.
+///
+/// On `wasm32` there is no Tokio timer driver, so the timer is scheduled on the JavaScript
+/// host instead.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(target_arch = "wasm32")]
+    wasmtimer::tokio::sleep(duration).await;
+}
+
+/// Runs a blocking function without blocking the async executor, resuming any panic.
+///
+/// On `wasm32` the function runs inline, as there is no thread to hand it off to.
+pub(crate) async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::task::spawn_blocking(f)
+            .await
+            .unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        f()
+    }
+}
+
+/// A marker for types that are [`Send`] on native targets.
+///
+/// On `wasm32`, where futures run on a single thread and HTTP responses are not `Send`, it
+/// is implemented for every type.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// A marker for types that are [`Send`] on native targets.
+///
+/// On `wasm32`, where futures run on a single thread and HTTP responses are not `Send`, it
+/// is implemented for every type.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// A boxed stream, which is [`Send`] on native targets.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) type BoxStream<'a, T> = futures_util::stream::BoxStream<'a, T>;
+
+/// A boxed stream, which is [`Send`] on native targets.
+#[cfg(target_arch = "wasm32")]
+pub(crate) type BoxStream<'a, T> = futures_util::stream::LocalBoxStream<'a, T>;
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use crate::core::Instant;
+use crate::{ErrorClassification, OperationError, WorkOsError};
+
+/// Returns whether a cached value fetched at `fetched_at` may be served in place of a
+/// failed refresh.
+///
+/// Stale values are only served for failures that are not caused by the request itself,
+/// such as timeouts or server errors, and only while the value is no older than its TTL
+/// plus the maximum staleness.
+pub(crate) fn may_serve_stale<E: OperationError>(
+    error: &WorkOsError<E>,
+    fetched_at: Instant,
+    ttl: Duration,
+    max_staleness: Option<Duration>,
+) -> bool {
+    let Some(max_staleness) = max_staleness else {
+        return false;
+    };
+
+    let recoverable = matches!(
+        error.classification(),
+        ErrorClassification::Transient | ErrorClassification::Permanent
+    );
+
+    recoverable && fetched_at.elapsed() < ttl.saturating_add(max_staleness)
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn log_serving_stale<E>(cache: &str, age: Duration, error: &WorkOsError<E>) {
+    tracing::warn!(
+        cache = cache,
+        age_ms = age.as_millis(),
+        error = tracing::field::display(error),
+        "refresh failed, serving stale cached value"
+    );
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_serving_stale<E>(cache: &str, age: Duration, error: &WorkOsError<E>) {
+    log::warn!(
+        target: crate::core::LOG_TARGET,
+        "refresh failed, serving stale cached value cache={cache} age_ms={} error={error}",
+        age.as_millis()
+    );
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+pub(crate) fn log_serving_stale<E>(cache: &str, age: Duration, error: &WorkOsError<E>) {
+    let _ = (cache, age, error);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RequestError;
+
+    #[test]
+    fn it_serves_stale_values_for_transient_failures_within_the_window() {
+        let error = WorkOsError::<()>::RateLimited { retry_after: None };
+
+        assert!(may_serve_stale(
+            &error,
+            Instant::now(),
+            Duration::ZERO,
+            Some(Duration::from_secs(60))
+        ));
+        assert!(!may_serve_stale(
+            &error,
+            Instant::now(),
+            Duration::ZERO,
+            None
+        ));
+        assert!(!may_serve_stale(
+            &error,
+            Instant::now(),
+            Duration::ZERO,
+            Some(Duration::ZERO)
+        ));
+    }
+
+    #[test]
+    fn it_does_not_serve_stale_values_for_configuration_errors() {
+        assert!(!may_serve_stale(
+            &WorkOsError::<()>::Unauthorized,
+            Instant::now(),
+            Duration::ZERO,
+            Some(Duration::from_secs(60))
+        ));
+        assert!(may_serve_stale(
+            &WorkOsError::<()>::RequestError(RequestError::new("connection reset")),
+            Instant::now(),
+            Duration::ZERO,
+            Some(Duration::from_secs(60))
+        ));
+    }
+}
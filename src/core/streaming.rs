@@ -0,0 +1,292 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{self, Read};
+#[cfg(not(target_arch = "wasm32"))]
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::OnceLock;
+
+#[cfg(not(target_arch = "wasm32"))]
+use bytes::{Buf, Bytes};
+#[cfg(not(target_arch = "wasm32"))]
+use futures_util::future::join;
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::sync::{mpsc, oneshot};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::RequestError;
+#[cfg(target_arch = "wasm32")]
+use crate::ResponseExt;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::core::{MAX_BODY_LOG_BYTES, response_context, truncate_for_log};
+use crate::{WorkOsError, WorkOsResult};
+
+/// The number of response chunks that may be buffered ahead of the deserializer.
+#[cfg(not(target_arch = "wasm32"))]
+const CHUNK_BUFFER_SIZE: usize = 16;
+
+#[cfg(not(target_arch = "wasm32"))]
+type BlockingJob = Box<dyn FnOnce() + Send>;
+
+/// A dedicated thread on Tokio's blocking thread pool that runs blocking functions one at
+/// a time.
+///
+/// The thread is started on first use and stops when the worker is dropped, so a worker
+/// that is kept for a whole task, such as paging through a list, hands off to the blocking
+/// thread pool once rather than once per function.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+pub(crate) struct BlockingWorker {
+    jobs: OnceLock<mpsc::UnboundedSender<BlockingJob>>,
+}
+
+/// There are no threads on `wasm32`, so there response bodies are buffered and deserialized
+/// on the async executor instead.
+#[cfg(target_arch = "wasm32")]
+#[derive(Default)]
+pub(crate) struct BlockingWorker {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl BlockingWorker {
+    /// Runs a blocking function on the worker's thread, without blocking the async
+    /// executor.
+    pub(crate) async fn run<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let job: BlockingJob = Box::new(move || {
+            let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(f)));
+        });
+
+        let jobs = self.jobs.get_or_init(|| {
+            let (jobs, mut receiver) = mpsc::unbounded_channel::<BlockingJob>();
+            tokio::task::spawn_blocking(move || {
+                while let Some(job) = receiver.blocking_recv() {
+                    job();
+                }
+            });
+
+            jobs
+        });
+        let _ = jobs.send(job);
+
+        match receiver
+            .await
+            .expect("blocking worker stopped before running the function")
+        {
+            Ok(value) => value,
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+/// A blocking [`Read`] implementation over the chunks of a response body.
+#[cfg(not(target_arch = "wasm32"))]
+struct ChunkReader {
+    receiver: mpsc::Receiver<Bytes>,
+    current: Bytes,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while !self.current.has_remaining() {
+            match self.receiver.blocking_recv() {
+                Some(chunk) => self.current = chunk,
+                None => return Ok(0),
+            }
+        }
+
+        let len = buf.len().min(self.current.remaining());
+        self.current.copy_to_slice(&mut buf[..len]);
+
+        Ok(len)
+    }
+}
+
+/// Deserializes a JSON response body as it is received, without buffering the full body.
+///
+/// This keeps peak memory low for large pages, such as directory users with large raw
+/// attributes. The body is deserialized on the provided worker, which callers keep for as
+/// long as they page through a list.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn json_streamed<T, E>(
+    mut response: Response,
+    worker: &BlockingWorker,
+) -> WorkOsResult<T, E>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let status = response.status();
+    let (method, url) = match response_context(&response) {
+        Some(context) => (context.method.to_string(), context.url),
+        None => ("UNKNOWN".to_string(), response.url().clone()),
+    };
+
+    let (sender, receiver) = mpsc::channel(CHUNK_BUFFER_SIZE);
+    let deserialize = worker.run(move || {
+        let mut deserializer = serde_json::Deserializer::from_reader(ChunkReader {
+            receiver,
+            current: Bytes::new(),
+        });
+        let value = serde_path_to_error::deserialize::<_, T>(&mut deserializer)
+            .map_err(|err| format!("at `{}`: {}", err.path(), err.inner()))?;
+        deserializer.end().map_err(|err| format!(": {err}"))?;
+
+        Ok::<_, String>(value)
+    });
+
+    // The worker only starts reading once `deserialize` is polled, so the body is
+    // downloaded concurrently.
+    let download = async {
+        // The start of the body is kept so that it can be reported if it cannot be decoded.
+        // A few bytes past the limit are kept so that a character split at the limit is not
+        // reported as invalid.
+        let mut prefix = Vec::new();
+        let mut download_error = None;
+        loop {
+            match response.chunk().await {
+                Ok(Some(chunk)) => {
+                    let remaining = (MAX_BODY_LOG_BYTES + 4).saturating_sub(prefix.len());
+                    prefix.extend_from_slice(&chunk[..chunk.len().min(remaining)]);
+
+                    // The deserializer stopped reading, so it has already failed.
+                    if sender.send(chunk).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(None) => break,
+                Err(err) => {
+                    download_error = Some(err);
+                    break;
+                }
+            }
+        }
+        drop(sender);
+
+        (prefix, download_error)
+    };
+
+    let (result, (prefix, download_error)) = join(deserialize, download).await;
+
+    if let Some(err) = download_error {
+        return Err(WorkOsError::from(err));
+    }
+
+    result.map_err(|err| {
+        // As with `ResponseExt::decode_json`, the body is only attached to the error, as it
+        // may hold credentials.
+        let body = truncate_for_log(&String::from_utf8_lossy(&prefix), MAX_BODY_LOG_BYTES);
+        WorkOsError::RequestError(
+            RequestError::new(format!(
+                "failed to decode response body from {method} {url} {err}"
+            ))
+            .with_status(status)
+            .with_body(body),
+        )
+    })
+}
+
+/// Deserializes a JSON response body once it has been received in full.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn json_streamed<T, E>(
+    response: Response,
+    _worker: &BlockingWorker,
+) -> WorkOsResult<T, E>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    response
+        .decode_json()
+        .await
+        .map_err(WorkOsError::RequestError)
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::PaginatedList;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_runs_every_function_on_the_same_thread() {
+        let worker = BlockingWorker::default();
+
+        let first = worker.run(|| thread::current().id()).await;
+        let second = worker.run(|| thread::current().id()).await;
+
+        assert_eq!(first, second);
+        assert_ne!(first, thread::current().id());
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "failed")]
+    async fn it_propagates_panics() {
+        BlockingWorker::default().run(|| panic!("failed")).await
+    }
+
+    #[tokio::test]
+    async fn it_deserializes_a_response_body_as_it_is_streamed() {
+        let mut server = mockito::Server::new_async().await;
+
+        let data: Vec<String> = (0..10_000).map(|index| format!("item_{index}")).collect();
+
+        server
+            .mock("GET", "/items")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": data,
+                    "list_metadata": {
+                        "before": null,
+                        "after": "item_9999"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = reqwest::get(format!("{}/items", server.url()))
+            .await
+            .unwrap();
+        let list = json_streamed::<PaginatedList<String>, ()>(response, &BlockingWorker::default())
+            .await
+            .unwrap();
+
+        assert_eq!(list.data, data);
+        assert_eq!(list.metadata.after, Some("item_9999".to_string()))
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_body_is_invalid_json() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/items")
+            .with_status(200)
+            .with_body("{\"data\": [")
+            .create_async()
+            .await;
+
+        let response = reqwest::get(format!("{}/items", server.url()))
+            .await
+            .unwrap();
+        let result =
+            json_streamed::<PaginatedList<String>, ()>(response, &BlockingWorker::default()).await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::RequestError(err))
+                if err.body() == Some("{\"data\": [") && !err.to_string().contains("\"data\"")
+        )
+    }
+}
@@ -1,17 +1,25 @@
 mod api_key;
+mod email;
+mod idempotency_key;
 mod metadata;
+mod one_time_code;
 mod paginated_list;
+mod paginated_stream;
 mod pagination_params;
 mod raw_attributes;
+mod secret_string;
 mod timestamps;
 mod unpaginated_list;
-mod url_encodable_vec;
 
 pub use api_key::*;
+pub use email::*;
+pub use idempotency_key::*;
 pub use metadata::*;
+pub use one_time_code::*;
 pub use paginated_list::*;
+pub use paginated_stream::*;
 pub use pagination_params::*;
 pub use raw_attributes::*;
+pub use secret_string::*;
 pub use timestamps::*;
 pub use unpaginated_list::*;
-pub(crate) use url_encodable_vec::*;
@@ -1,7 +1,9 @@
 use derive_more::{Deref, Display, From};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// An API key to authenticate with the WorkOS API.
-#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
 #[from(forward)]
 pub struct ApiKey(String);
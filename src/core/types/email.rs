@@ -0,0 +1,127 @@
+use std::str::FromStr;
+
+use derive_more::{Deref, Display};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An error returned when parsing an [`Email`] that is not syntactically valid.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("invalid email address: {0}")]
+pub struct InvalidEmailError(pub String);
+
+/// A syntactically valid email address.
+///
+/// Parsing an email address checks its syntax locally, so malformed addresses are
+/// rejected before a request is sent to WorkOS.
+///
+/// # Examples
+///
+/// ```
+/// use workos_sdk::Email;
+///
+/// let email: Email = "marcelina.davis@example.com".parse().unwrap();
+/// assert_eq!(email.as_str(), "marcelina.davis@example.com");
+///
+/// assert!("marcelina.davis".parse::<Email>().is_err());
+/// ```
+#[derive(
+    Clone, Debug, Deref, Display, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(try_from = "String")]
+pub struct Email(String);
+
+impl Email {
+    /// Returns an email address received from WorkOS, which has already validated it.
+    #[cfg_attr(not(feature = "user-management"), allow(dead_code))]
+    pub(crate) fn new_unchecked(email: String) -> Self {
+        Self(email)
+    }
+
+    /// Returns the email address as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Email {
+    type Err = InvalidEmailError;
+
+    fn from_str(email: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidEmailError(email.to_string());
+
+        if email.chars().any(char::is_whitespace) {
+            return Err(invalid());
+        }
+
+        let (local, domain) = email.rsplit_once('@').ok_or_else(invalid)?;
+        let is_valid_domain = domain
+            .split('.')
+            .all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+            && domain.contains('.');
+
+        if local.is_empty() || local.contains('@') || !is_valid_domain {
+            return Err(invalid());
+        }
+
+        Ok(Self(email.to_string()))
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = InvalidEmailError;
+
+    fn try_from(email: String) -> Result<Self, Self::Error> {
+        email.parse()
+    }
+}
+
+impl TryFrom<&str> for Email {
+    type Error = InvalidEmailError;
+
+    fn try_from(email: &str) -> Result<Self, Self::Error> {
+        email.parse()
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_accepts_valid_email_addresses() {
+        for email in ["marcelina@example.com", "marcelina+test@mail.example.co.uk"] {
+            assert_eq!(email.parse::<Email>().unwrap().as_str(), email);
+        }
+    }
+
+    #[test]
+    fn it_rejects_invalid_email_addresses() {
+        for email in [
+            "",
+            "marcelina",
+            "@example.com",
+            "marcelina@",
+            "marcelina@example",
+            "marcelina@@example.com",
+            "marcelina@example..com",
+            "marcelina davis@example.com",
+        ] {
+            assert_eq!(
+                email.parse::<Email>(),
+                Err(InvalidEmailError(email.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn it_validates_email_addresses_when_deserializing() {
+        assert!(serde_json::from_str::<Email>(r#""marcelina@example.com""#).is_ok());
+        assert!(serde_json::from_str::<Email>(r#""marcelina""#).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use derive_more::{Deref, Display, From};
+#[cfg(feature = "organizations")]
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
+
+/// The header used to send an [`IdempotencyKey`].
+pub(crate) const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// A key that makes a create request idempotent.
+///
+/// WorkOS performs the request at most once for a given key, so a request that is
+/// retried, whether by the [`RetryPolicy`](crate::RetryPolicy) or by the application,
+/// cannot create duplicate resources.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct IdempotencyKey(String);
+
+/// Sets the `Idempotency-Key` header of a request.
+#[cfg(feature = "organizations")]
+pub(crate) trait IdempotencyKeyExt {
+    /// Sets the `Idempotency-Key` header to the provided key, if any.
+    fn idempotency_key(self, key: Option<&IdempotencyKey>) -> Self;
+}
+
+#[cfg(feature = "organizations")]
+impl IdempotencyKeyExt for RequestBuilder {
+    fn idempotency_key(self, key: Option<&IdempotencyKey>) -> Self {
+        match key {
+            Some(key) => self.header(IDEMPOTENCY_KEY_HEADER, key.as_str()),
+            None => self,
+        }
+    }
+}
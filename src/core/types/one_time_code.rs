@@ -0,0 +1,80 @@
+use thiserror::Error;
+
+/// The number of digits in a WorkOS one-time code.
+pub const ONE_TIME_CODE_LENGTH: usize = 6;
+
+/// An error returned when a one-time code is not correctly formatted.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum InvalidOneTimeCode {
+    /// The code does not have the expected number of digits.
+    #[error("one-time code must be {expected} digits, got {actual}")]
+    InvalidLength {
+        /// The expected number of digits.
+        expected: usize,
+
+        /// The number of characters in the provided code.
+        actual: usize,
+    },
+
+    /// The code contains a character that is not an ASCII digit.
+    #[error("one-time code must contain only digits")]
+    InvalidCharacter,
+}
+
+/// Validates that a one-time code consists of exactly [`ONE_TIME_CODE_LENGTH`] ASCII digits.
+#[cfg(any(feature = "mfa", feature = "user-management"))]
+pub(crate) fn validate_one_time_code(code: &str) -> Result<(), InvalidOneTimeCode> {
+    let actual = code.chars().count();
+    if actual != ONE_TIME_CODE_LENGTH {
+        return Err(InvalidOneTimeCode::InvalidLength {
+            expected: ONE_TIME_CODE_LENGTH,
+            actual,
+        });
+    }
+
+    if !code.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(InvalidOneTimeCode::InvalidCharacter);
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, any(feature = "mfa", feature = "user-management")))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_accepts_six_digit_codes() {
+        assert_eq!(validate_one_time_code("012345"), Ok(()));
+    }
+
+    #[test]
+    fn it_rejects_codes_of_the_wrong_length() {
+        assert_eq!(
+            validate_one_time_code("12345"),
+            Err(InvalidOneTimeCode::InvalidLength {
+                expected: 6,
+                actual: 5
+            })
+        );
+        assert_eq!(
+            validate_one_time_code(" 123456"),
+            Err(InvalidOneTimeCode::InvalidLength {
+                expected: 6,
+                actual: 7
+            })
+        );
+    }
+
+    #[test]
+    fn it_rejects_codes_with_non_digit_characters() {
+        assert_eq!(
+            validate_one_time_code("12a456"),
+            Err(InvalidOneTimeCode::InvalidCharacter)
+        );
+        assert_eq!(
+            validate_one_time_code("١٢٣٤٥٦"),
+            Err(InvalidOneTimeCode::InvalidCharacter)
+        );
+    }
+}
@@ -0,0 +1,463 @@
+use std::collections::VecDeque;
+use std::future::{Future, ready};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use futures_util::stream::{self, StreamExt};
+use thiserror::Error;
+
+use crate::core::{BoxStream, retry_while_rate_limited};
+use crate::{MaybeSend, PaginatedList, Timestamp, WorkOsError, WorkOsResult};
+
+/// A stream of records that fetches successive pages of a paginated endpoint as needed.
+///
+/// Pages are requested lazily using the `after` cursor of the previous page. When a page
+/// is rate limited, it is retried after the delay requested by the API, so long-running
+/// jobs do not need their own throttling. These retries replace those of the client's
+/// [`RetryPolicy`](crate::RetryPolicy) for rate limits, rather than adding to them. The
+/// stream ends after the first error.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::WorkOsResult;
+/// # use workos_sdk::organizations::*;
+/// use workos_sdk::{ApiKey, PaginatedStream, PaginationParams, WorkOs};
+///
+/// # async fn run() -> WorkOsResult<(), ()> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let mut names = PaginatedStream::new(|after| {
+///     let workos = workos.clone();
+///     async move {
+///         workos
+///             .organizations()
+///             .list_organizations(&ListOrganizationsParams {
+///                 pagination: PaginationParams {
+///                     after: after.as_deref(),
+///                     ..Default::default()
+///                 },
+///                 ..Default::default()
+///             })
+///             .await
+///     }
+/// })
+/// .filter_items(|organization| !organization.domains.is_empty())
+/// .map_items(|organization| organization.name);
+///
+/// while let Some(name) = names.next().await {
+///     println!("{}", name?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PaginatedStream<'a, T, E> {
+    inner: BoxStream<'a, WorkOsResult<T, E>>,
+}
+
+struct PageState<F, T> {
+    fetch_page: F,
+    after: Option<String>,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+
+impl<'a, T, E> PaginatedStream<'a, T, E>
+where
+    T: MaybeSend + 'a,
+    E: MaybeSend + 'a,
+{
+    /// Returns a stream over the records of the pages returned by `fetch_page`.
+    ///
+    /// `fetch_page` is called with the `after` cursor of the page to fetch, which is
+    /// [`None`] for the first page.
+    pub fn new<F, Fut>(fetch_page: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + MaybeSend + 'a,
+        Fut: Future<Output = WorkOsResult<PaginatedList<T>, E>> + MaybeSend + 'a,
+    {
+        let state = PageState {
+            fetch_page,
+            after: None,
+            buffer: VecDeque::new(),
+            done: false,
+        };
+
+        let inner = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+
+                if state.done {
+                    return None;
+                }
+
+                let after = state.after.clone();
+                let page = retry_while_rate_limited(|| (state.fetch_page)(after.clone())).await;
+
+                match page {
+                    Ok(page) => {
+                        state.after = page.metadata.after;
+                        state.done = state.after.is_none();
+                        state.buffer.extend(page.data);
+                    }
+                    Err(err) => {
+                        state.done = true;
+                        return Some((Err(err), state));
+                    }
+                }
+            }
+        });
+
+        Self {
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Returns the next record, fetching the next page if needed.
+    pub async fn next(&mut self) -> Option<WorkOsResult<T, E>> {
+        self.inner.next().await
+    }
+
+    /// Keeps only the records for which the predicate returns `true`.
+    ///
+    /// Errors are always passed through.
+    pub fn filter_items(self, mut predicate: impl FnMut(&T) -> bool + MaybeSend + 'a) -> Self {
+        Self {
+            inner: Box::pin(self.inner.filter(move |result| {
+                ready(match result {
+                    Ok(item) => predicate(item),
+                    Err(_) => true,
+                })
+            })),
+        }
+    }
+
+    /// Maps each record into another type, such as an application model.
+    pub fn map_items<U: MaybeSend + 'a>(
+        self,
+        mut map: impl FnMut(T) -> U + MaybeSend + 'a,
+    ) -> PaginatedStream<'a, U, E> {
+        PaginatedStream {
+            inner: Box::pin(self.inner.map(move |result| result.map(&mut map))),
+        }
+    }
+
+    /// Ends the stream at the first record whose timestamp is not after the cutoff.
+    ///
+    /// With the default descending order, this streams the records newer than the cutoff
+    /// without fetching the pages of older records.
+    pub fn take_until(
+        self,
+        cutoff: Timestamp,
+        timestamp: impl Fn(&T) -> &Timestamp + MaybeSend + 'a,
+    ) -> Self {
+        Self {
+            inner: Box::pin(self.inner.take_while(move |result| {
+                ready(match result {
+                    Ok(item) => timestamp(item).0 > cutoff.0,
+                    Err(_) => true,
+                })
+            })),
+        }
+    }
+
+    /// Fetches every remaining record into a [`Vec`], one page at a time.
+    ///
+    /// Returns [`CollectAllError::TooManyItems`] as soon as the stream yields more than
+    /// `max_items` records, so an unexpectedly large result set cannot exhaust memory
+    /// or the rate limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::CollectAllError;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> Result<(), CollectAllError<ListUsersError>> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let user_management = workos.user_management();
+    ///
+    /// let params = ListUsersParams {
+    ///     email: Some(&"marcelina.davis@example.com".parse().unwrap()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let users = user_management
+    ///     .list_users_stream(&params)
+    ///     .collect_all(1_000)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn collect_all(mut self, max_items: usize) -> Result<Vec<T>, CollectAllError<E>> {
+        let mut items = Vec::new();
+        while let Some(item) = self.next().await {
+            if items.len() == max_items {
+                return Err(CollectAllError::TooManyItems { max_items });
+            }
+
+            items.push(item?);
+        }
+
+        Ok(items)
+    }
+}
+
+/// An error returned from [`PaginatedStream::collect_all`].
+#[derive(Debug, Error)]
+pub enum CollectAllError<E> {
+    /// The stream yielded more records than allowed.
+    #[error("more than {max_items} records were returned")]
+    TooManyItems {
+        /// The maximum number of records that was allowed.
+        max_items: usize,
+    },
+
+    /// A page could not be fetched.
+    #[error(transparent)]
+    WorkOs(#[from] WorkOsError<E>),
+}
+
+impl<T, E> Stream for PaginatedStream<'_, T, E> {
+    type Item = WorkOsResult<T, E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use matches::assert_matches;
+
+    use super::*;
+    use crate::{ApiKey, ListMetadata, ResponseExt, RetryPolicy, WorkOs};
+
+    type Record = (u32, Timestamp);
+
+    fn record(id: u32, timestamp: &str) -> Record {
+        (id, Timestamp::try_from(timestamp).unwrap())
+    }
+
+    fn page(data: Vec<Record>, after: Option<&str>) -> PaginatedList<Record> {
+        PaginatedList {
+            data,
+            metadata: ListMetadata {
+                before: None,
+                after: after.map(str::to_string),
+            },
+        }
+    }
+
+    fn pages() -> Vec<PaginatedList<Record>> {
+        vec![
+            page(
+                vec![
+                    record(4, "2024-01-04T00:00:00.000Z"),
+                    record(3, "2024-01-03T00:00:00.000Z"),
+                ],
+                Some("3"),
+            ),
+            page(
+                vec![
+                    record(2, "2024-01-02T00:00:00.000Z"),
+                    record(1, "2024-01-01T00:00:00.000Z"),
+                ],
+                None,
+            ),
+        ]
+    }
+
+    fn stream_pages(
+        requested: Arc<Mutex<Vec<Option<String>>>>,
+    ) -> PaginatedStream<'static, Record, ()> {
+        PaginatedStream::new(move |after: Option<String>| {
+            requested.lock().unwrap().push(after.clone());
+            let index = usize::from(after.is_some());
+            async move { Ok(pages().swap_remove(index)) }
+        })
+    }
+
+    async fn collect<T, E>(mut stream: PaginatedStream<'_, T, E>) -> Vec<WorkOsResult<T, E>>
+    where
+        T: Send,
+        E: Send,
+    {
+        let mut items = Vec::new();
+        while let Some(item) = stream.next().await {
+            items.push(item);
+        }
+        items
+    }
+
+    #[tokio::test]
+    async fn it_streams_records_across_pages() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let ids = collect(stream_pages(requested.clone()).map_items(|(id, _)| id))
+            .await
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![4, 3, 2, 1]);
+        assert_eq!(
+            *requested.lock().unwrap(),
+            vec![None, Some("3".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_filters_records() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let ids = collect(
+            stream_pages(requested)
+                .filter_items(|(id, _)| id % 2 == 0)
+                .map_items(|(id, _)| id),
+        )
+        .await
+        .into_iter()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![4, 2]);
+    }
+
+    #[tokio::test]
+    async fn it_stops_fetching_pages_at_the_cutoff() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let ids = collect(
+            stream_pages(requested.clone())
+                .take_until(
+                    Timestamp::try_from("2024-01-02T12:00:00.000Z").unwrap(),
+                    |(_, timestamp)| timestamp,
+                )
+                .map_items(|(id, _)| id),
+        )
+        .await
+        .into_iter()
+        .map(Result::unwrap)
+        .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![4, 3]);
+        assert_eq!(requested.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn it_retries_rate_limited_pages() {
+        let attempts = Arc::new(Mutex::new(0));
+        let counter = attempts.clone();
+
+        let stream = PaginatedStream::new(move |_after| {
+            let attempt = {
+                let mut attempts = counter.lock().unwrap();
+                *attempts += 1;
+                *attempts
+            };
+
+            async move {
+                if attempt == 1 {
+                    Err(WorkOsError::<()>::RateLimited {
+                        retry_after: Some(0.0),
+                    })
+                } else {
+                    Ok(page(vec![record(1, "2024-01-01T00:00:00.000Z")], None))
+                }
+            }
+        });
+
+        let items = collect(stream).await;
+
+        assert_eq!(items.len(), 1);
+        assert_matches!(items[0], Ok((1, _)));
+        assert_eq!(*attempts.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn it_does_not_stack_rate_limit_retries_on_the_retry_policy() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        // Each rate limited page is retried by the stream alone: 1 attempt and 5 retries,
+        // rather than 3 attempts by the retry policy for each of those.
+        let rate_limited = server
+            .mock("GET", "/organizations")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(6)
+            .create_async()
+            .await;
+
+        let stream = PaginatedStream::new(move |_after| {
+            let workos = workos.clone();
+
+            async move {
+                let url = workos.base_url().join("/organizations")?;
+
+                Ok::<_, WorkOsError<()>>(
+                    workos
+                        .send(workos.client().get(url).bearer_auth(workos.key()))
+                        .await?
+                        .handle_unauthorized_or_generic_error()
+                        .await?
+                        .json::<PaginatedList<u32>>()
+                        .await?,
+                )
+            }
+        });
+
+        let items = collect(stream).await;
+
+        assert_eq!(items.len(), 1);
+        assert_matches!(items[0], Err(WorkOsError::RateLimited { .. }));
+        rate_limited.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_collects_all_records() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let ids = stream_pages(requested)
+            .map_items(|(id, _)| id)
+            .collect_all(4)
+            .await
+            .unwrap();
+
+        assert_eq!(ids, vec![4, 3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_collecting_more_records_than_allowed() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let result = stream_pages(requested).collect_all(3).await;
+
+        assert_matches!(result, Err(CollectAllError::TooManyItems { max_items: 3 }));
+    }
+
+    #[tokio::test]
+    async fn it_ends_the_stream_after_an_error() {
+        let stream =
+            PaginatedStream::<Record, ()>::new(|_after| async { Err(WorkOsError::Unauthorized) });
+
+        let items = collect(stream).await;
+
+        assert_eq!(items.len(), 1);
+        assert_matches!(items[0], Err(WorkOsError::Unauthorized));
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A string holding sensitive data, such as a token or one-time code.
+///
+/// The value is redacted from the [`Debug`] output so that it never appears in logs
+/// or tracing spans verbatim. Use [`expose_secret`](SecretString::expose_secret)
+/// to access the underlying value.
+///
+/// Equality comparisons run in constant time with respect to the contents of the values,
+/// so comparing secrets locally does not introduce a timing side channel.
+#[derive(Clone, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Returns the underlying secret value.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for SecretString {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(self.0.as_bytes(), other.0.as_bytes())
+    }
+}
+
+/// Compares two byte slices in time that depends only on their lengths.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let difference = a
+        .iter()
+        .zip(b)
+        .fold(0u8, |difference, (a, b)| difference | (a ^ b));
+
+    std::hint::black_box(difference) == 0
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"<redacted>\"")
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{SecretString, constant_time_eq};
+
+    #[test]
+    fn it_redacts_the_value_from_debug_output() {
+        let secret = SecretString::from("super_secret_value");
+
+        assert_eq!(format!("{:?}", secret), "\"<redacted>\"");
+        assert_eq!(secret.expose_secret(), "super_secret_value")
+    }
+
+    #[test]
+    fn it_serializes_and_deserializes_as_a_plain_string() {
+        let secret: SecretString =
+            serde_json::from_str(&json!("super_secret_value").to_string()).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&secret).unwrap(),
+            json!("super_secret_value").to_string()
+        )
+    }
+
+    #[test]
+    fn it_compares_values() {
+        assert_eq!(SecretString::from("123456"), SecretString::from("123456"));
+        assert_ne!(SecretString::from("123456"), SecretString::from("123457"));
+        assert_ne!(SecretString::from("123456"), SecretString::from("1234567"));
+        assert!(constant_time_eq(b"", b""));
+    }
+}
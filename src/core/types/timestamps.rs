@@ -1,3 +1,8 @@
+#[cfg(feature = "user-management")]
+use std::time::Duration;
+
+#[cfg(feature = "user-management")]
+use chrono::Utc;
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +26,20 @@ impl TryFrom<&str> for Timestamp {
     }
 }
 
+#[cfg(feature = "user-management")]
+impl Timestamp {
+    /// Returns whether the timestamp is at or before `now`.
+    pub(crate) fn has_passed(&self, now: DateTime<Utc>) -> bool {
+        self.0 <= now
+    }
+
+    /// Returns the time remaining from `now` until the timestamp, or [`None`] if it has
+    /// passed.
+    pub(crate) fn remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        (self.0.with_timezone(&Utc) - now).to_std().ok()
+    }
+}
+
 /// The timestamps for an object.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Timestamps {
@@ -46,4 +65,27 @@ mod test {
             DateTime::parse_from_rfc3339(iso_string).map(Timestamp)
         )
     }
+
+    #[cfg(feature = "user-management")]
+    #[test]
+    fn it_reports_the_time_remaining_until_a_timestamp() {
+        use std::time::Duration;
+
+        use chrono::Utc;
+
+        let timestamp = Timestamp::try_from("2022-06-28T19:07:33.155Z").unwrap();
+        let now = |value| {
+            DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        assert!(!timestamp.has_passed(now("2022-06-28T19:07:33.154Z")));
+        assert!(timestamp.has_passed(now("2022-06-28T19:07:33.155Z")));
+        assert_eq!(
+            timestamp.remaining(now("2022-06-28T19:06:33.155Z")),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(timestamp.remaining(now("2022-06-28T19:08:33.155Z")), None);
+    }
 }
@@ -2,24 +2,34 @@
 //!
 //! [WorkOS Docs: Directory Sync Guide](https://workos.com/docs/directory-sync/guide)
 
+mod checkpoint;
 mod operations;
 mod types;
 
+pub use checkpoint::*;
 pub use operations::*;
 pub use types::*;
 
 use crate::WorkOs;
+use crate::core::BlockingWorker;
 
 /// Directory Sync.
 ///
 /// [WorkOS Docs: Directory Sync Guide](https://workos.com/docs/directory-sync/guide)
 pub struct DirectorySync<'a> {
     workos: &'a WorkOs,
+
+    /// Deserializes pages of directory users and groups, reused for every page fetched through this
+    /// instance.
+    decoder: BlockingWorker,
 }
 
 impl<'a> DirectorySync<'a> {
     /// Returns a new [`DirectorySync`] instance for the provided WorkOS client.
     pub fn new(workos: &'a WorkOs) -> Self {
-        Self { workos }
+        Self {
+            workos,
+            decoder: BlockingWorker::default(),
+        }
     }
 }
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::Timestamp;
+use crate::directory_sync::DirectoryId;
+
+/// An error returned from a [`DirectorySyncCheckpointStore`].
+pub type CheckpointStoreError = Box<dyn StdError + Send + Sync>;
+
+/// A persistent store for the checkpoints of incremental directory syncs.
+///
+/// A checkpoint is the latest `updated_at` timestamp of the directory users that a sync
+/// job has processed. Implement this trait to keep checkpoints in a database or file so
+/// that recurring jobs resume where the previous run left off.
+///
+/// See [`ScanDirectoryUserChanges`](crate::directory_sync::ScanDirectoryUserChanges).
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait DirectorySyncCheckpointStore: Send + Sync {
+    /// Returns the checkpoint for the directory, if one has been saved.
+    async fn load(
+        &self,
+        directory_id: &DirectoryId,
+    ) -> Result<Option<Timestamp>, CheckpointStoreError>;
+
+    /// Saves the checkpoint for the directory.
+    async fn save(
+        &self,
+        directory_id: &DirectoryId,
+        checkpoint: &Timestamp,
+    ) -> Result<(), CheckpointStoreError>;
+}
+
+/// A [`DirectorySyncCheckpointStore`] that keeps checkpoints in memory.
+///
+/// Checkpoints are lost when the process exits, so this is mostly useful for tests and
+/// long-running processes.
+#[derive(Debug, Default)]
+pub struct InMemoryCheckpointStore {
+    checkpoints: Mutex<BTreeMap<DirectoryId, Timestamp>>,
+}
+
+impl InMemoryCheckpointStore {
+    /// Returns a new, empty [`InMemoryCheckpointStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<DirectoryId, Timestamp>> {
+        self.checkpoints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl DirectorySyncCheckpointStore for InMemoryCheckpointStore {
+    async fn load(
+        &self,
+        directory_id: &DirectoryId,
+    ) -> Result<Option<Timestamp>, CheckpointStoreError> {
+        Ok(self.lock().get(directory_id).cloned())
+    }
+
+    async fn save(
+        &self,
+        directory_id: &DirectoryId,
+        checkpoint: &Timestamp,
+    ) -> Result<(), CheckpointStoreError> {
+        self.lock().insert(directory_id.clone(), checkpoint.clone());
+
+        Ok(())
+    }
+}
@@ -1,15 +1,19 @@
 mod delete_directory;
 mod get_directory;
 mod get_directory_group;
+mod get_directory_summary;
 mod get_directory_user;
 mod list_directories;
 mod list_directory_groups;
 mod list_directory_users;
+mod scan_directory_user_changes;
 
 pub use delete_directory::*;
 pub use get_directory::*;
 pub use get_directory_group::*;
+pub use get_directory_summary::*;
 pub use get_directory_user::*;
 pub use list_directories::*;
 pub use list_directory_groups::*;
 pub use list_directory_users::*;
+pub use scan_directory_user_changes::*;
@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectoryId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteDirectory`].
 #[derive(Debug, Serialize)]
@@ -22,8 +22,11 @@ impl From<DeleteDirectoryError> for WorkOsError<DeleteDirectoryError> {
     }
 }
 
+impl OperationError for DeleteDirectoryError {}
+
 /// [WorkOS Docs: Delete a Directory](https://workos.com/docs/reference/directory-sync/directory/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteDirectory {
     /// Deletes a [`Directory`](crate::directory_sync::Directory).
     ///
@@ -54,7 +57,8 @@ pub trait DeleteDirectory {
     ) -> WorkOsResult<(), DeleteDirectoryError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteDirectory for DirectorySync<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn delete_directory(
@@ -97,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{Directory, DirectoryId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectory`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetDirectoryError> for WorkOsError<GetDirectoryError> {
     }
 }
 
+impl OperationError for GetDirectoryError {}
+
 /// [WorkOS Docs: Get a Directory](https://workos.com/docs/reference/directory-sync/directory/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetDirectory {
     /// Retrieves a [`Directory`] by its ID.
     ///
@@ -41,7 +44,8 @@ pub trait GetDirectory {
     async fn get_directory(&self, id: &DirectoryId) -> WorkOsResult<Directory, GetDirectoryError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetDirectory for DirectorySync<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_directory(&self, id: &DirectoryId) -> WorkOsResult<Directory, GetDirectoryError> {
@@ -55,7 +59,7 @@ impl GetDirectory for DirectorySync<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<Directory>()
+            .decode_json::<Directory>()
             .await?;
 
         Ok(directory)
@@ -79,7 +83,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directories/directory_01ECAZ4NV9QMV47GW873HDCX74")
@@ -120,7 +125,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directories/directory_01ECAZ4NV9QMV47GW873HDCX74")
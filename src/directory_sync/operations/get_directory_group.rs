@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectoryGroup, DirectoryGroupId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectoryGroup`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetDirectoryGroupError> for WorkOsError<GetDirectoryGroupError> {
     }
 }
 
+impl OperationError for GetDirectoryGroupError {}
+
 /// [WorkOS Docs: Get a Directory Group](https://workos.com/docs/reference/directory-sync/group/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetDirectoryGroup {
     /// Retrieves a [`DirectoryGroup`] by its ID.
     ///
@@ -46,7 +49,8 @@ pub trait GetDirectoryGroup {
     ) -> WorkOsResult<DirectoryGroup, GetDirectoryGroupError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetDirectoryGroup for DirectorySync<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_directory_group(
@@ -63,7 +67,7 @@ impl GetDirectoryGroup for DirectorySync<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<DirectoryGroup>()
+            .decode_json::<DirectoryGroup>()
             .await?;
 
         Ok(directory_group)
@@ -87,7 +91,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server.mock(
             "GET",
@@ -130,7 +135,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
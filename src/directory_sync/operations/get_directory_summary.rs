@@ -0,0 +1,309 @@
+use async_trait::async_trait;
+
+use crate::directory_sync::{
+    DirectoryGroupsFilter, DirectoryId, DirectorySync, DirectoryUserState, DirectoryUsersFilter,
+    ListDirectoryGroups, ListDirectoryGroupsParams, ListDirectoryUsers, ListDirectoryUsersParams,
+};
+use crate::{KnownOrUnknown, PaginationParams, Timestamp, WorkOsResult};
+
+/// The number of records to request per page when summarizing a directory.
+const PAGE_SIZE: u8 = 100;
+
+/// The number of directory users in each state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DirectoryUserStateCounts {
+    /// The number of active users.
+    pub active: usize,
+
+    /// The number of inactive users.
+    pub inactive: usize,
+
+    /// The number of suspended users.
+    pub suspended: usize,
+
+    /// The number of users in a state not known to this SDK.
+    pub unknown: usize,
+}
+
+/// Summary statistics for a directory, returned from [`GetDirectorySummary`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DirectorySummary {
+    /// The total number of users in the directory.
+    pub users: usize,
+
+    /// The number of users in each state.
+    pub users_by_state: DirectoryUserStateCounts,
+
+    /// The number of users without any email address.
+    pub users_without_email: usize,
+
+    /// The total number of groups in the directory.
+    pub groups: usize,
+
+    /// The most recent time a user in the directory was updated by a sync.
+    pub last_user_update: Option<Timestamp>,
+
+    /// The most recent time a group in the directory was updated by a sync.
+    pub last_group_update: Option<Timestamp>,
+}
+
+/// Walks a directory and summarizes its users and groups.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait GetDirectorySummary {
+    /// Retrieves every user and group in the directory and returns summary statistics.
+    ///
+    /// This pages through [`ListDirectoryUsers`] and [`ListDirectoryGroups`], so its cost
+    /// grows with the size of the directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::directory_sync::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), ()> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let summary = workos
+    ///     .directory_sync()
+    ///     .get_directory_summary(&DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"))
+    ///     .await?;
+    ///
+    /// println!("{} active users", summary.users_by_state.active);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn get_directory_summary(
+        &self,
+        directory_id: &DirectoryId,
+    ) -> WorkOsResult<DirectorySummary, ()>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl GetDirectorySummary for DirectorySync<'_> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    async fn get_directory_summary(
+        &self,
+        directory_id: &DirectoryId,
+    ) -> WorkOsResult<DirectorySummary, ()> {
+        let mut summary = DirectorySummary::default();
+
+        let mut after: Option<String> = None;
+        loop {
+            let page = self
+                .list_directory_users(&ListDirectoryUsersParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        limit: Some(PAGE_SIZE),
+                        ..Default::default()
+                    },
+                    filter: DirectoryUsersFilter::Directory {
+                        directory: directory_id,
+                    },
+                })
+                .await?;
+
+            for user in page.data {
+                summary.users += 1;
+
+                let counts = &mut summary.users_by_state;
+                match user.state {
+                    KnownOrUnknown::Known(DirectoryUserState::Active) => counts.active += 1,
+                    KnownOrUnknown::Known(DirectoryUserState::Inactive) => counts.inactive += 1,
+                    KnownOrUnknown::Known(DirectoryUserState::Suspended) => counts.suspended += 1,
+                    KnownOrUnknown::Unknown(_) => counts.unknown += 1,
+                }
+
+                if !user.emails.iter().any(|email| email.value.is_some()) {
+                    summary.users_without_email += 1;
+                }
+
+                summary.last_user_update =
+                    latest(summary.last_user_update, user.timestamps.updated_at);
+            }
+
+            match page.metadata.after {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+
+        let mut after: Option<String> = None;
+        loop {
+            let page = self
+                .list_directory_groups(&ListDirectoryGroupsParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        limit: Some(PAGE_SIZE),
+                        ..Default::default()
+                    },
+                    filter: DirectoryGroupsFilter::Directory {
+                        directory: directory_id,
+                    },
+                })
+                .await?;
+
+            for group in page.data {
+                summary.groups += 1;
+                summary.last_group_update =
+                    latest(summary.last_group_update, group.timestamps.updated_at);
+            }
+
+            match page.metadata.after {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+fn latest(current: Option<Timestamp>, candidate: Timestamp) -> Option<Timestamp> {
+    match current {
+        Some(current) if current.0 >= candidate.0 => Some(current),
+        _ => Some(candidate),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::{Value, json};
+    use tokio;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn user(id: &str, state: &str, emails: Value, updated_at: &str) -> Value {
+        json!({
+            "id": id,
+            "idp_id": "2836",
+            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+            "emails": emails,
+            "state": state,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": updated_at,
+            "custom_attributes": {},
+            "raw_attributes": {}
+        })
+    }
+
+    #[tokio::test]
+    async fn it_summarizes_a_directory_across_pages() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::Exact(
+                "directory=directory_01ECAZ4NV9QMV47GW873HDCX74&limit=100&order=desc".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        user(
+                            "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                            "active",
+                            json!([{ "primary": true, "value": "marcelina@foo-corp.com" }]),
+                            "2021-06-25T19:07:33.155Z"
+                        ),
+                        user(
+                            "directory_user_01E1JG7J09H96KYP8HM9B0G5SK",
+                            "suspended",
+                            json!([]),
+                            "2021-07-01T10:00:00.000Z"
+                        )
+                    ],
+                    "list_metadata": {
+                        "before": null,
+                        "after": "directory_user_01E1JG7J09H96KYP8HM9B0G5SK"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::Exact(
+                "after=directory_user_01E1JG7J09H96KYP8HM9B0G5SK&directory=directory_01ECAZ4NV9QMV47GW873HDCX74&limit=100&order=desc".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        user(
+                            "directory_user_01E1JG7J09H96KYP8HM9B0G5SM",
+                            "deprovisioned",
+                            json!([{ "primary": true, "value": null }]),
+                            "2021-06-01T10:00:00.000Z"
+                        )
+                    ],
+                    "list_metadata": {
+                        "before": "directory_user_01E1JG7J09H96KYP8HM9B0G5SM",
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/directory_groups")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [{
+                        "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+                        "idp_id": "02grqrue4294w24",
+                        "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                        "name": "Developers",
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-26T19:07:33.155Z",
+                        "raw_attributes": {}
+                    }],
+                    "list_metadata": { "before": null, "after": null }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let summary = workos
+            .directory_sync()
+            .get_directory_summary(&DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary,
+            DirectorySummary {
+                users: 3,
+                users_by_state: DirectoryUserStateCounts {
+                    active: 1,
+                    inactive: 0,
+                    suspended: 1,
+                    unknown: 1,
+                },
+                users_without_email: 2,
+                groups: 1,
+                last_user_update: Some(Timestamp::try_from("2021-07-01T10:00:00.000Z").unwrap()),
+                last_group_update: Some(Timestamp::try_from("2021-06-26T19:07:33.155Z").unwrap()),
+            }
+        )
+    }
+}
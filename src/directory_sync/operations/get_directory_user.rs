@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectorySync, DirectoryUser, DirectoryUserId};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectoryUser`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetDirectoryUserError> for WorkOsError<GetDirectoryUserError> {
     }
 }
 
+impl OperationError for GetDirectoryUserError {}
+
 /// [WorkOS Docs: Get a Directory User](https://workos.com/docs/reference/directory-sync/user/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetDirectoryUser {
     /// Retrieves a [`DirectoryUser`] by its ID.
     ///
@@ -46,7 +49,8 @@ pub trait GetDirectoryUser {
     ) -> WorkOsResult<DirectoryUser, GetDirectoryUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetDirectoryUser for DirectorySync<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_directory_user(
@@ -63,7 +67,7 @@ impl GetDirectoryUser for DirectorySync<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<DirectoryUser>()
+            .decode_json::<DirectoryUser>()
             .await?;
 
         Ok(directory_user)
@@ -87,7 +91,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -150,7 +155,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_users/")
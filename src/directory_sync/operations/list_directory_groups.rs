@@ -1,11 +1,12 @@
 use async_trait::async_trait;
 use serde::Serialize;
 
+use crate::core::{QueryParams, json_streamed};
 use crate::directory_sync::{DirectoryGroup, DirectoryId, DirectorySync, DirectoryUserId};
-use crate::{PaginatedList, PaginationParams, ResponseExt, WorkOsResult};
+use crate::{PaginatedList, PaginatedStream, PaginationParams, ResponseExt, WorkOsResult};
 
 /// A filter for [`ListDirectoryGroups`].
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum DirectoryGroupsFilter<'a> {
     /// Retrieve directory groups within the specified directory.
@@ -22,7 +23,7 @@ pub enum DirectoryGroupsFilter<'a> {
 }
 
 /// The parameters for [`ListDirectoryGroups`].
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ListDirectoryGroupsParams<'a> {
     /// The pagination parameters to use when listing directory groups.
     #[serde(flatten)]
@@ -34,7 +35,8 @@ pub struct ListDirectoryGroupsParams<'a> {
 }
 
 /// [WorkOS Docs: List Directory Groups](https://workos.com/docs/reference/directory-sync/group/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListDirectoryGroups {
     /// Retrieves a list of [`DirectoryGroup`]s.
     ///
@@ -64,9 +66,27 @@ pub trait ListDirectoryGroups {
         &self,
         params: &ListDirectoryGroupsParams<'_>,
     ) -> WorkOsResult<PaginatedList<DirectoryGroup>, ()>;
+
+    /// Returns a [`PaginatedStream`] over every [`DirectoryGroup`] matching the parameters,
+    /// fetching successive pages as needed.
+    fn list_directory_groups_stream<'a>(
+        &'a self,
+        params: &'a ListDirectoryGroupsParams<'a>,
+    ) -> PaginatedStream<'a, DirectoryGroup, ()>
+    where
+        Self: Sync,
+    {
+        PaginatedStream::new(move |after| async move {
+            let mut params: ListDirectoryGroupsParams<'_> = params.clone();
+            params.pagination.after = after.as_deref();
+
+            self.list_directory_groups(&params).await
+        })
+    }
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListDirectoryGroups for DirectorySync<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn list_directory_groups(
@@ -74,20 +94,19 @@ impl ListDirectoryGroups for DirectorySync<'_> {
         params: &ListDirectoryGroupsParams<'_>,
     ) -> WorkOsResult<PaginatedList<DirectoryGroup>, ()> {
         let url = self.workos.base_url().join("/directory_groups")?;
-        let directory_groups = self
+        let response = self
             .workos
             .send(
                 self.workos
                     .client()
                     .get(url)
-                    .query(&params)
+                    .query(&QueryParams(params))
                     .bearer_auth(self.workos.key()),
             )
             .await?
             .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<DirectoryGroup>>()
             .await?;
+        let directory_groups = json_streamed(response, &self.decoder).await?;
 
         Ok(directory_groups)
     }
@@ -111,7 +130,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_groups")
@@ -176,7 +196,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_groups")
@@ -1,11 +1,12 @@
 use async_trait::async_trait;
 use serde::Serialize;
 
+use crate::core::{QueryParams, json_streamed};
 use crate::directory_sync::{DirectoryGroupId, DirectoryId, DirectorySync, DirectoryUser};
-use crate::{PaginatedList, PaginationParams, ResponseExt, WorkOsResult};
+use crate::{PaginatedList, PaginatedStream, PaginationParams, ResponseExt, WorkOsResult};
 
 /// A filter for [`ListDirectoryUsers`].
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum DirectoryUsersFilter<'a> {
     /// Retrieve directory users within the specified directory.
@@ -22,7 +23,7 @@ pub enum DirectoryUsersFilter<'a> {
 }
 
 /// The parameters for [`ListDirectoryUsers`].
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ListDirectoryUsersParams<'a> {
     /// The pagination parameters to use when listing directory users.
     #[serde(flatten)]
@@ -34,7 +35,8 @@ pub struct ListDirectoryUsersParams<'a> {
 }
 
 /// [WorkOS Docs: List Directory Users](https://workos.com/docs/reference/directory-sync/user/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListDirectoryUsers {
     /// Retrieves a list of [`DirectoryUser`]s.
     ///
@@ -64,30 +66,61 @@ pub trait ListDirectoryUsers {
         &self,
         params: &ListDirectoryUsersParams<'_>,
     ) -> WorkOsResult<PaginatedList<DirectoryUser>, ()>;
+
+    /// Returns a [`PaginatedStream`] over every [`DirectoryUser`] matching the parameters,
+    /// fetching successive pages as needed.
+    fn list_directory_users_stream<'a>(
+        &'a self,
+        params: &'a ListDirectoryUsersParams<'a>,
+    ) -> PaginatedStream<'a, DirectoryUser, ()>
+    where
+        Self: Sync,
+    {
+        PaginatedStream::new(move |after| async move {
+            let mut params: ListDirectoryUsersParams<'_> = params.clone();
+            params.pagination.after = after.as_deref();
+
+            self.list_directory_users(&params).await
+        })
+    }
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListDirectoryUsers for DirectorySync<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn list_directory_users(
         &self,
         params: &ListDirectoryUsersParams<'_>,
     ) -> WorkOsResult<PaginatedList<DirectoryUser>, ()> {
+        self.fetch_directory_users(params).await
+    }
+}
+
+impl DirectorySync<'_> {
+    /// Retrieves a page of directory users for operations built on top of
+    /// [`ListDirectoryUsers`].
+    ///
+    /// Listing directory users has no operation-specific errors, so the page can be
+    /// returned with the error type of the calling operation.
+    pub(crate) async fn fetch_directory_users<E: Send>(
+        &self,
+        params: &ListDirectoryUsersParams<'_>,
+    ) -> WorkOsResult<PaginatedList<DirectoryUser>, E> {
         let url = self.workos.base_url().join("/directory_users")?;
-        let directory_users = self
+        let response = self
             .workos
             .send(
                 self.workos
                     .client()
                     .get(url)
-                    .query(&params)
+                    .query(&QueryParams(params))
                     .bearer_auth(self.workos.key()),
             )
             .await?
             .handle_unauthorized_or_generic_error()
-            .await?
-            .json::<PaginatedList<DirectoryUser>>()
             .await?;
+        let directory_users = json_streamed(response, &self.decoder).await?;
 
         Ok(directory_users)
     }
@@ -111,7 +144,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_users")
@@ -233,7 +267,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/directory_users")
@@ -0,0 +1,441 @@
+use std::collections::BTreeSet;
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::directory_sync::{
+    CheckpointStoreError, DirectoryId, DirectorySync, DirectorySyncCheckpointStore, DirectoryUser,
+    DirectoryUsersFilter, ListDirectoryUsersParams,
+};
+use crate::{OperationError, PaginationParams, Timestamp, WorkOsError, WorkOsResult};
+
+/// The number of records to request per page when scanning for changes.
+const PAGE_SIZE: u8 = 100;
+
+/// An error returned from [`ScanDirectoryUserChanges`].
+#[derive(Debug, Error)]
+pub enum ScanDirectoryUserChangesError {
+    /// The checkpoint could not be loaded from or saved to the store.
+    #[error("checkpoint store error: {0}")]
+    CheckpointStore(#[source] CheckpointStoreError),
+}
+
+impl From<ScanDirectoryUserChangesError> for WorkOsError<ScanDirectoryUserChangesError> {
+    fn from(err: ScanDirectoryUserChangesError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for ScanDirectoryUserChangesError {}
+
+/// The directory users that changed since the last checkpoint, returned from
+/// [`ScanDirectoryUserChanges`].
+#[derive(Debug)]
+pub struct DirectoryUserChanges {
+    /// The ID of the directory.
+    pub directory_id: DirectoryId,
+
+    /// The users updated since the previous checkpoint, ordered by `updated_at`.
+    pub users: Vec<DirectoryUser>,
+
+    /// The checkpoint to save once the users have been processed.
+    ///
+    /// This is [`None`] if no users changed.
+    pub checkpoint: Option<Timestamp>,
+}
+
+impl DirectoryUserChanges {
+    /// Saves the checkpoint to the store, so that the next sync only returns users
+    /// updated since these ones.
+    ///
+    /// Call this once the users have been processed successfully. Does nothing if no
+    /// users changed.
+    pub async fn commit(
+        &self,
+        store: &dyn DirectorySyncCheckpointStore,
+    ) -> WorkOsResult<(), ScanDirectoryUserChangesError> {
+        if let Some(checkpoint) = &self.checkpoint {
+            store
+                .save(&self.directory_id, checkpoint)
+                .await
+                .map_err(ScanDirectoryUserChangesError::CheckpointStore)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Scans a directory for the users that changed since the last sync.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait ScanDirectoryUserChanges {
+    /// Retrieves every user in the directory and keeps those updated since the checkpoint
+    /// saved in the store.
+    ///
+    /// This is a full scan filtered locally, not an incremental listing: the Directory Sync
+    /// API neither filters nor sorts users by `updated_at`, so every page of users is
+    /// requested on each run. The checkpoint only reduces the users returned, not the
+    /// requests made.
+    ///
+    /// The checkpoint is not advanced automatically: call [`DirectoryUserChanges::commit`]
+    /// after processing the users, so that a failed run is retried from the same point.
+    ///
+    /// Users updated at the checkpoint itself are returned again, so that changes sharing
+    /// its timestamp are not missed; processing them must be idempotent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::directory_sync::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), ScanDirectoryUserChangesError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let store = InMemoryCheckpointStore::new();
+    ///
+    /// let changes = workos
+    ///     .directory_sync()
+    ///     .scan_directory_user_changes(
+    ///         &DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
+    ///         &store,
+    ///     )
+    ///     .await?;
+    ///
+    /// for user in &changes.users {
+    ///     println!("{} changed", user.id);
+    /// }
+    ///
+    /// changes.commit(&store).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn scan_directory_user_changes(
+        &self,
+        directory_id: &DirectoryId,
+        store: &dyn DirectorySyncCheckpointStore,
+    ) -> WorkOsResult<DirectoryUserChanges, ScanDirectoryUserChangesError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ScanDirectoryUserChanges for DirectorySync<'_> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, store)))]
+    async fn scan_directory_user_changes(
+        &self,
+        directory_id: &DirectoryId,
+        store: &dyn DirectorySyncCheckpointStore,
+    ) -> WorkOsResult<DirectoryUserChanges, ScanDirectoryUserChangesError> {
+        let previous = store
+            .load(directory_id)
+            .await
+            .map_err(ScanDirectoryUserChangesError::CheckpointStore)?;
+
+        let mut users = Vec::new();
+        let mut seen = BTreeSet::new();
+        let mut after: Option<String> = None;
+        loop {
+            let page = self
+                .fetch_directory_users(&ListDirectoryUsersParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        limit: Some(PAGE_SIZE),
+                        ..Default::default()
+                    },
+                    filter: DirectoryUsersFilter::Directory {
+                        directory: directory_id,
+                    },
+                })
+                .await?;
+
+            // Users are listed by creation, so a user created long ago may have been
+            // updated since the checkpoint: every page has to be checked.
+            for user in page.data {
+                let is_changed = previous
+                    .as_ref()
+                    .is_none_or(|previous| user.timestamps.updated_at.0 >= previous.0);
+                if is_changed && seen.insert(user.id.clone()) {
+                    users.push(user);
+                }
+            }
+
+            match page.metadata.after {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+
+        users.sort_by_key(|user| user.timestamps.updated_at.0);
+        let checkpoint = users.last().map(|user| user.timestamps.updated_at.clone());
+
+        Ok(DirectoryUserChanges {
+            directory_id: directory_id.clone(),
+            users,
+            checkpoint,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::directory_sync::{DirectoryUserId, InMemoryCheckpointStore};
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    /// Mocks three pages of users, most recently created first, returning the mock of the
+    /// last page. The second page repeats a user from the first, as happens when a user is
+    /// created while paginating, and the last page holds the oldest user, which was
+    /// updated most recently.
+    async fn mock_users(server: &mut mockito::ServerGuard) -> mockito::Mock {
+        server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::Exact(
+                "directory=directory_01ECAZ4NV9QMV47GW873HDCX74&limit=100&order=desc".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        {
+                            "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                            "idp_id": "2836",
+                            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                            "emails": [],
+                            "state": "active",
+                            "created_at": "2021-07-01T10:00:00.000Z",
+                            "updated_at": "2021-07-01T10:00:00.000Z",
+                            "custom_attributes": {},
+                            "raw_attributes": {}
+                        },
+                        {
+                            "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SK",
+                            "idp_id": "2836",
+                            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                            "emails": [],
+                            "state": "active",
+                            "created_at": "2021-06-20T10:00:00.000Z",
+                            "updated_at": "2021-06-20T10:00:00.000Z",
+                            "custom_attributes": {},
+                            "raw_attributes": {}
+                        }
+                    ],
+                    "list_metadata": {
+                        "before": null,
+                        "after": "directory_user_01E1JG7J09H96KYP8HM9B0G5SK"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::Exact(
+                "after=directory_user_01E1JG7J09H96KYP8HM9B0G5SK&directory=directory_01ECAZ4NV9QMV47GW873HDCX74&limit=100&order=desc".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        {
+                            "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SK",
+                            "idp_id": "2836",
+                            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                            "emails": [],
+                            "state": "active",
+                            "created_at": "2021-06-20T10:00:00.000Z",
+                            "updated_at": "2021-06-20T10:00:00.000Z",
+                            "custom_attributes": {},
+                            "raw_attributes": {}
+                        },
+                        {
+                            "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SM",
+                            "idp_id": "2836",
+                            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                            "emails": [],
+                            "state": "active",
+                            "created_at": "2021-06-01T10:00:00.000Z",
+                            "updated_at": "2021-06-01T10:00:00.000Z",
+                            "custom_attributes": {},
+                            "raw_attributes": {}
+                        }
+                    ],
+                    "list_metadata": {
+                        "before": "directory_user_01E1JG7J09H96KYP8HM9B0G5SK",
+                        "after": "directory_user_01E1JG7J09H96KYP8HM9B0G5SM"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/directory_users")
+            .match_query(Matcher::Exact(
+                "after=directory_user_01E1JG7J09H96KYP8HM9B0G5SM&directory=directory_01ECAZ4NV9QMV47GW873HDCX74&limit=100&order=desc".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        {
+                            "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SN",
+                            "idp_id": "2836",
+                            "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                            "emails": [],
+                            "state": "active",
+                            "created_at": "2021-05-01T10:00:00.000Z",
+                            "updated_at": "2021-07-02T10:00:00.000Z",
+                            "custom_attributes": {},
+                            "raw_attributes": {}
+                        }
+                    ],
+                    "list_metadata": {
+                        "before": "directory_user_01E1JG7J09H96KYP8HM9B0G5SN",
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_returns_every_user_without_a_checkpoint() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        mock_users(&mut server).await;
+
+        let store = InMemoryCheckpointStore::new();
+        let directory_id = DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74");
+
+        let changes = workos
+            .directory_sync()
+            .scan_directory_user_changes(&directory_id, &store)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            changes
+                .users
+                .iter()
+                .map(|user| user.id.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SM"),
+                DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SK"),
+                DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"),
+                DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SN"),
+            ]
+        );
+        assert_eq!(store.load(&directory_id).await.unwrap(), None);
+
+        changes.commit(&store).await.unwrap();
+
+        assert_eq!(
+            store.load(&directory_id).await.unwrap(),
+            Some(Timestamp::try_from("2021-07-02T10:00:00.000Z").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn it_returns_users_updated_since_the_checkpoint() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let last_page = mock_users(&mut server).await.expect(1);
+
+        let store = InMemoryCheckpointStore::new();
+        let directory_id = DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74");
+        store
+            .save(
+                &directory_id,
+                &Timestamp::try_from("2021-06-20T10:00:00.000Z").unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let changes = workos
+            .directory_sync()
+            .scan_directory_user_changes(&directory_id, &store)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            changes
+                .users
+                .iter()
+                .map(|user| user.id.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SK"),
+                DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"),
+                DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SN"),
+            ]
+        );
+        last_page.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_old_user_updated_since_the_checkpoint() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        mock_users(&mut server).await;
+
+        let store = InMemoryCheckpointStore::new();
+        let directory_id = DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74");
+        store
+            .save(
+                &directory_id,
+                &Timestamp::try_from("2021-07-01T12:00:00.000Z").unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let changes = workos
+            .directory_sync()
+            .scan_directory_user_changes(&directory_id, &store)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            changes
+                .users
+                .iter()
+                .map(|user| user.id.clone())
+                .collect::<Vec<_>>(),
+            vec![DirectoryUserId::from(
+                "directory_user_01E1JG7J09H96KYP8HM9B0G5SN"
+            )]
+        );
+        assert_eq!(
+            changes.checkpoint,
+            Some(Timestamp::try_from("2021-07-02T10:00:00.000Z").unwrap())
+        );
+    }
+}
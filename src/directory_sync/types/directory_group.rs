@@ -1,4 +1,5 @@
 use derive_more::{Deref, Display, From};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::directory_sync::DirectoryId;
@@ -14,7 +15,7 @@ pub struct DirectoryGroupId(String);
 
 /// [WorkOS Docs: Directory Group](https://workos.com/docs/reference/directory-sync/directory-group)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-pub struct DirectoryGroup {
+pub struct DirectoryGroup<TRawAttributes = RawAttributes> {
     /// Unique identifier for the Directory Group.
     pub id: DirectoryGroupId,
 
@@ -36,13 +37,21 @@ pub struct DirectoryGroup {
     pub timestamps: Timestamps,
 
     /// The raw attributes received from the Identity Provider.
-    pub raw_attributes: RawAttributes,
+    pub raw_attributes: TRawAttributes,
+}
+
+impl DirectoryGroup {
+    /// Deserializes the raw attributes of the [`DirectoryGroup`] into the provided type.
+    pub fn raw_attributes_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::to_value(&self.raw_attributes).and_then(serde_json::from_value)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
 
+    use serde::Deserialize;
     use serde_json::{Value, json};
 
     use crate::organizations::OrganizationId;
@@ -87,4 +96,69 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn it_deserializes_a_directory_group_with_a_provided_raw_attributes_type() {
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct MyRawAttributes {
+            #[serde(rename = "distinguishedName")]
+            pub distinguished_name: String,
+        }
+
+        let directory_group: DirectoryGroup<MyRawAttributes> = serde_json::from_str(
+            &json!({
+              "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+              "idp_id": "02grqrue4294w24",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+              "name": "Developers",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "raw_attributes": {
+                "distinguishedName": "CN=Developers,OU=Groups,DC=example,DC=com"
+            }})
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory_group.raw_attributes,
+            MyRawAttributes {
+                distinguished_name: "CN=Developers,OU=Groups,DC=example,DC=com".to_string()
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_the_raw_attributes_of_a_directory_group_into_a_provided_type() {
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct MyRawAttributes {
+            pub idp_id: String,
+        }
+
+        let directory_group: DirectoryGroup = serde_json::from_str(
+            &json!({
+              "id": "directory_group_01E1JJS84MFPPQ3G655FHTKX6Z",
+              "idp_id": "02grqrue4294w24",
+              "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+              "organization_id": "org_01EZTR6WYX1A0DSE2CYMGXQ24Y",
+              "name": "Developers",
+              "created_at": "2021-06-25T19:07:33.155Z",
+              "updated_at": "2021-06-25T19:07:33.155Z",
+              "raw_attributes": {
+                "idp_id": "12345"
+            }})
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory_group
+                .raw_attributes_as::<MyRawAttributes>()
+                .unwrap(),
+            MyRawAttributes {
+                idp_id: "12345".to_string()
+            }
+        )
+    }
 }
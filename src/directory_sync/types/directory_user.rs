@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use derive_more::{Deref, Display, From};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -57,7 +58,7 @@ pub struct DirectoryUser<TCustomAttributes = HashMap<String, Value>> {
     pub timestamps: Timestamps,
 }
 
-impl DirectoryUser {
+impl<TCustomAttributes> DirectoryUser<TCustomAttributes> {
     /// Returns the first primary email for the [`DirectoryUser`].
     ///
     /// Returns [`None`] if the directory user does not have a primary email.
@@ -66,6 +67,16 @@ impl DirectoryUser {
     }
 }
 
+impl DirectoryUser {
+    /// Deserializes the custom attributes of the [`DirectoryUser`] into the provided type.
+    ///
+    /// Operations return directory users with untyped custom attributes; use this to
+    /// read them into your own type.
+    pub fn custom_attributes_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::to_value(&self.custom_attributes).and_then(serde_json::from_value)
+    }
+}
+
 /// The state of a [`DirectoryUser`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -236,9 +247,49 @@ mod test {
         )
     }
 
+    #[test]
+    fn it_deserializes_the_custom_attributes_of_a_directory_user_into_a_provided_type() {
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct MyCustomAttributes {
+            pub department: String,
+            pub manager_email: Option<String>,
+        }
+
+        let directory_user: DirectoryUser = serde_json::from_str(
+            &json!({
+                "id": "directory_user_01E1JG7J09H96KYP8HM9B0G5SJ",
+                "idp_id": "2836",
+                "directory_id": "directory_01ECAZ4NV9QMV47GW873HDCX74",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "emails": [],
+                "username": "marcelina@foo-corp.com",
+                "state": "active",
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z",
+                "custom_attributes": {
+                    "department": "Engineering"
+                },
+                "raw_attributes": {}
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            directory_user
+                .custom_attributes_as::<MyCustomAttributes>()
+                .unwrap(),
+            MyCustomAttributes {
+                department: "Engineering".to_string(),
+                manager_email: None,
+            }
+        )
+    }
+
     #[test]
     fn it_returns_the_primary_email_when_the_user_has_a_primary_email() {
-        let directory_user = DirectoryUser {
+        let directory_user: DirectoryUser = DirectoryUser {
             id: DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"),
             idp_id: "2836".to_string(),
             directory_id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
@@ -274,7 +325,7 @@ mod test {
 
     #[test]
     fn it_returns_none_for_the_primary_email_when_the_user_does_not_have_a_primary_email() {
-        let directory_user = DirectoryUser {
+        let directory_user: DirectoryUser = DirectoryUser {
             id: DirectoryUserId::from("directory_user_01E1JG7J09H96KYP8HM9B0G5SJ"),
             idp_id: "2836".to_string(),
             directory_id: DirectoryId::from("directory_01ECAZ4NV9QMV47GW873HDCX74"),
@@ -0,0 +1,35 @@
+//! A module for interacting with the WorkOS Events API.
+//!
+//! [WorkOS Docs: Events](https://workos.com/docs/events)
+
+mod cursor;
+mod operations;
+mod types;
+
+pub use cursor::*;
+pub use operations::*;
+pub use types::*;
+
+use crate::WorkOs;
+use crate::core::BlockingWorker;
+
+/// Events.
+///
+/// [WorkOS Docs: Events](https://workos.com/docs/events)
+pub struct Events<'a> {
+    workos: &'a WorkOs,
+
+    /// Deserializes pages of events, reused for every page fetched through this
+    /// instance.
+    decoder: BlockingWorker,
+}
+
+impl<'a> Events<'a> {
+    /// Returns a new [`Events`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self {
+            workos,
+            decoder: BlockingWorker::default(),
+        }
+    }
+}
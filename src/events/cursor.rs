@@ -0,0 +1,58 @@
+use std::error::Error as StdError;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+/// An error returned from an [`EventCursorStore`].
+pub type EventCursorStoreError = Box<dyn StdError + Send + Sync>;
+
+/// A persistent store for the cursor of an [`EventStream`](crate::events::EventStream).
+///
+/// The cursor is the ID of the last event that a consumer has processed. Implement this
+/// trait to keep the cursor in a database or file so that a sync pipeline resumes where
+/// it left off after a restart. Each consumer should use its own store.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait EventCursorStore: Send + Sync {
+    /// Returns the cursor, if one has been saved.
+    async fn load(&self) -> Result<Option<String>, EventCursorStoreError>;
+
+    /// Saves the cursor.
+    async fn save(&self, cursor: &str) -> Result<(), EventCursorStoreError>;
+}
+
+/// An [`EventCursorStore`] that keeps the cursor in memory.
+///
+/// The cursor is lost when the process exits, so this is mostly useful for tests and
+/// long-running processes.
+#[derive(Debug, Default)]
+pub struct InMemoryEventCursorStore {
+    cursor: Mutex<Option<String>>,
+}
+
+impl InMemoryEventCursorStore {
+    /// Returns a new, empty [`InMemoryEventCursorStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<String>> {
+        self.cursor
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl EventCursorStore for InMemoryEventCursorStore {
+    async fn load(&self) -> Result<Option<String>, EventCursorStoreError> {
+        Ok(self.lock().clone())
+    }
+
+    async fn save(&self, cursor: &str) -> Result<(), EventCursorStoreError> {
+        *self.lock() = Some(cursor.to_string());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+mod list_events;
+mod stream_events;
+
+pub use list_events::*;
+pub use stream_events::*;
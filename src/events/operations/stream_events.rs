@@ -0,0 +1,403 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use futures_util::stream::{self, StreamExt};
+use thiserror::Error;
+
+use crate::core::{BoxStream, sleep};
+use crate::events::{
+    Event, EventCursorStore, EventCursorStoreError, EventType, Events, ListEventsParams,
+};
+use crate::organizations::OrganizationId;
+use crate::{OperationError, PaginatedStream, RetryPolicy, WorkOs, WorkOsError, WorkOsResult};
+
+/// The delay before polling once the stream has caught up, unless configured otherwise.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The maximum delay between polls while no new events occur, unless the configured
+/// poll interval is longer.
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The parameters for [`StreamEvents`].
+#[derive(Clone, Debug)]
+pub struct StreamEventsParams {
+    /// The types of events to stream.
+    pub events: Vec<EventType>,
+
+    /// Only stream events for the organization with this ID.
+    pub organization_id: Option<OrganizationId>,
+
+    /// The number of events to request per page, between 1 and 100.
+    pub limit: Option<u8>,
+
+    /// The delay before polling once the stream has caught up. Defaults to 5 seconds.
+    ///
+    /// The delay doubles after each poll that returns no new events, up to 1 minute.
+    pub poll_interval: Option<Duration>,
+}
+
+/// An error returned from [`StreamEvents`].
+#[derive(Debug, Error)]
+pub enum StreamEventsError {
+    /// The cursor could not be loaded from or saved to the store.
+    #[error("event cursor store error: {0}")]
+    CursorStore(#[source] EventCursorStoreError),
+}
+
+impl From<StreamEventsError> for WorkOsError<StreamEventsError> {
+    fn from(err: StreamEventsError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for StreamEventsError {}
+
+/// A continuous stream of [`Event`]s, returned from [`StreamEvents`].
+///
+/// The stream never ends on its own: once it has caught up, it polls for new events.
+/// It ends after the first error.
+pub struct EventStream<'a> {
+    inner: BoxStream<'a, WorkOsResult<Event, StreamEventsError>>,
+}
+
+impl EventStream<'_> {
+    /// Returns the next event, waiting for one to occur if needed.
+    pub async fn next(&mut self) -> Option<WorkOsResult<Event, StreamEventsError>> {
+        self.inner.next().await
+    }
+}
+
+impl Stream for EventStream<'_> {
+    type Item = WorkOsResult<Event, StreamEventsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+struct StreamState<'a> {
+    workos: WorkOs,
+    params: StreamEventsParams,
+    store: &'a dyn EventCursorStore,
+    after: Option<String>,
+    loaded: bool,
+    pages: Option<PaginatedStream<'a, Event, StreamEventsError>>,
+    polling: RetryPolicy,
+    empty_polls: u32,
+    processed: Option<String>,
+    failed: bool,
+}
+
+impl<'a> StreamState<'a> {
+    async fn next_event(&mut self) -> WorkOsResult<Event, StreamEventsError> {
+        if !self.loaded {
+            self.after = self
+                .store
+                .load()
+                .await
+                .map_err(StreamEventsError::CursorStore)?;
+            self.loaded = true;
+        }
+
+        // The previous event has been processed once the next one is requested.
+        if let Some(cursor) = self.processed.take() {
+            self.store
+                .save(&cursor)
+                .await
+                .map_err(StreamEventsError::CursorStore)?;
+        }
+
+        loop {
+            let pages = self.pages.get_or_insert_with(|| {
+                pages_after(self.workos.clone(), self.params.clone(), self.after.clone())
+            });
+
+            match pages.next().await {
+                Some(Ok(event)) => {
+                    self.after = Some(event.id.to_string());
+                    self.processed = Some(event.id.to_string());
+                    self.empty_polls = 0;
+
+                    return Ok(event);
+                }
+                Some(Err(err)) => return Err(err),
+                None => {
+                    // The stream has caught up, so poll again from the last event, backing
+                    // off while no new events occur.
+                    self.pages = None;
+                    self.empty_polls = self.empty_polls.saturating_add(1);
+
+                    sleep(self.polling.delay(self.empty_polls)).await;
+                }
+            }
+        }
+    }
+}
+
+/// Returns a [`PaginatedStream`] over the events after the cursor, up to the latest one.
+fn pages_after<'a>(
+    workos: WorkOs,
+    params: StreamEventsParams,
+    cursor: Option<String>,
+) -> PaginatedStream<'a, Event, StreamEventsError> {
+    PaginatedStream::new(move |after: Option<String>| {
+        let workos = workos.clone();
+        let params = params.clone();
+        let after = after.or_else(|| cursor.clone());
+
+        async move {
+            workos
+                .events()
+                .fetch_events(&ListEventsParams {
+                    events: &params.events,
+                    after: after.as_deref(),
+                    limit: params.limit,
+                    organization_id: params.organization_id.as_ref(),
+                    range_start: None,
+                    range_end: None,
+                })
+                .await
+        }
+    })
+}
+
+/// [WorkOS Docs: Events](https://workos.com/docs/events)
+pub trait StreamEvents {
+    /// Returns a continuous [`EventStream`] of events, starting after the cursor saved in
+    /// the store.
+    ///
+    /// The cursor of an event is saved when the next event is requested, so an event that
+    /// was being processed when the consumer stopped is delivered again on restart.
+    /// Rate limited requests are retried after the delay requested by the API. Once the
+    /// stream has caught up, it polls for new events with an increasing delay, which is
+    /// reset as soon as a new event occurs.
+    ///
+    /// [WorkOS Docs: Events](https://workos.com/docs/events)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::events::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), StreamEventsError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let store = InMemoryEventCursorStore::new();
+    ///
+    /// let mut events = workos.events().stream_events(
+    ///     StreamEventsParams {
+    ///         events: vec![EventType::DirectoryUserCreated, EventType::DirectoryUserUpdated],
+    ///         organization_id: None,
+    ///         limit: Some(100),
+    ///         poll_interval: None,
+    ///     },
+    ///     &store,
+    /// );
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("{}", event?.event);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn stream_events<'a>(
+        &self,
+        params: StreamEventsParams,
+        store: &'a dyn EventCursorStore,
+    ) -> EventStream<'a>;
+}
+
+impl StreamEvents for Events<'_> {
+    fn stream_events<'a>(
+        &self,
+        params: StreamEventsParams,
+        store: &'a dyn EventCursorStore,
+    ) -> EventStream<'a> {
+        let poll_interval = params.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+        let state = StreamState {
+            workos: self.workos.clone(),
+            params,
+            store,
+            after: None,
+            loaded: false,
+            pages: None,
+            polling: RetryPolicy::new(u32::MAX)
+                .base_delay(poll_interval)
+                .max_delay(poll_interval.max(MAX_POLL_INTERVAL)),
+            empty_polls: 0,
+            processed: None,
+            failed: false,
+        };
+
+        let inner = stream::unfold(state, |mut state| async move {
+            if state.failed {
+                return None;
+            }
+
+            let result = state.next_event().await;
+            state.failed = result.is_err();
+
+            Some((result, state))
+        });
+
+        EventStream {
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::events::{EventId, InMemoryEventCursorStore};
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn params() -> StreamEventsParams {
+        StreamEventsParams {
+            events: vec![EventType::UserCreated],
+            organization_id: None,
+            limit: None,
+            poll_interval: Some(Duration::from_millis(10)),
+        }
+    }
+
+    fn page(ids: &[&str]) -> String {
+        json!({
+            "object": "list",
+            "data": ids
+                .iter()
+                .map(|id| json!({
+                    "object": "event",
+                    "id": id,
+                    "event": "user.created",
+                    "data": {},
+                    "created_at": "2023-06-09T18:12:01.837Z"
+                }))
+                .collect::<Vec<_>>(),
+            "list_metadata": {
+                "after": null
+            }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn it_resumes_from_the_stored_cursor_and_polls_for_new_events() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/events")
+            .match_query(Matcher::Exact(
+                "after=event_01&events=user.created".to_string(),
+            ))
+            .with_status(200)
+            .with_body(page(&["event_02", "event_03"]))
+            .create_async()
+            .await;
+
+        let empty = server
+            .mock("GET", "/events")
+            .match_query(Matcher::Exact(
+                "after=event_03&events=user.created".to_string(),
+            ))
+            .with_status(200)
+            .with_body(page(&[]))
+            .expect_at_least(2)
+            .create_async()
+            .await;
+
+        let store = InMemoryEventCursorStore::new();
+        store.save("event_01").await.unwrap();
+
+        let mut events = workos.events().stream_events(params(), &store);
+
+        let event = events.next().await.unwrap().unwrap();
+        assert_eq!(event.id, EventId::from("event_02"));
+        assert_eq!(store.load().await.unwrap(), Some("event_01".to_string()));
+
+        let event = events.next().await.unwrap().unwrap();
+        assert_eq!(event.id, EventId::from("event_03"));
+        assert_eq!(store.load().await.unwrap(), Some("event_02".to_string()));
+
+        // Once caught up, the stream keeps polling until a new event occurs.
+        let result = tokio::time::timeout(Duration::from_millis(100), events.next()).await;
+        assert!(result.is_err());
+        assert_eq!(store.load().await.unwrap(), Some("event_03".to_string()));
+        empty.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_backs_off_while_no_new_events_occur() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // With a fixed 20ms interval, 200ms of polling would take about 10 requests.
+        // Doubling the delay from 20ms polls at 0, 20, 60 and 140ms.
+        let empty = server
+            .mock("GET", "/events")
+            .match_query(Matcher::Exact("events=user.created".to_string()))
+            .with_status(200)
+            .with_body(page(&[]))
+            .expect_at_least(2)
+            .expect_at_most(5)
+            .create_async()
+            .await;
+
+        let store = InMemoryEventCursorStore::new();
+        let mut events = workos.events().stream_events(
+            StreamEventsParams {
+                poll_interval: Some(Duration::from_millis(20)),
+                ..params()
+            },
+            &store,
+        );
+
+        let result = tokio::time::timeout(Duration::from_millis(200), events.next()).await;
+        assert!(result.is_err());
+        empty.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_ends_the_stream_after_an_error() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/events")
+            .match_query(Matcher::Any)
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let store = InMemoryEventCursorStore::new();
+        let mut events = workos.events().stream_events(params(), &store);
+
+        assert_matches!(events.next().await, Some(Err(WorkOsError::Unauthorized)));
+        assert_matches!(events.next().await, None);
+    }
+}
@@ -0,0 +1,5 @@
+mod event;
+mod event_type;
+
+pub use event::*;
+pub use event_type::*;
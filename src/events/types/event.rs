@@ -0,0 +1,36 @@
+use derive_more::{Deref, Display, From};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamp;
+use crate::events::EventType;
+
+/// The ID of an [`Event`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct EventId(String);
+
+/// [WorkOS Docs: Event](https://workos.com/docs/reference/events)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    /// The unique ID of the event.
+    pub id: EventId,
+
+    /// The type of the event.
+    pub event: EventType,
+
+    /// The payload of the event, whose shape depends on its type.
+    pub data: serde_json::Value,
+
+    /// The timestamp indicating when the event was created.
+    pub created_at: Timestamp,
+}
+
+impl Event {
+    /// Deserializes the payload of the event into `T`.
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&self.data)
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! event_types {
+    ($($(#[$meta:meta])* $variant:ident => $name:literal,)*) => {
+        /// The type of an [`Event`](crate::events::Event).
+        ///
+        /// [WorkOS Docs: Event types](https://workos.com/docs/events)
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum EventType {
+            $(
+                $(#[$meta])*
+                $variant,
+            )*
+
+            /// An event type not known to this version of the SDK.
+            Other(String),
+        }
+
+        impl EventType {
+            /// Returns the name of the event type, e.g. `dsync.user.created`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $name,)*
+                    Self::Other(name) => name,
+                }
+            }
+        }
+
+        impl From<&str> for EventType {
+            fn from(name: &str) -> Self {
+                match name {
+                    $($name => Self::$variant,)*
+                    name => Self::Other(name.to_string()),
+                }
+            }
+        }
+    };
+}
+
+event_types! {
+    /// `authentication.email_verification_succeeded`
+    AuthenticationEmailVerificationSucceeded => "authentication.email_verification_succeeded",
+    /// `authentication.magic_auth_failed`
+    AuthenticationMagicAuthFailed => "authentication.magic_auth_failed",
+    /// `authentication.magic_auth_succeeded`
+    AuthenticationMagicAuthSucceeded => "authentication.magic_auth_succeeded",
+    /// `authentication.mfa_succeeded`
+    AuthenticationMfaSucceeded => "authentication.mfa_succeeded",
+    /// `authentication.oauth_succeeded`
+    AuthenticationOAuthSucceeded => "authentication.oauth_succeeded",
+    /// `authentication.password_failed`
+    AuthenticationPasswordFailed => "authentication.password_failed",
+    /// `authentication.password_succeeded`
+    AuthenticationPasswordSucceeded => "authentication.password_succeeded",
+    /// `authentication.sso_succeeded`
+    AuthenticationSsoSucceeded => "authentication.sso_succeeded",
+    /// `connection.activated`
+    ConnectionActivated => "connection.activated",
+    /// `connection.deactivated`
+    ConnectionDeactivated => "connection.deactivated",
+    /// `connection.deleted`
+    ConnectionDeleted => "connection.deleted",
+    /// `dsync.activated`
+    DirectoryActivated => "dsync.activated",
+    /// `dsync.deleted`
+    DirectoryDeleted => "dsync.deleted",
+    /// `dsync.group.created`
+    DirectoryGroupCreated => "dsync.group.created",
+    /// `dsync.group.deleted`
+    DirectoryGroupDeleted => "dsync.group.deleted",
+    /// `dsync.group.updated`
+    DirectoryGroupUpdated => "dsync.group.updated",
+    /// `dsync.group.user_added`
+    DirectoryUserAddedToGroup => "dsync.group.user_added",
+    /// `dsync.group.user_removed`
+    DirectoryUserRemovedFromGroup => "dsync.group.user_removed",
+    /// `dsync.user.created`
+    DirectoryUserCreated => "dsync.user.created",
+    /// `dsync.user.deleted`
+    DirectoryUserDeleted => "dsync.user.deleted",
+    /// `dsync.user.updated`
+    DirectoryUserUpdated => "dsync.user.updated",
+    /// `email_verification.created`
+    EmailVerificationCreated => "email_verification.created",
+    /// `invitation.created`
+    InvitationCreated => "invitation.created",
+    /// `magic_auth.created`
+    MagicAuthCreated => "magic_auth.created",
+    /// `organization.created`
+    OrganizationCreated => "organization.created",
+    /// `organization.deleted`
+    OrganizationDeleted => "organization.deleted",
+    /// `organization.updated`
+    OrganizationUpdated => "organization.updated",
+    /// `organization_domain.verification_failed`
+    OrganizationDomainVerificationFailed => "organization_domain.verification_failed",
+    /// `organization_domain.verified`
+    OrganizationDomainVerified => "organization_domain.verified",
+    /// `organization_membership.created`
+    OrganizationMembershipCreated => "organization_membership.created",
+    /// `organization_membership.deleted`
+    OrganizationMembershipDeleted => "organization_membership.deleted",
+    /// `organization_membership.updated`
+    OrganizationMembershipUpdated => "organization_membership.updated",
+    /// `password_reset.created`
+    PasswordResetCreated => "password_reset.created",
+    /// `role.created`
+    RoleCreated => "role.created",
+    /// `role.deleted`
+    RoleDeleted => "role.deleted",
+    /// `role.updated`
+    RoleUpdated => "role.updated",
+    /// `session.created`
+    SessionCreated => "session.created",
+    /// `user.created`
+    UserCreated => "user.created",
+    /// `user.deleted`
+    UserDeleted => "user.deleted",
+    /// `user.updated`
+    UserUpdated => "user.updated",
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for EventType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Ok(Self::from(name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_round_trips_known_event_types() {
+        let event_type: EventType = serde_json::from_value(json!("dsync.user.created")).unwrap();
+
+        assert_eq!(event_type, EventType::DirectoryUserCreated);
+        assert_eq!(
+            serde_json::to_value(&event_type).unwrap(),
+            json!("dsync.user.created")
+        );
+    }
+
+    #[test]
+    fn it_falls_back_to_other_for_unknown_event_types() {
+        let event_type: EventType = serde_json::from_value(json!("widget.exploded")).unwrap();
+
+        assert_eq!(event_type, EventType::Other("widget.exploded".to_string()));
+        assert_eq!(event_type.to_string(), "widget.exploded");
+    }
+}
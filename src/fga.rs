@@ -0,0 +1,25 @@
+//! A module for interacting with the WorkOS Fine-Grained Authorization (FGA) API.
+//!
+//! [WorkOS Docs: FGA](https://workos.com/docs/fga)
+
+mod operations;
+mod types;
+
+pub use operations::*;
+pub use types::*;
+
+use crate::WorkOs;
+
+/// Fine-Grained Authorization (FGA).
+///
+/// [WorkOS Docs: FGA](https://workos.com/docs/fga)
+pub struct Fga<'a> {
+    workos: &'a WorkOs,
+}
+
+impl<'a> Fga<'a> {
+    /// Returns a new [`Fga`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self { workos }
+    }
+}
@@ -0,0 +1,31 @@
+mod apply_schema;
+mod batch_check;
+mod check;
+mod create_resource;
+mod create_warrant;
+mod delete_resource;
+mod delete_warrant;
+mod get_resource;
+mod get_resource_url;
+mod get_schema;
+mod list_resource_types;
+mod list_resources;
+mod list_warrants;
+mod query;
+mod update_resource;
+
+pub use apply_schema::*;
+pub use batch_check::*;
+pub use check::*;
+pub use create_resource::*;
+pub use create_warrant::*;
+pub use delete_resource::*;
+pub use delete_warrant::*;
+pub use get_resource::*;
+pub use get_resource_url::*;
+pub use get_schema::*;
+pub use list_resource_types::*;
+pub use list_resources::*;
+pub use list_warrants::*;
+pub use query::*;
+pub use update_resource::*;
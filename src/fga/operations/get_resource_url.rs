@@ -0,0 +1,74 @@
+use url::{ParseError, Url};
+
+use crate::fga::{Fga, Resource};
+
+/// Builds the API URL of a [`Resource`].
+pub trait GetResourceUrl {
+    /// Returns the API URL of the provided resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use url::ParseError;
+    /// # use workos_sdk::fga::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let resource_url = workos
+    ///     .fga()
+    ///     .get_resource_url(&Resource::new("document", "doc_123"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_resource_url(&self, resource: &Resource) -> Result<Url, ParseError>;
+}
+
+impl GetResourceUrl for Fga<'_> {
+    fn get_resource_url(&self, resource: &Resource) -> Result<Url, ParseError> {
+        let mut url = self.workos.base_url().join("/fga/v1/resources")?;
+        url.path_segments_mut()
+            .map_err(|()| ParseError::RelativeUrlWithCannotBeABaseBase)?
+            .extend([&resource.resource_type, &resource.resource_id]);
+
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    #[test]
+    fn it_builds_a_resource_url() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let resource_url = workos
+            .fga()
+            .get_resource_url(&Resource::new("document", "doc_123"))
+            .unwrap();
+
+        assert_eq!(
+            resource_url,
+            Url::parse("https://api.workos.com/fga/v1/resources/document/doc_123").unwrap()
+        )
+    }
+
+    #[test]
+    fn it_escapes_the_resource_id() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let resource_url = workos
+            .fga()
+            .get_resource_url(&Resource::new("file", "reports/q1 2024"))
+            .unwrap();
+
+        assert_eq!(
+            resource_url.as_str(),
+            "https://api.workos.com/fga/v1/resources/file/reports%2Fq1%202024"
+        )
+    }
+}
@@ -0,0 +1,13 @@
+mod check;
+mod query;
+mod resource;
+mod resource_type;
+mod schema;
+mod warrant;
+
+pub use check::*;
+pub use query::*;
+pub use resource::*;
+pub use resource_type::*;
+pub use schema::*;
+pub use warrant::*;
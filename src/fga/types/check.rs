@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use crate::KnownOrUnknown;
+use crate::fga::{Resource, Subject, WarrantToken};
+
+/// A single check of whether a subject has a relation on a resource.
+#[derive(Clone, Debug, Serialize)]
+pub struct WarrantCheck<'a> {
+    /// The resource to check.
+    #[serde(flatten)]
+    pub resource: &'a Resource,
+
+    /// The relation to check, e.g. `viewer`.
+    pub relation: &'a str,
+
+    /// The subject to check.
+    pub subject: &'a Subject,
+
+    /// The context used to evaluate warrant policies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<&'a serde_json::Value>,
+}
+
+/// How the results of multiple checks are combined.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckOp {
+    /// Authorized if any of the checks is authorized.
+    #[default]
+    AnyOf,
+
+    /// Authorized only if all of the checks are authorized.
+    AllOf,
+}
+
+/// The result of a check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckResultStatus {
+    /// The subject has the relation on the resource.
+    Authorized,
+
+    /// The subject does not have the relation on the resource.
+    NotAuthorized,
+}
+
+/// [WorkOS Docs: Check](https://workos.com/docs/reference/fga/check)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckResult {
+    /// The result of the check.
+    pub result: KnownOrUnknown<CheckResultStatus, String>,
+
+    /// Whether the result was derived from other warrants rather than a direct warrant.
+    pub is_implicit: bool,
+
+    /// The token of the FGA data the check was evaluated against.
+    pub warrant_token: Option<WarrantToken>,
+}
+
+impl CheckResult {
+    /// Returns whether the check is authorized.
+    pub fn is_authorized(&self) -> bool {
+        self.result == KnownOrUnknown::Known(CheckResultStatus::Authorized)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_is_only_authorized_when_the_result_is_authorized() {
+        let result = |result: &str| -> CheckResult {
+            serde_json::from_value(json!({
+                "result": result,
+                "is_implicit": false
+            }))
+            .unwrap()
+        };
+
+        assert!(result("authorized").is_authorized());
+        assert!(!result("not_authorized").is_authorized());
+        assert!(!result("something_else").is_authorized());
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::fga::{Resource, Warrant};
+
+/// A resource matched by a query, along with the warrant that matched it.
+///
+/// [WorkOS Docs: Query](https://workos.com/docs/reference/fga/query)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryResult {
+    /// The matched resource.
+    #[serde(flatten)]
+    pub resource: Resource,
+
+    /// The relation the query matched on the resource.
+    pub relation: String,
+
+    /// The warrant that matched the query.
+    pub warrant: Warrant,
+
+    /// Whether the match was derived from other warrants rather than a direct warrant.
+    pub is_implicit: bool,
+
+    /// Arbitrary metadata of the matched resource.
+    pub meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// A warning about a query, e.g. that it references a relation that does not exist.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryWarning {
+    /// A code that identifies the kind of warning.
+    pub code: String,
+
+    /// A human-readable description of the warning.
+    pub message: String,
+}
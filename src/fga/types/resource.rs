@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// A reference to an FGA resource, such as a user or a document.
+///
+/// [WorkOS Docs: Resource](https://workos.com/docs/reference/fga/resource)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Resource {
+    /// The type of the resource, e.g. `document`.
+    pub resource_type: String,
+
+    /// The unique ID of the resource within its type.
+    pub resource_id: String,
+}
+
+impl Resource {
+    /// Returns a reference to the resource with the provided type and ID.
+    pub fn new(resource_type: impl Into<String>, resource_id: impl Into<String>) -> Self {
+        Self {
+            resource_type: resource_type.into(),
+            resource_id: resource_id.into(),
+        }
+    }
+}
+
+/// The subject of a warrant or check: a resource, or the set of resources that have a
+/// relation on it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Subject {
+    /// The type of the subject resource, e.g. `user`.
+    pub resource_type: String,
+
+    /// The unique ID of the subject resource within its type.
+    pub resource_id: String,
+
+    /// The relation on the subject resource, e.g. `member` for the members of a team.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation: Option<String>,
+}
+
+impl Subject {
+    /// Returns a subject for the resource with the provided type and ID.
+    pub fn new(resource_type: impl Into<String>, resource_id: impl Into<String>) -> Self {
+        Self {
+            resource_type: resource_type.into(),
+            resource_id: resource_id.into(),
+            relation: None,
+        }
+    }
+
+    /// Returns a subject for the resources that have the relation on this subject.
+    pub fn with_relation(mut self, relation: impl Into<String>) -> Self {
+        self.relation = Some(relation.into());
+        self
+    }
+}
+
+impl From<Resource> for Subject {
+    fn from(resource: Resource) -> Self {
+        Self::new(resource.resource_type, resource.resource_id)
+    }
+}
+
+/// A resource stored in FGA, along with its metadata.
+///
+/// [WorkOS Docs: Resource](https://workos.com/docs/reference/fga/resource)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceDetails {
+    /// The resource.
+    #[serde(flatten)]
+    pub resource: Resource,
+
+    /// Arbitrary metadata of the resource.
+    pub meta: Option<serde_json::Map<String, serde_json::Value>>,
+}
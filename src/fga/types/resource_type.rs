@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A type of resource in the FGA schema, along with the relations resources of that
+/// type can have.
+///
+/// [WorkOS Docs: Resource Type](https://workos.com/docs/reference/fga/resource-type)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceType {
+    /// The name of the resource type, e.g. `document`.
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    /// The relations of the resource type, keyed by name, along with the rules that
+    /// grant them implicitly.
+    pub relations: HashMap<String, serde_json::Value>,
+}
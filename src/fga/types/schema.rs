@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::fga::ResourceType;
+
+/// The FGA schema of the current environment.
+///
+/// [WorkOS Docs: Schema](https://workos.com/docs/fga/schema)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Schema {
+    /// The schema in the FGA schema language.
+    pub schema: String,
+
+    /// The resource types defined by the schema.
+    pub resource_types: Vec<ResourceType>,
+}
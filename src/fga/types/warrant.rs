@@ -0,0 +1,60 @@
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::fga::{Resource, Subject};
+
+/// The name of the header that carries the [`WarrantToken`] a read should observe.
+pub(crate) const WARRANT_TOKEN_HEADER: &str = "Warrant-Token";
+
+/// A token that identifies the state of the FGA data after a write.
+///
+/// Pass the token to subsequent checks to ensure they observe the write.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct WarrantToken(String);
+
+impl WarrantToken {
+    /// Returns a token that makes a check observe the most recent writes, at the
+    /// expense of latency.
+    pub fn latest() -> Self {
+        Self::from("latest")
+    }
+}
+
+/// The operation of a warrant write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarrantOp {
+    /// Creates the warrant.
+    Create,
+
+    /// Deletes the warrant.
+    Delete,
+}
+
+/// A relation between a resource and a subject.
+///
+/// [WorkOS Docs: Warrant](https://workos.com/docs/reference/fga/warrant)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Warrant {
+    /// The resource the subject has the relation on.
+    #[serde(flatten)]
+    pub resource: Resource,
+
+    /// The relation the subject has on the resource, e.g. `editor`.
+    pub relation: String,
+
+    /// The subject of the warrant.
+    pub subject: Subject,
+
+    /// A policy that must be satisfied for the warrant to apply.
+    pub policy: Option<String>,
+}
+
+/// The response of a warrant write.
+#[derive(Debug, Deserialize)]
+pub(crate) struct WriteWarrantResponse {
+    pub warrant_token: WarrantToken,
+}
@@ -1,21 +1,70 @@
 //! The official SDK for interacting with the [WorkOS](https://workos.com) API.
+//!
+//! The SDK also builds for `wasm32-unknown-unknown`, e.g. for Cloudflare Workers, where
+//! requests are sent with the JavaScript `fetch` API. Custom HTTP backends, dry-run mode,
+//! middleware and [`WorkOs::with_raw`] are not available there, as they rely on building
+//! responses that did not come from `fetch`, as is the `actix` feature.
 
 #![warn(missing_docs)]
+// Without any product modules enabled, the shared request plumbing is unused.
+#![cfg_attr(
+    not(any(
+        feature = "admin-portal",
+        feature = "audit-logs",
+        feature = "directory-sync",
+        feature = "events",
+        feature = "fga",
+        feature = "mfa",
+        feature = "organization-domains",
+        feature = "organizations",
+        feature = "passwordless",
+        feature = "roles",
+        feature = "sso",
+        feature = "user-management",
+        feature = "vault",
+        feature = "webhooks",
+        feature = "widgets",
+    )),
+    allow(dead_code, unused_imports)
+)]
 
 mod core;
 mod known_or_unknown;
+mod registry;
 mod workos;
 
+#[cfg(feature = "admin-portal")]
 pub mod admin_portal;
+#[cfg(feature = "audit-logs")]
+pub mod audit_logs;
+#[cfg(feature = "directory-sync")]
 pub mod directory_sync;
+#[cfg(feature = "events")]
+pub mod events;
+#[cfg(feature = "fga")]
+pub mod fga;
+#[cfg(feature = "mfa")]
 pub mod mfa;
+#[cfg(feature = "organization-domains")]
+pub mod organization_domains;
+#[cfg(feature = "organizations")]
 pub mod organizations;
+#[cfg(feature = "passwordless")]
 pub mod passwordless;
+#[cfg(feature = "roles")]
 pub mod roles;
+#[cfg(feature = "sso")]
 pub mod sso;
+#[cfg(feature = "user-management")]
 pub mod user_management;
+#[cfg(feature = "vault")]
+pub mod vault;
+#[cfg(feature = "webhooks")]
 pub mod webhooks;
+#[cfg(feature = "widgets")]
+pub mod widgets;
 
 pub use crate::core::*;
 pub use crate::workos::*;
 pub use known_or_unknown::*;
+pub use registry::*;
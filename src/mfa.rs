@@ -3,9 +3,13 @@
 //! [WorkOS Docs: MFA Guide](https://workos.com/docs/mfa/guide)
 
 mod operations;
+#[cfg(feature = "test-util")]
+mod test_util;
 mod types;
 
 pub use operations::*;
+#[cfg(feature = "test-util")]
+pub use test_util::*;
 pub use types::*;
 
 use crate::WorkOs;
@@ -1,7 +1,11 @@
 mod challenge_factor;
+mod delete_factor;
 mod enroll_factor;
+mod get_factor;
 mod verify_challenge;
 
 pub use challenge_factor::*;
+pub use delete_factor::*;
 pub use enroll_factor::*;
+pub use get_factor::*;
 pub use verify_challenge::*;
@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationFactorId, Mfa};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The type of authentication factor to challenge.
 #[derive(Debug, Serialize)]
@@ -39,8 +39,17 @@ pub struct ChallengeFactorParams<'a> {
 #[derive(Debug, Error)]
 pub enum ChallengeFactorError {}
 
+impl From<ChallengeFactorError> for WorkOsError<ChallengeFactorError> {
+    fn from(err: ChallengeFactorError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for ChallengeFactorError {}
+
 /// [WorkOS Docs: Challenge Factor](https://workos.com/docs/reference/mfa/challenge-factor)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ChallengeFactor {
     /// Creates a challenge for an authentication factor.
     ///
@@ -74,7 +83,8 @@ pub trait ChallengeFactor {
     ) -> WorkOsResult<AuthenticationChallenge, ChallengeFactorError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ChallengeFactor for Mfa<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn challenge_factor(
@@ -97,7 +107,7 @@ impl ChallengeFactor for Mfa<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<AuthenticationChallenge>()
+            .decode_json::<AuthenticationChallenge>()
             .await?;
 
         Ok(challenge)
@@ -121,7 +131,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -169,7 +180,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationChallengeId, Mfa, MfaCode};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The response for [`VerifyChallenge`].
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,7 +17,7 @@ pub struct VerifyChallengeResponse {
 }
 
 /// The parameters for [`VerifyChallenge`].
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct VerifyChallengeParams<'a> {
     /// The ID of the authentication challenge to verify.
     #[serde(skip)]
@@ -31,8 +31,17 @@ pub struct VerifyChallengeParams<'a> {
 #[derive(Debug, Error)]
 pub enum VerifyChallengeError {}
 
+impl From<VerifyChallengeError> for WorkOsError<VerifyChallengeError> {
+    fn from(err: VerifyChallengeError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for VerifyChallengeError {}
+
 /// [WorkOS Docs: Verify Challenge](https://workos.com/docs/reference/mfa/verify-challenge)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait VerifyChallenge {
     /// Attempts a verification for an authentication challenge.
     ///
@@ -66,7 +75,8 @@ pub trait VerifyChallenge {
     ) -> WorkOsResult<VerifyChallengeResponse, VerifyChallengeError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl VerifyChallenge for Mfa<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn verify_challenge(
@@ -89,7 +99,7 @@ impl VerifyChallenge for Mfa<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<VerifyChallengeResponse>()
+            .decode_json::<VerifyChallengeResponse>()
             .await?;
 
         Ok(verify_response)
@@ -113,7 +123,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
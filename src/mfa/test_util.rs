@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use thiserror::Error;
+
+/// The number of seconds each TOTP code is valid for.
+const TOTP_PERIOD_SECONDS: i64 = 30;
+
+/// The number of digits in a TOTP code.
+const TOTP_DIGITS: u32 = 6;
+
+/// An error returned from [`generate_totp_code`].
+#[derive(Debug, Error)]
+pub enum GenerateTotpCodeError {
+    /// The secret is not valid base32.
+    #[error("invalid base32 secret: {0}")]
+    InvalidSecret(#[from] data_encoding::DecodeError),
+}
+
+/// Derives the current 6-digit TOTP code from the base32-encoded secret of an enrolled factor.
+///
+/// This is intended for end-to-end tests that need to complete an MFA challenge
+/// without a physical authenticator device.
+///
+/// # Examples
+///
+/// ```
+/// use workos_sdk::mfa::generate_totp_code;
+///
+/// let code = generate_totp_code("NAGCCFS3EYRB422HNAKAKY3XDUORMSRF").unwrap();
+///
+/// assert_eq!(code.len(), 6);
+/// ```
+pub fn generate_totp_code(secret: &str) -> Result<String, GenerateTotpCodeError> {
+    generate_totp_code_at(secret, Utc::now())
+}
+
+/// Derives the 6-digit TOTP code that is valid at the provided time.
+pub fn generate_totp_code_at(
+    secret: &str,
+    time: DateTime<Utc>,
+) -> Result<String, GenerateTotpCodeError> {
+    let normalized = secret
+        .trim_end_matches('=')
+        .replace(' ', "")
+        .to_ascii_uppercase();
+    let key = BASE32_NOPAD.decode(normalized.as_bytes())?;
+    let counter = time.timestamp().div_euclid(TOTP_PERIOD_SECONDS) as u64;
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key).expect("HMAC can take a key of any size");
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    let code = binary % 10_u32.pow(TOTP_DIGITS);
+
+    Ok(format!("{:0width$}", code, width = TOTP_DIGITS as usize))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+    use matches::assert_matches;
+
+    use super::*;
+
+    // The RFC 6238 SHA-1 test secret, "12345678901234567890", encoded as base32.
+    const RFC_6238_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn it_generates_the_rfc_6238_test_vectors() {
+        let vectors = [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1111111111, "050471"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+        ];
+
+        for (timestamp, expected) in vectors {
+            let time = Utc.timestamp_opt(timestamp, 0).unwrap();
+
+            assert_eq!(
+                generate_totp_code_at(RFC_6238_SECRET, time).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn it_accepts_a_lowercase_padded_secret() {
+        let time = Utc.timestamp_opt(59, 0).unwrap();
+
+        assert_eq!(
+            generate_totp_code_at("gezdgnbvgy3tqojqgezdgnbvgy3tqojq======", time).unwrap(),
+            "287082"
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_for_an_invalid_secret() {
+        assert_matches!(
+            generate_totp_code("not-base32!"),
+            Err(GenerateTotpCodeError::InvalidSecret(_))
+        )
+    }
+}
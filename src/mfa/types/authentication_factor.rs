@@ -55,13 +55,22 @@ pub enum AuthenticationFactorType {
         user: String,
 
         /// Base64 encoded image containing scannable QR code.
-        qr_code: String,
+        ///
+        /// Only returned when the factor is enrolled.
+        #[serde(default)]
+        qr_code: Option<String>,
 
         /// TOTP secret that can be manually entered into some authenticator apps in place of scanning a QR code.
-        secret: String,
+        ///
+        /// Only returned when the factor is enrolled.
+        #[serde(default)]
+        secret: Option<String>,
 
         /// The `otpauth` URI that is encoded by the provided `qr_code`.
-        uri: String,
+        ///
+        /// Only returned when the factor is enrolled.
+        #[serde(default)]
+        uri: Option<String>,
     },
     /// One-time password via SMS message.
     Sms {
@@ -102,9 +111,9 @@ mod test {
                 r#type: AuthenticationFactorType::Totp {
                     issuer: "Foo Corp".to_string(),
                     user: "alan.turing@foo-corp.com".to_string(),
-                    qr_code: "data:image/png;base64,{base64EncodedPng}".to_string(),
-                    secret: "NAGCCFS3EYRB422HNAKAKY3XDUORMSRF".to_string(),
-                    uri: "otpauth://totp/FooCorp:alan.turing@foo-corp.com?secret=NAGCCFS3EYRB422HNAKAKY3XDUORMSRF&issuer=FooCorp".to_string()
+                    qr_code: Some("data:image/png;base64,{base64EncodedPng}".to_string()),
+                    secret: Some("NAGCCFS3EYRB422HNAKAKY3XDUORMSRF".to_string()),
+                    uri: Some("otpauth://totp/FooCorp:alan.turing@foo-corp.com?secret=NAGCCFS3EYRB422HNAKAKY3XDUORMSRF&issuer=FooCorp".to_string())
                 },
                 timestamps: Timestamps {
                     created_at: Timestamp::try_from("2022-02-15T15:14:19.392Z").unwrap(),
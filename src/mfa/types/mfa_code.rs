@@ -1,7 +1,42 @@
-use derive_more::{Deref, Display, From};
+use std::str::FromStr;
+
+use derive_more::{Deref, From};
 use serde::Serialize;
 
-/// A multi-factor authentication (MFA) code.
-#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+use crate::core::validate_one_time_code;
+use crate::{InvalidOneTimeCode, SecretString};
+
+/// A multi-factor authentication (MFA) code, such as a TOTP or SMS code.
+///
+/// Codes compare in constant time. Use [`str::parse`] to validate the format of a code
+/// entered by a user.
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize)]
 #[from(forward)]
-pub struct MfaCode(String);
+pub struct MfaCode(SecretString);
+
+impl FromStr for MfaCode {
+    type Err = InvalidOneTimeCode;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        validate_one_time_code(code)?;
+
+        Ok(Self::from(code))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_a_well_formed_code() {
+        assert_eq!("012345".parse::<MfaCode>(), Ok(MfaCode::from("012345")));
+        assert_eq!(
+            "1234567".parse::<MfaCode>(),
+            Err(InvalidOneTimeCode::InvalidLength {
+                expected: 6,
+                actual: 7
+            })
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An error returned when a phone number is not in E.164 format.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("phone number must be in E.164 format, e.g. +15555555555")]
+pub struct InvalidPhoneNumber;
+
+/// The phone number of an SMS-enabled device.
+///
+/// Use [`str::parse`] to validate that a phone number entered by a user is in
+/// [E.164](https://en.wikipedia.org/wiki/E.164) format before enrolling it.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct PhoneNumber(String);
+
+impl FromStr for PhoneNumber {
+    type Err = InvalidPhoneNumber;
+
+    fn from_str(phone_number: &str) -> Result<Self, Self::Err> {
+        let digits = phone_number.strip_prefix('+').ok_or(InvalidPhoneNumber)?;
+
+        if !(2..=15).contains(&digits.len())
+            || digits.starts_with('0')
+            || !digits.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(InvalidPhoneNumber);
+        }
+
+        Ok(Self::from(phone_number))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_parses_an_e164_phone_number() {
+        assert_eq!(
+            "+15555555555".parse::<PhoneNumber>(),
+            Ok(PhoneNumber::from("+15555555555"))
+        );
+        assert_eq!("5555555555".parse::<PhoneNumber>(), Err(InvalidPhoneNumber));
+        assert_eq!("+1 555 555".parse::<PhoneNumber>(), Err(InvalidPhoneNumber));
+        assert_eq!("+0123456".parse::<PhoneNumber>(), Err(InvalidPhoneNumber));
+    }
+}
@@ -0,0 +1,67 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use thiserror::Error;
+
+const PNG_DATA_URL_PREFIX: &str = "data:image/png;base64,";
+
+/// An error returned from [`decode_qr_code`].
+#[derive(Debug, Error)]
+pub enum DecodeQrCodeError {
+    /// The value is not a base64-encoded PNG `data:` URL.
+    #[error("expected a base64-encoded PNG data URL")]
+    InvalidDataUrl,
+
+    /// The base64 payload could not be decoded.
+    #[error("invalid base64: {0}")]
+    InvalidBase64(#[from] base64::DecodeError),
+}
+
+/// Decodes the base64 `data:` URL returned as the `qr_code` of a TOTP factor into raw PNG bytes.
+///
+/// # Examples
+///
+/// ```
+/// use workos_sdk::mfa::decode_qr_code;
+///
+/// let png = decode_qr_code("data:image/png;base64,iVBORw0KGgo=").unwrap();
+///
+/// assert_eq!(png, b"\x89PNG\r\n\x1a\n");
+/// ```
+pub fn decode_qr_code(data_url: &str) -> Result<Vec<u8>, DecodeQrCodeError> {
+    let payload = data_url
+        .strip_prefix(PNG_DATA_URL_PREFIX)
+        .ok_or(DecodeQrCodeError::InvalidDataUrl)?;
+
+    Ok(STANDARD.decode(payload)?)
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_a_png_data_url() {
+        assert_eq!(
+            decode_qr_code("data:image/png;base64,iVBORw0KGgo=").unwrap(),
+            b"\x89PNG\r\n\x1a\n"
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_for_a_non_png_data_url() {
+        assert_matches!(
+            decode_qr_code("data:image/jpeg;base64,iVBORw0KGgo="),
+            Err(DecodeQrCodeError::InvalidDataUrl)
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_for_invalid_base64() {
+        assert_matches!(
+            decode_qr_code("data:image/png;base64,not base64!"),
+            Err(DecodeQrCodeError::InvalidBase64(_))
+        )
+    }
+}
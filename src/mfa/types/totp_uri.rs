@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use percent_encoding::percent_decode_str;
+use thiserror::Error;
+use url::Url;
+
+/// An error returned when parsing a [`TotpUri`].
+#[derive(Debug, Error)]
+pub enum TotpUriError {
+    /// The value is not a valid URI.
+    #[error("invalid URI: {0}")]
+    InvalidUri(#[from] url::ParseError),
+
+    /// The URI does not use the `otpauth` scheme.
+    #[error("expected the otpauth scheme, found {0}")]
+    InvalidScheme(String),
+
+    /// The URI is not for a time-based one-time password.
+    #[error("expected a totp URI, found {0}")]
+    InvalidType(String),
+
+    /// The URI does not contain a `secret` parameter.
+    #[error("missing secret")]
+    MissingSecret,
+}
+
+/// The components of an `otpauth://totp/...` URI, as returned when enrolling a TOTP factor.
+///
+/// [Key Uri Format](https://github.com/google/google-authenticator/wiki/Key-Uri-Format)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TotpUri {
+    /// The issuer of the factor, taken from the `issuer` parameter or the label prefix.
+    pub issuer: Option<String>,
+
+    /// The account name of the factor.
+    pub account: String,
+
+    /// The base32-encoded shared secret.
+    pub secret: String,
+
+    /// The remaining parameters of the URI, such as `algorithm`, `digits`, and `period`.
+    pub parameters: HashMap<String, String>,
+}
+
+impl FromStr for TotpUri {
+    type Err = TotpUriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = Url::parse(s)?;
+
+        if url.scheme() != "otpauth" {
+            return Err(TotpUriError::InvalidScheme(url.scheme().to_string()));
+        }
+
+        let r#type = url.host_str().unwrap_or_default();
+        if r#type != "totp" {
+            return Err(TotpUriError::InvalidType(r#type.to_string()));
+        }
+
+        let label = percent_decode_str(url.path().trim_start_matches('/'))
+            .decode_utf8_lossy()
+            .into_owned();
+        let (label_issuer, account) = match label.split_once(':') {
+            Some((issuer, account)) => (Some(issuer.to_string()), account.trim().to_string()),
+            None => (None, label),
+        };
+
+        let mut parameters: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        let secret = parameters
+            .remove("secret")
+            .ok_or(TotpUriError::MissingSecret)?;
+        let issuer = parameters.remove("issuer").or(label_issuer);
+
+        Ok(TotpUri {
+            issuer,
+            account,
+            secret,
+            parameters,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_a_totp_uri() {
+        let uri = TotpUri::from_str(
+            "otpauth://totp/FooCorp:alan.turing@foo-corp.com?secret=NAGCCFS3EYRB422HNAKAKY3XDUORMSRF&issuer=FooCorp&digits=6",
+        )
+        .unwrap();
+
+        assert_eq!(
+            uri,
+            TotpUri {
+                issuer: Some("FooCorp".to_string()),
+                account: "alan.turing@foo-corp.com".to_string(),
+                secret: "NAGCCFS3EYRB422HNAKAKY3XDUORMSRF".to_string(),
+                parameters: HashMap::from([("digits".to_string(), "6".to_string())]),
+            }
+        )
+    }
+
+    #[test]
+    fn it_decodes_a_percent_encoded_label() {
+        let uri =
+            TotpUri::from_str("otpauth://totp/Foo%20Corp:alan.turing%40foo-corp.com?secret=ABC")
+                .unwrap();
+
+        assert_eq!(uri.issuer, Some("Foo Corp".to_string()));
+        assert_eq!(uri.account, "alan.turing@foo-corp.com")
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_secret_is_missing() {
+        assert_matches!(
+            TotpUri::from_str("otpauth://totp/FooCorp:alan.turing@foo-corp.com?issuer=FooCorp"),
+            Err(TotpUriError::MissingSecret)
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_for_a_non_totp_uri() {
+        assert_matches!(
+            TotpUri::from_str("otpauth://hotp/FooCorp:alan?secret=ABC"),
+            Err(TotpUriError::InvalidType(_))
+        );
+        assert_matches!(
+            TotpUri::from_str("https://totp/FooCorp:alan?secret=ABC"),
+            Err(TotpUriError::InvalidScheme(_))
+        )
+    }
+}
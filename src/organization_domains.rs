@@ -0,0 +1,27 @@
+//! A module for interacting with the WorkOS Organization Domains API.
+//!
+//! The [`OrganizationDomain`](crate::organizations::OrganizationDomain) type itself lives
+//! in the [`organizations`](crate::organizations) module, as it is part of every
+//! organization.
+//!
+//! [WorkOS Docs: Domain Verification](https://workos.com/docs/domain-verification)
+
+mod operations;
+
+pub use operations::*;
+
+use crate::WorkOs;
+
+/// Organization Domains.
+///
+/// [WorkOS Docs: Domain Verification](https://workos.com/docs/domain-verification)
+pub struct OrganizationDomains<'a> {
+    workos: &'a WorkOs,
+}
+
+impl<'a> OrganizationDomains<'a> {
+    /// Returns a new [`OrganizationDomains`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self { workos }
+    }
+}
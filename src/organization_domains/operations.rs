@@ -0,0 +1,7 @@
+mod create_organization_domain;
+mod get_organization_domain;
+mod verify_organization_domain;
+
+pub use create_organization_domain::*;
+pub use get_organization_domain::*;
+pub use verify_organization_domain::*;
@@ -1,6 +1,8 @@
 mod create_organization;
 mod delete_organization;
 mod get_organization;
+mod get_organization_by_external_id;
+mod get_organization_url;
 mod list_organizations;
 mod update_external_id;
 mod update_organization;
@@ -8,6 +10,8 @@ mod update_organization;
 pub use create_organization::*;
 pub use delete_organization::*;
 pub use get_organization::*;
+pub use get_organization_by_external_id::*;
+pub use get_organization_url::*;
 pub use list_organizations::*;
 pub use update_external_id::*;
 pub use update_organization::*;
@@ -2,8 +2,9 @@ use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::core::IdempotencyKeyExt;
 use crate::organizations::{DomainData, Organization, Organizations};
-use crate::{Metadata, ResponseExt, WorkOsError, WorkOsResult};
+use crate::{IdempotencyKey, Metadata, OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreateOrganization`].
 #[derive(Debug, Serialize)]
@@ -21,6 +22,11 @@ pub struct CreateOrganizationParams<'a> {
 
     /// Object containing metadata key/value pairs associated with the organization.
     pub metadata: Option<Metadata>,
+
+    /// A key that prevents the organization from being created twice when the request is
+    /// retried.
+    #[serde(skip)]
+    pub idempotency_key: Option<&'a IdempotencyKey>,
 }
 
 /// An error returned from [`CreateOrganization`].
@@ -33,8 +39,11 @@ impl From<CreateOrganizationError> for WorkOsError<CreateOrganizationError> {
     }
 }
 
+impl OperationError for CreateOrganizationError {}
+
 /// [WorkOS Docs: Create an Organization](https://workos.com/docs/reference/organization/create)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CreateOrganization {
     /// Creates a new organization in the current environment.
     ///
@@ -47,7 +56,7 @@ pub trait CreateOrganization {
     ///
     /// # use workos_sdk::WorkOsResult;
     /// # use workos_sdk::organizations::*;
-    /// use workos_sdk::{ApiKey, Metadata, WorkOs};
+    /// use workos_sdk::{ApiKey, IdempotencyKey, Metadata, WorkOs};
     ///
     /// # async fn run() -> WorkOsResult<(), CreateOrganizationError> {
     /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
@@ -66,6 +75,7 @@ pub trait CreateOrganization {
     ///         }],
     ///         external_id: Some("2fe01467-f7ea-4dd2-8b79-c2b4f56d0191"),
     ///         metadata: Some(metadata),
+    ///         idempotency_key: Some(&IdempotencyKey::from("2fe01467-f7ea-4dd2-8b79-c2b4f56d0191")),
     ///     })
     ///     .await?;
     /// # Ok(())
@@ -77,7 +87,8 @@ pub trait CreateOrganization {
     ) -> WorkOsResult<Organization, CreateOrganizationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CreateOrganization for Organizations<'_> {
     async fn create_organization(
         &self,
@@ -87,15 +98,18 @@ impl CreateOrganization for Organizations<'_> {
 
         let organization = self
             .workos
-            .client()
-            .post(url)
-            .bearer_auth(self.workos.key())
-            .json(&params)
-            .send()
+            .send(
+                self.workos
+                    .client()
+                    .post(url)
+                    .bearer_auth(self.workos.key())
+                    .idempotency_key(params.idempotency_key)
+                    .json(&params),
+            )
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<Organization>()
+            .decode_json::<Organization>()
             .await?;
 
         Ok(organization)
@@ -121,7 +135,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/organizations")
@@ -158,10 +173,7 @@ mod test {
             .create_async()
             .await;
 
-        let metadata = Metadata(HashMap::from([(
-            "tier".to_string(),
-            "diamond".to_string(),
-        )]));
+        let metadata = Metadata(HashMap::from([("tier".to_string(), "diamond".to_string())]));
 
         let organization = workos
             .organizations()
@@ -173,6 +185,54 @@ mod test {
                 }],
                 external_id: Some("2fe01467-f7ea-4dd2-8b79-c2b4f56d0191"),
                 metadata: Some(metadata),
+                idempotency_key: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            organization.id,
+            OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_sends_the_idempotency_key() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/organizations")
+            .match_header("Idempotency-Key", "create-foo-corp")
+            .with_status(201)
+            .with_body(
+                json!({
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "object": "organization",
+                    "name": "Foo Corp",
+                    "allow_profiles_outside_organization": false,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z",
+                    "domains": []
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let organization = workos
+            .organizations()
+            .create_organization(&CreateOrganizationParams {
+                name: "Foo Corp",
+                domain_data: vec![],
+                external_id: None,
+                metadata: None,
+                idempotency_key: Some(&IdempotencyKey::from("create-foo-corp")),
             })
             .await
             .unwrap();
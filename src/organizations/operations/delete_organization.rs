@@ -2,8 +2,8 @@ use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::organizations::{OrganizationId, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::organizations::{GetOrganizationUrl, OrganizationId, Organizations};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteOrganization`].
 #[derive(Debug, Serialize)]
@@ -22,8 +22,11 @@ impl From<DeleteOrganizationError> for WorkOsError<DeleteOrganizationError> {
     }
 }
 
+impl OperationError for DeleteOrganizationError {}
+
 /// [WorkOS Docs: Delete an Organization](https://workos.com/docs/reference/organization/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteOrganization {
     /// Creates an [`Organization`](crate::organizations::Organization).
     ///
@@ -54,17 +57,15 @@ pub trait DeleteOrganization {
     ) -> WorkOsResult<(), DeleteOrganizationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteOrganization for Organizations<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn delete_organization(
         &self,
         params: &DeleteOrganizationParams<'_>,
     ) -> WorkOsResult<(), DeleteOrganizationError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/organizations/{id}", id = params.organization_id))?;
+        let url = self.get_organization_url(params.organization_id)?;
         self.workos
             .send(
                 self.workos
@@ -95,7 +96,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("DELETE", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
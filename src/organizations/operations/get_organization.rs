@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use thiserror::Error;
 
-use crate::organizations::{Organization, OrganizationId, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::organizations::{GetOrganizationUrl, Organization, OrganizationId, Organizations};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetOrganization`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetOrganizationError> for WorkOsError<GetOrganizationError> {
     }
 }
 
+impl OperationError for GetOrganizationError {}
+
 /// [WorkOS Docs: Get an Organization](https://workos.com/docs/reference/sso/organization/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetOrganization {
     /// Retrieves an [`Organization`] by its ID.
     ///
@@ -44,24 +47,22 @@ pub trait GetOrganization {
     ) -> WorkOsResult<Organization, GetOrganizationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetOrganization for Organizations<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_organization(
         &self,
         id: &OrganizationId,
     ) -> WorkOsResult<Organization, GetOrganizationError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/organizations/{id}", id = id))?;
+        let url = self.get_organization_url(id)?;
         let organization = self
             .workos
             .send(self.workos.client().get(url).bearer_auth(self.workos.key()))
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<Organization>()
+            .decode_json::<Organization>()
             .await?;
 
         Ok(organization)
@@ -84,7 +85,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
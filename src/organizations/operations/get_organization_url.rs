@@ -0,0 +1,57 @@
+use url::{ParseError, Url};
+
+use crate::organizations::{OrganizationId, Organizations};
+
+/// Builds the API URL of an [`Organization`](crate::organizations::Organization).
+pub trait GetOrganizationUrl {
+    /// Returns the API URL of the organization with the provided ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use url::ParseError;
+    /// # use workos_sdk::organizations::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let organization_url = workos
+    ///     .organizations()
+    ///     .get_organization_url(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_organization_url(&self, organization_id: &OrganizationId) -> Result<Url, ParseError>;
+}
+
+impl GetOrganizationUrl for Organizations<'_> {
+    fn get_organization_url(&self, organization_id: &OrganizationId) -> Result<Url, ParseError> {
+        self.workos
+            .base_url()
+            .join(&format!("/organizations/{organization_id}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    #[test]
+    fn it_builds_an_organization_url() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let organization_url = workos
+            .organizations()
+            .get_organization_url(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .unwrap();
+
+        assert_eq!(
+            organization_url,
+            Url::parse("https://api.workos.com/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+                .unwrap()
+        )
+    }
+}
@@ -2,8 +2,10 @@ use async_trait::async_trait;
 use serde_json::json;
 use thiserror::Error;
 
-use crate::organizations::{ExternalId, Organization, OrganizationId, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::organizations::{
+    ExternalId, GetOrganizationUrl, Organization, OrganizationId, Organizations,
+};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`UpdateExternalId`].
 #[derive(Debug, Error)]
@@ -15,8 +17,11 @@ impl From<UpdateExternalIdError> for WorkOsError<UpdateExternalIdError> {
     }
 }
 
+impl OperationError for UpdateExternalIdError {}
+
 /// [WorkOS Docs: Update an Organization's External ID](https://workos.com/docs/reference/organization/update)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait UpdateExternalId {
     /// Updates an organization's external ID.
     ///
@@ -49,7 +54,8 @@ pub trait UpdateExternalId {
     ) -> WorkOsResult<Organization, UpdateExternalIdError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl UpdateExternalId for Organizations<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn update_external_id(
@@ -57,10 +63,7 @@ impl UpdateExternalId for Organizations<'_> {
         organization_id: &OrganizationId,
         external_id: &ExternalId,
     ) -> WorkOsResult<Organization, UpdateExternalIdError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/organizations/{organization_id}"))?;
+        let url = self.get_organization_url(organization_id)?;
 
         let body = json!({
             "external_id": external_id
@@ -78,7 +81,7 @@ impl UpdateExternalId for Organizations<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<Organization>()
+            .decode_json::<Organization>()
             .await?;
 
         Ok(organization)
@@ -102,7 +105,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("PUT", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
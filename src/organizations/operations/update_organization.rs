@@ -2,8 +2,10 @@ use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::organizations::{DomainData, Organization, OrganizationId, Organizations};
-use crate::{Metadata, ResponseExt, WorkOsError, WorkOsResult};
+use crate::organizations::{
+    DomainData, GetOrganizationUrl, Organization, OrganizationId, Organizations,
+};
+use crate::{Metadata, OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`UpdateOrganization`].
 #[derive(Debug, Serialize)]
@@ -40,8 +42,11 @@ impl From<UpdateOrganizationError> for WorkOsError<UpdateOrganizationError> {
     }
 }
 
+impl OperationError for UpdateOrganizationError {}
+
 /// [WorkOS Docs: Update an Organization](https://workos.com/docs/reference/organization/update)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait UpdateOrganization {
     /// Updates an organization in the current environment.
     ///
@@ -86,28 +91,28 @@ pub trait UpdateOrganization {
     ) -> WorkOsResult<Organization, UpdateOrganizationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl UpdateOrganization for Organizations<'_> {
     async fn update_organization(
         &self,
         params: &UpdateOrganizationParams<'_>,
     ) -> WorkOsResult<Organization, UpdateOrganizationError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/organizations/{id}", id = params.organization_id))?;
+        let url = self.get_organization_url(params.organization_id)?;
 
         let organization = self
             .workos
-            .client()
-            .put(url)
-            .bearer_auth(self.workos.key())
-            .json(&params)
-            .send()
+            .send(
+                self.workos
+                    .client()
+                    .put(url)
+                    .bearer_auth(self.workos.key())
+                    .json(&params),
+            )
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<Organization>()
+            .decode_json::<Organization>()
             .await?;
 
         Ok(organization)
@@ -117,12 +122,13 @@ impl UpdateOrganization for Organizations<'_> {
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
 
     use serde_json::json;
     use tokio;
 
     use crate::organizations::{DomainData, DomainDataState, OrganizationId};
-    use crate::{ApiKey, Metadata, WorkOs};
+    use crate::{ApiKey, AuditEntry, AuditSink, AuditSinkError, DryRunRequest, Metadata, WorkOs};
 
     use super::*;
 
@@ -133,7 +139,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("PUT", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
@@ -194,4 +201,115 @@ mod test {
             OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
         )
     }
+
+    #[tokio::test]
+    async fn it_does_not_send_the_update_in_dry_run_mode() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .dry_run(|request: &DryRunRequest| {
+                let body = json!({
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "object": "organization",
+                    "name": request.body.as_ref().unwrap()["name"],
+                    "allow_profiles_outside_organization": false,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z",
+                    "domains": []
+                });
+
+                http::Response::builder()
+                    .status(200)
+                    .body(body.to_string().into_bytes())
+                    .unwrap()
+            })
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("PUT", mockito::Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let organization = workos
+            .organizations()
+            .update_organization(&UpdateOrganizationParams {
+                organization_id: &OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"),
+                name: Some("Foo Corp"),
+                domain_data: None,
+                stripe_customer_id: None,
+                external_id: None,
+                metadata: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(organization.name, "Foo Corp");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_records_the_update_in_the_audit_sink() {
+        #[derive(Clone, Default)]
+        struct RecordingSink(Arc<Mutex<Vec<AuditEntry>>>);
+
+        impl AuditSink for RecordingSink {
+            fn record(&self, entry: &AuditEntry) -> Result<(), AuditSinkError> {
+                self.0.lock().unwrap().push(entry.clone());
+                Ok(())
+            }
+        }
+
+        let mut server = mockito::Server::new_async().await;
+        let sink = RecordingSink::default();
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .audit_sink(sink.clone())
+            .build()
+            .unwrap();
+
+        server
+            .mock("PUT", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "object": "organization",
+                    "name": "Foo Corp",
+                    "allow_profiles_outside_organization": false,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z",
+                    "domains": []
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        workos
+            .organizations()
+            .update_organization(&UpdateOrganizationParams {
+                organization_id: &OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"),
+                name: Some("Foo Corp"),
+                domain_data: None,
+                stripe_customer_id: None,
+                external_id: None,
+                metadata: None,
+            })
+            .await
+            .unwrap();
+
+        let entries = sink.0.lock().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].method, "PUT");
+        assert_eq!(
+            entries[0].entity_ids,
+            vec!["org_01EHZNVPK3SFK441A1RGBFSHRT".to_string()]
+        );
+    }
 }
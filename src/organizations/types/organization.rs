@@ -2,7 +2,7 @@ use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
 use super::ExternalId;
-use crate::Timestamps;
+use crate::{KnownOrUnknown, Timestamps};
 
 /// The ID of an [`Organization`].
 #[derive(
@@ -57,6 +57,34 @@ pub struct Organization {
 #[from(forward)]
 pub struct OrganizationDomainId(String);
 
+/// The verification state of an [`OrganizationDomain`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationDomainState {
+    /// The domain has not been verified yet.
+    Pending,
+
+    /// The domain has been verified.
+    Verified,
+
+    /// The verification of the domain failed.
+    Failed,
+
+    /// The domain was verified before domain verification was introduced.
+    LegacyVerified,
+}
+
+/// How the ownership of an [`OrganizationDomain`] is verified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationDomainVerificationStrategy {
+    /// The organization adds a DNS TXT record containing the verification token.
+    Dns,
+
+    /// The domain is marked as verified by you.
+    Manual,
+}
+
 /// [WorkOS Docs: Organization Domain](https://workos.com/docs/reference/organization-domain)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrganizationDomain {
@@ -65,4 +93,17 @@ pub struct OrganizationDomain {
 
     /// The domain.
     pub domain: String,
+
+    /// The ID of the organization the domain belongs to.
+    pub organization_id: Option<OrganizationId>,
+
+    /// The verification state of the domain.
+    pub state: Option<KnownOrUnknown<OrganizationDomainState, String>>,
+
+    /// How the ownership of the domain is verified.
+    pub verification_strategy:
+        Option<KnownOrUnknown<OrganizationDomainVerificationStrategy, String>>,
+
+    /// The token to add to a DNS TXT record to verify the domain.
+    pub verification_token: Option<String>,
 }
@@ -1,8 +1,9 @@
 use async_trait::async_trait;
 use serde::Serialize;
+use thiserror::Error;
 
 use crate::passwordless::{Passwordless, PasswordlessSessionId};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`SendPasswordlessSession`].
 #[derive(Debug, Serialize)]
@@ -12,11 +13,20 @@ pub struct SendPasswordlessSessionParams<'a> {
 }
 
 /// An error returned from [`SendPasswordlessSession`].
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum SendPasswordlessSessionError {}
 
+impl From<SendPasswordlessSessionError> for WorkOsError<SendPasswordlessSessionError> {
+    fn from(err: SendPasswordlessSessionError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for SendPasswordlessSessionError {}
+
 /// [WorkOS Docs: Send a Passwordless Session](https://workos.com/docs/reference/magic-link/passwordless-session/send-email)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait SendPasswordlessSession {
     /// Sends a [`PasswordlessSession`](crate::passwordless::PasswordlessSession).
     ///
@@ -47,7 +57,8 @@ pub trait SendPasswordlessSession {
     ) -> WorkOsResult<(), SendPasswordlessSessionError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl SendPasswordlessSession for Passwordless<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn send_passwordless_session(
@@ -92,7 +103,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
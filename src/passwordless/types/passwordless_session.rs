@@ -1,5 +1,6 @@
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use crate::Timestamp;
 
@@ -20,7 +21,7 @@ pub enum PasswordlessSessionType {
         email: String,
 
         /// The Magic Link for the user to authenticate with.
-        link: String,
+        link: Url,
     },
 }
 
@@ -42,9 +43,7 @@ pub struct PasswordlessSession {
 mod test {
     use serde_json::json;
 
-    use crate::Timestamp;
-
-    use super::{PasswordlessSession, PasswordlessSessionId, PasswordlessSessionType};
+    use super::*;
 
     #[test]
     fn it_deserializes_a_magic_link_session() {
@@ -66,8 +65,10 @@ mod test {
                 id: PasswordlessSessionId::from("passwordless_session_01EHDAK2BFGWCSZXP9HGZ3VK8C"),
                 r#type: PasswordlessSessionType::MagicLink {
                     email: "marcelina@foo-corp.com".to_string(),
-                    link: "https://auth.workos.com/passwordless/4TeRexuejWCKs9rrFOIuLRYEr/confirm"
-                        .to_string(),
+                    link: Url::parse(
+                        "https://auth.workos.com/passwordless/4TeRexuejWCKs9rrFOIuLRYEr/confirm"
+                    )
+                    .unwrap(),
                 },
                 expires_at: Timestamp::try_from("2020-08-13T05:50:00.000Z").unwrap()
             }
@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fmt;
+
+use serde::Deserialize;
+use thiserror::Error;
+use url::ParseError;
+
+use crate::{ApiKey, BuildError, WorkOs};
+
+/// The configuration for a single environment in a [`WorkOsRegistry`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkOsConfig {
+    /// The API key for the environment.
+    pub api_key: ApiKey,
+
+    /// The base URL of the WorkOS API for the environment.
+    ///
+    /// Defaults to `https://api.workos.com`.
+    #[serde(default)]
+    pub base_url: Option<String>,
+
+    /// Whether parameters should be validated locally before being sent to the API.
+    #[serde(default)]
+    pub local_validation: bool,
+}
+
+impl WorkOsConfig {
+    /// Builds a [`WorkOs`] client from the configuration.
+    pub fn build(&self) -> Result<WorkOs, WorkOsConfigError> {
+        let mut builder = WorkOs::builder(&self.api_key).local_validation(self.local_validation);
+
+        if let Some(base_url) = &self.base_url {
+            builder = builder.base_url(base_url)?;
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+/// An error returned when building a [`WorkOs`] client from a [`WorkOsConfig`].
+#[derive(Debug, Error)]
+pub enum WorkOsConfigError {
+    /// The base URL could not be parsed.
+    #[error("invalid base URL")]
+    InvalidBaseUrl(#[from] ParseError),
+
+    /// The client could not be built.
+    #[error(transparent)]
+    Build(#[from] BuildError),
+}
+
+/// An error returned when building a [`WorkOsRegistry`].
+#[derive(Debug, Error)]
+pub enum WorkOsRegistryError {
+    /// The same environment name was configured more than once.
+    #[error("environment configured more than once: {0}")]
+    DuplicateEnvironment(String),
+
+    /// Two environments were configured with the same API key.
+    #[error("environments '{first}' and '{second}' share an API key")]
+    SharedApiKey {
+        /// The first environment using the API key.
+        first: String,
+
+        /// The second environment using the API key.
+        second: String,
+    },
+
+    /// The base URL of an environment could not be parsed.
+    #[error("invalid base URL for environment '{environment}': {source}")]
+    InvalidBaseUrl {
+        /// The environment with the invalid base URL.
+        environment: String,
+
+        /// The underlying parse error.
+        source: ParseError,
+    },
+
+    /// The client of an environment could not be built.
+    #[error("failed to build client for environment '{environment}': {source}")]
+    Build {
+        /// The environment whose client could not be built.
+        environment: String,
+
+        /// The underlying build error.
+        source: BuildError,
+    },
+}
+
+/// A set of named [`WorkOs`] clients, such as one per environment or region.
+///
+/// Each environment must use its own API key, so that keys cannot accidentally be
+/// mixed between environments.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use workos_sdk::{WorkOsConfig, WorkOsRegistry};
+///
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let configs: HashMap<String, WorkOsConfig> = serde_json::from_str(
+///     r#"{
+///         "staging": { "api_key": "sk_test_123456789" },
+///         "production": { "api_key": "sk_live_123456789" }
+///     }"#,
+/// )?;
+///
+/// let registry = WorkOsRegistry::from_config(configs)?;
+///
+/// let staging = registry.get("staging").expect("staging is configured");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct WorkOsRegistry {
+    clients: BTreeMap<String, WorkOs>,
+}
+
+impl WorkOsRegistry {
+    /// Returns a new, empty [`WorkOsRegistry`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a [`WorkOsRegistry`] from a set of named configurations.
+    pub fn from_config<I, K>(configs: I) -> Result<Self, WorkOsRegistryError>
+    where
+        I: IntoIterator<Item = (K, WorkOsConfig)>,
+        K: Into<String>,
+    {
+        let mut registry = Self::new();
+
+        for (name, config) in configs {
+            let name = name.into();
+            let client = config.build().map_err(|err| match err {
+                WorkOsConfigError::InvalidBaseUrl(source) => WorkOsRegistryError::InvalidBaseUrl {
+                    environment: name.clone(),
+                    source,
+                },
+                WorkOsConfigError::Build(source) => WorkOsRegistryError::Build {
+                    environment: name.clone(),
+                    source,
+                },
+            })?;
+
+            registry.insert(name, client)?;
+        }
+
+        Ok(registry)
+    }
+
+    /// Adds a client to the registry under the provided name.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        client: WorkOs,
+    ) -> Result<(), WorkOsRegistryError> {
+        let name = name.into();
+
+        if let Some((existing, _)) = self
+            .clients
+            .iter()
+            .find(|(_, existing)| existing.key() == client.key())
+        {
+            return Err(WorkOsRegistryError::SharedApiKey {
+                first: existing.clone(),
+                second: name,
+            });
+        }
+
+        match self.clients.entry(name) {
+            Entry::Occupied(entry) => Err(WorkOsRegistryError::DuplicateEnvironment(
+                entry.key().clone(),
+            )),
+            Entry::Vacant(entry) => {
+                entry.insert(client);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the client for the named environment, if it exists.
+    pub fn get(&self, name: &str) -> Option<&WorkOs> {
+        self.clients.get(name)
+    }
+
+    /// Returns the names of the configured environments, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    /// Returns the number of configured environments.
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns whether no environments are configured.
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+}
+
+impl fmt::Debug for WorkOsRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkOsRegistry")
+            .field("environments", &self.clients.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    fn config(api_key: &str) -> WorkOsConfig {
+        WorkOsConfig {
+            api_key: ApiKey::from(api_key),
+            base_url: None,
+            local_validation: false,
+        }
+    }
+
+    #[test]
+    fn it_builds_clients_from_config() {
+        let registry = WorkOsRegistry::from_config([
+            ("staging", config("sk_test_123456789")),
+            (
+                "production-eu",
+                WorkOsConfig {
+                    base_url: Some("https://eu.api.workos.com".to_string()),
+                    ..config("sk_live_123456789")
+                },
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            vec!["production-eu", "staging"]
+        );
+        assert_eq!(
+            registry.get("staging").unwrap().key(),
+            &ApiKey::from("sk_test_123456789")
+        );
+        assert_eq!(
+            registry.get("production-eu").unwrap().base_url().as_str(),
+            "https://eu.api.workos.com/"
+        );
+        assert!(registry.get("development").is_none());
+    }
+
+    #[test]
+    fn it_rejects_environments_sharing_an_api_key() {
+        let result = WorkOsRegistry::from_config([
+            ("staging", config("sk_test_123456789")),
+            ("production", config("sk_test_123456789")),
+        ]);
+
+        assert_matches!(
+            result,
+            Err(WorkOsRegistryError::SharedApiKey { first, second })
+                if first == "staging" && second == "production"
+        );
+    }
+
+    #[test]
+    fn it_rejects_duplicate_environments() {
+        let mut registry = WorkOsRegistry::new();
+        registry
+            .insert("staging", config("sk_test_123456789").build().unwrap())
+            .unwrap();
+
+        let result = registry.insert("staging", config("sk_test_987654321").build().unwrap());
+
+        assert_matches!(
+            result,
+            Err(WorkOsRegistryError::DuplicateEnvironment(name)) if name == "staging"
+        );
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_base_url() {
+        let result = WorkOsRegistry::from_config([(
+            "staging",
+            WorkOsConfig {
+                base_url: Some("not a url".to_string()),
+                ..config("sk_test_123456789")
+            },
+        )]);
+
+        assert_matches!(
+            result,
+            Err(WorkOsRegistryError::InvalidBaseUrl { environment, .. }) if environment == "staging"
+        );
+    }
+}
@@ -3,9 +3,11 @@
 //! [WorkOS Docs: Role-Based Access Control Guide](https://workos.com/docs/rbac/guide)
 
 mod operations;
+mod permission_cache;
 mod types;
 
 pub use operations::*;
+pub use permission_cache::*;
 pub use types::*;
 
 use crate::WorkOs;
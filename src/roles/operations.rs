@@ -1,3 +1,19 @@
+mod add_role_permission;
+mod create_permission;
+mod create_role;
+mod delete_permission;
+mod delete_role;
 mod list_organization_roles;
+mod list_permissions;
+mod remove_role_permission;
+mod update_role;
 
+pub use add_role_permission::*;
+pub use create_permission::*;
+pub use create_role::*;
+pub use delete_permission::*;
+pub use delete_role::*;
 pub use list_organization_roles::*;
+pub use list_permissions::*;
+pub use remove_role_permission::*;
+pub use update_role::*;
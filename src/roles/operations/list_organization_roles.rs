@@ -2,9 +2,10 @@ use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::core::QueryParams;
 use crate::organizations::OrganizationId;
 use crate::roles::{Role, Roles};
-use crate::{ResponseExt, UnpaginatedList, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, UnpaginatedList, WorkOsError, WorkOsResult};
 
 /// The parameters for the [`ListOrganizationRoles`] function.
 #[derive(Debug, Serialize)]
@@ -24,8 +25,11 @@ impl From<ListOrganizationRolesError> for WorkOsError<ListOrganizationRolesError
     }
 }
 
+impl OperationError for ListOrganizationRolesError {}
+
 /// [WorkOS Docs: List roles for an organization](https://workos.com/docs/reference/roles/list-for-organization)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ListOrganizationRoles {
     /// Get a list of all roles for the provided organization in priority order.
     ///
@@ -59,7 +63,8 @@ pub trait ListOrganizationRoles {
     ) -> WorkOsResult<UnpaginatedList<Role>, ListOrganizationRolesError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ListOrganizationRoles for Roles<'_> {
     async fn list_organization_roles(
         &self,
@@ -70,21 +75,19 @@ impl ListOrganizationRoles for Roles<'_> {
             .base_url()
             .join(&format!("/organizations/{}/roles", params.organization_id))?;
 
-        println!("{url}");
-
         let roles = self
             .workos
             .send(
                 self.workos
                     .client()
                     .get(url)
-                    .query(&params)
+                    .query(&QueryParams(params))
                     .bearer_auth(self.workos.key()),
             )
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<UnpaginatedList<Role>>()
+            .decode_json::<UnpaginatedList<Role>>()
             .await?;
 
         Ok(roles)
@@ -109,7 +112,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT/roles")
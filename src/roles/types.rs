@@ -1,3 +1,5 @@
+mod permission;
 mod role;
 
+pub use permission::*;
 pub use role::*;
@@ -0,0 +1,39 @@
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamps;
+
+/// The slug of a permission, such as `posts:write`.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct PermissionSlug(String);
+
+/// The ID of a [`Permission`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct PermissionId(String);
+
+/// [WorkOS Docs: Permission](https://workos.com/docs/reference/roles/permission)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Permission {
+    /// Unique identifier of the permission.
+    pub id: PermissionId,
+
+    /// A unique key to reference the permission.
+    pub slug: PermissionSlug,
+
+    /// A descriptive name for the permission.
+    pub name: String,
+
+    /// An optional description of the permission.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// The timestamps for the permission.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
@@ -2,6 +2,9 @@ use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
 use crate::Timestamps;
+use crate::organizations::OrganizationId;
+
+use super::PermissionSlug;
 
 /// The ID of a [`Role`].
 #[derive(
@@ -34,6 +37,28 @@ pub enum RoleType {
     OrganizationRole,
 }
 
+/// Whether a [`Role`] is shared by the whole environment or belongs to an organization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoleScope<'a> {
+    /// An environment role, available to every organization.
+    Environment,
+
+    /// An organization role, only available to the organization with this ID.
+    Organization(&'a OrganizationId),
+}
+
+impl RoleScope<'_> {
+    /// Returns the API path of the roles in this scope.
+    pub(crate) fn roles_path(&self) -> String {
+        match self {
+            Self::Environment => "/authorization/roles".to_string(),
+            Self::Organization(organization_id) => {
+                format!("/authorization/organizations/{organization_id}/roles")
+            }
+        }
+    }
+}
+
 /// [WorkOS Docs: Role](https://workos.com/docs/reference/roles)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Role {
@@ -48,8 +73,12 @@ pub struct Role {
     /// A unique key to reference the role.
     pub slug: RoleSlug,
 
+    /// An optional description of the role.
+    #[serde(default)]
+    pub description: Option<String>,
+
     /// A list of permission slugs assigned to the role.
-    pub permissions: Vec<String>,
+    pub permissions: Vec<PermissionSlug>,
 
     /// The type of role.
     pub r#type: RoleType,
@@ -59,6 +88,15 @@ pub struct Role {
     pub timestamps: Timestamps,
 }
 
+impl Role {
+    /// Returns whether the permission with the provided slug is assigned to the role.
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions
+            .iter()
+            .any(|slug| slug.as_str() == permission)
+    }
+}
+
 /// [WorkOS Docs: Role events](https://workos.com/docs/events/role)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RoleEvent {
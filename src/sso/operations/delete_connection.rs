@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::sso::{ConnectionId, Sso};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteConnection`].
 #[derive(Debug, Serialize)]
@@ -22,8 +22,11 @@ impl From<DeleteConnectionError> for WorkOsError<DeleteConnectionError> {
     }
 }
 
+impl OperationError for DeleteConnectionError {}
+
 /// [WorkOS Docs: Delete a Connection](https://workos.com/docs/reference/sso/connection/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteConnection {
     /// Deletes a [`Connection`](crate::sso::Connection).
     ///
@@ -54,7 +57,8 @@ pub trait DeleteConnection {
     ) -> WorkOsResult<(), DeleteConnectionError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteConnection for Sso<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn delete_connection(
@@ -97,7 +101,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("DELETE", "/connections/conn_01E2NPPCT7XQ2MVVYDHWGK1WN4")
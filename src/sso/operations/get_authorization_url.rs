@@ -1,17 +1,8 @@
 use url::{ParseError, Url};
 
+use crate::core::AuthorizationUrlBuilder;
 use crate::organizations::OrganizationId;
-use crate::sso::{ClientId, ConnectionId, Sso};
-
-/// An OAuth provider to use for Single Sign-On (SSO).
-#[derive(Clone, Copy, Debug)]
-pub enum Provider {
-    /// Sign in with Google OAuth.
-    GoogleOauth,
-
-    /// Sign in with Microsoft OAuth.
-    MicrosoftOauth,
-}
+use crate::sso::{ClientId, CodeChallenge, ConnectionId, OauthProvider, Sso};
 
 /// The selector to use to determine which connection to use for SSO.
 #[derive(Debug)]
@@ -23,7 +14,7 @@ pub enum ConnectionSelector<'a> {
     Organization(&'a OrganizationId),
 
     /// Initiate SSO for the specified OAuth provider.
-    Provider(&'a Provider),
+    Provider(&'a OauthProvider),
 }
 
 /// The parameters for [`GetAuthorizationUrl`].
@@ -42,6 +33,15 @@ pub struct GetAuthorizationUrlParams<'a> {
 
     /// The state parameter that will be passed back to the redirect URI.
     pub state: Option<&'a str>,
+
+    /// Code challenge is derived from the code verifier used for the PKCE flow.
+    pub code_challenge: Option<CodeChallenge<'a>>,
+
+    /// Can be used to pre-fill the username/email address field of the IdP sign-in page for the user, if you know their username ahead of time.
+    pub login_hint: Option<&'a str>,
+
+    /// Can be used to pre-fill the domain field when initiating authentication with Microsoft OAuth or with a Google SAML connection type.
+    pub domain_hint: Option<&'a str>,
 }
 
 /// [WorkOS Docs: Get Authorization URL](https://workos.com/docs/reference/sso/authorize/get)
@@ -69,6 +69,9 @@ pub trait GetAuthorizationUrl {
     ///             "conn_01E4ZCR3C56J083X43JQXF3JK5",
     ///         )),
     ///         state: None,
+    ///         code_challenge: None,
+    ///         login_hint: None,
+    ///         domain_hint: None,
     ///     })?;
     /// # Ok(())
     /// # }
@@ -84,43 +87,36 @@ impl GetAuthorizationUrl for Sso<'_> {
             client_id,
             redirect_uri,
             state,
+            code_challenge,
+            login_hint,
+            domain_hint,
         } = params;
 
-        let query = {
-            let client_id = client_id.to_string();
-
-            let connection_selector_param = match connection_selector {
-                ConnectionSelector::Connection(connection_id) => {
-                    ("connection", connection_id.to_string())
-                }
-                ConnectionSelector::Organization(organization_id) => {
-                    ("organization", organization_id.to_string())
-                }
-                ConnectionSelector::Provider(provider) => (
-                    "provider",
-                    match provider {
-                        Provider::GoogleOauth => "GoogleOAuth".to_string(),
-                        Provider::MicrosoftOauth => "MicrosoftOAuth".to_string(),
-                    },
-                ),
-            };
-
-            let mut query_params: querystring::QueryParams = vec![
-                ("response_type", "code"),
-                ("client_id", &client_id),
-                ("redirect_uri", redirect_uri),
-                (connection_selector_param.0, &connection_selector_param.1),
-            ];
-
-            if let Some(state) = state {
-                query_params.push(("state", state));
+        let builder = AuthorizationUrlBuilder::new(
+            self.workos.base_url(),
+            "/sso/authorize",
+            client_id,
+            redirect_uri,
+        )?;
+
+        let builder = match connection_selector {
+            ConnectionSelector::Connection(connection_id) => {
+                builder.param("connection", connection_id)
+            }
+            ConnectionSelector::Organization(organization_id) => {
+                builder.param("organization", organization_id)
+            }
+            ConnectionSelector::Provider(provider) => {
+                builder.param("provider", &provider.to_string())
             }
-            String::from(querystring::stringify(query_params).trim_end_matches('&'))
         };
 
-        self.workos
-            .base_url()
-            .join(&format!("/sso/authorize?{}", query))
+        Ok(builder
+            .optional_param("state", *state)
+            .code_challenge(code_challenge.as_ref())
+            .optional_param("login_hint", *login_hint)
+            .optional_param("domain_hint", *domain_hint)
+            .build())
     }
 }
 
@@ -143,13 +139,16 @@ mod test {
                     "conn_1234",
                 )),
                 state: None,
+                code_challenge: None,
+                login_hint: None,
+                domain_hint: None,
             })
             .unwrap();
 
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&connection=conn_1234"
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&connection=conn_1234"
             )
             .unwrap()
         )
@@ -168,13 +167,16 @@ mod test {
                     "org_1234",
                 )),
                 state: None,
+                code_challenge: None,
+                login_hint: None,
+                domain_hint: None,
             })
             .unwrap();
 
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&organization=org_1234"
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&organization=org_1234"
             )
             .unwrap()
         )
@@ -189,17 +191,68 @@ mod test {
             .get_authorization_url(&GetAuthorizationUrlParams {
                 client_id: &ClientId::from("client_123456789"),
                 redirect_uri: "https://your-app.com/callback",
-                connection_selector: ConnectionSelector::Provider(&Provider::GoogleOauth),
+                connection_selector: ConnectionSelector::Provider(&OauthProvider::GoogleOAuth),
                 state: None,
+                code_challenge: None,
+                login_hint: None,
+                domain_hint: None,
             })
             .unwrap();
 
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&provider=GoogleOAuth"
+                "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=GoogleOAuth"
             )
             .unwrap()
         )
     }
+
+    #[test]
+    fn it_builds_an_authorization_url_with_hints_and_state() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: "https://your-app.com/callback",
+                connection_selector: ConnectionSelector::Provider(&OauthProvider::MicrosoftOAuth),
+                state: Some("{\"next\":\"/dashboard\"}"),
+                code_challenge: None,
+                login_hint: Some("todd@foo-corp.com"),
+                domain_hint: Some("foo-corp.com"),
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url.as_str(),
+            "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=MicrosoftOAuth&state=%7B%22next%22%3A%22%2Fdashboard%22%7D&login_hint=todd%40foo-corp.com&domain_hint=foo-corp.com"
+        )
+    }
+
+    #[test]
+    fn it_builds_an_authorization_url_with_a_code_challenge() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .sso()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: "https://your-app.com/callback",
+                connection_selector: ConnectionSelector::Provider(&OauthProvider::GithubOAuth),
+                state: None,
+                code_challenge: Some(CodeChallenge::S256(
+                    "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM",
+                )),
+                login_hint: None,
+                domain_hint: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url.as_str(),
+            "https://api.workos.com/sso/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=GitHubOAuth&code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&code_challenge_method=S256"
+        )
+    }
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::sso::{Connection, ConnectionId, Sso};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetConnection`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetConnectionError> for WorkOsError<GetConnectionError> {
     }
 }
 
+impl OperationError for GetConnectionError {}
+
 /// [WorkOS Docs: Get a Connection](https://workos.com/docs/reference/sso/connection/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetConnection {
     /// Retrieves a [`Connection`] by its ID.
     ///
@@ -44,7 +47,8 @@ pub trait GetConnection {
     ) -> WorkOsResult<Connection, GetConnectionError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetConnection for Sso<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_connection(
@@ -61,7 +65,7 @@ impl GetConnection for Sso<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<Connection>()
+            .decode_json::<Connection>()
             .await?;
 
         Ok(connection)
@@ -85,7 +89,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/connections/conn_01E4ZCR3C56J083X43JQXF3JK5")
@@ -133,7 +138,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/connections/conn_01E4ZCR3C56J083X43JQXF3JK5")
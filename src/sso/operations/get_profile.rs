@@ -1,16 +1,64 @@
 use async_trait::async_trait;
+use reqwest::{Response, StatusCode};
 use thiserror::Error;
 
+use crate::core::response_to_request_error;
 use crate::sso::{AccessToken, Profile, Sso};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetProfile`].
 #[derive(Debug, Error)]
-pub enum GetProfileError {}
+pub enum GetProfileError {
+    /// The access token is invalid or has expired.
+    ///
+    /// The profile endpoint is authenticated with the access token rather than the API key,
+    /// so a rejected request indicates a problem with the token.
+    #[error("invalid or expired access token")]
+    InvalidAccessToken,
+}
+
+impl From<GetProfileError> for WorkOsError<GetProfileError> {
+    fn from(err: GetProfileError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for GetProfileError {}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+trait HandleGetProfileError
+where
+    Self: Sized,
+{
+    async fn handle_get_profile_error(self) -> WorkOsResult<Self, GetProfileError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl HandleGetProfileError for Response {
+    async fn handle_get_profile_error(self) -> WorkOsResult<Self, GetProfileError> {
+        if self.status().is_success() {
+            return Ok(self);
+        }
+
+        if self.status() == StatusCode::UNAUTHORIZED {
+            return Err(WorkOsError::Operation(GetProfileError::InvalidAccessToken));
+        }
+
+        Err(response_to_request_error(self).await)
+    }
+}
 
 /// [WorkOS Docs: Get a User Profile](https://workos.com/docs/reference/sso/profile/user)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetProfile {
+    /// Retrieves the [`Profile`] associated with an access token.
+    ///
+    /// This allows SSO-only integrations to resolve a profile from an access token
+    /// obtained earlier through [`GetProfileAndToken`](crate::sso::GetProfileAndToken).
+    ///
     /// [WorkOS Docs: Get a User Profile](https://workos.com/docs/reference/sso/profile/user)
     ///
     /// # Examples
@@ -36,7 +84,8 @@ pub trait GetProfile {
     ) -> WorkOsResult<Profile, GetProfileError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetProfile for Sso<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_profile(
@@ -46,11 +95,16 @@ impl GetProfile for Sso<'_> {
         let url = self.workos.base_url().join("/sso/profile")?;
         let get_profile_response = self
             .workos
-            .send(self.workos.client().get(url).bearer_auth(access_token))
+            .send(
+                self.workos
+                    .client()
+                    .get(url)
+                    .bearer_auth(access_token.expose_secret()),
+            )
             .await?
-            .handle_unauthorized_or_generic_error()
+            .handle_get_profile_error()
             .await?
-            .json::<Profile>()
+            .decode_json::<Profile>()
             .await?;
 
         Ok(get_profile_response)
@@ -59,11 +113,12 @@ impl GetProfile for Sso<'_> {
 
 #[cfg(test)]
 mod test {
+    use matches::assert_matches;
     use serde_json::json;
     use tokio;
 
     use crate::sso::ProfileId;
-    use crate::{ApiKey, WorkOs};
+    use crate::{ApiKey, WorkOs, WorkOsError};
 
     use super::*;
 
@@ -74,7 +129,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/sso/profile")
@@ -108,4 +164,32 @@ mod test {
             ProfileId::from("prof_01DMC79VCBZ0NY2099737PSVF1")
         )
     }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_access_token_is_invalid() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/sso/profile")
+            .with_status(401)
+            .with_body(json!({ "message": "Unauthorized" }).to_string())
+            .create_async()
+            .await;
+
+        let result = workos
+            .sso()
+            .get_profile(&AccessToken::from("01DMEK0J53CVMC32CK5SE0KZ8Q"))
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(GetProfileError::InvalidAccessToken))
+        )
+    }
 }
@@ -5,7 +5,7 @@ use thiserror::Error;
 
 use crate::core::response_to_request_error;
 use crate::sso::{AccessToken, AuthorizationCode, ClientId, Profile, Sso};
-use crate::{WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`GetProfileAndToken`].
 #[derive(Debug)]
@@ -39,7 +39,10 @@ pub struct GetProfileAndTokenError {
     pub error_description: String,
 }
 
-#[async_trait]
+impl OperationError for GetProfileAndTokenError {}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 trait HandleGetProfileAndTokenError
 where
     Self: Sized,
@@ -49,7 +52,8 @@ where
     ) -> WorkOsResult<Self, GetProfileAndTokenError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl HandleGetProfileAndTokenError for Response {
     async fn handle_get_profile_and_token_error(
         self,
@@ -59,7 +63,7 @@ impl HandleGetProfileAndTokenError for Response {
         }
 
         if self.status() == StatusCode::BAD_REQUEST {
-            let error = self.json::<GetProfileAndTokenError>().await?;
+            let error = self.decode_json::<GetProfileAndTokenError>().await?;
 
             return Err(match error.error.as_str() {
                 "invalid_client" | "unauthorized_client" => WorkOsError::Unauthorized,
@@ -72,7 +76,8 @@ impl HandleGetProfileAndTokenError for Response {
 }
 
 /// [WorkOS Docs: Get a Profile and Token](https://workos.com/docs/reference/sso/profile/token)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetProfileAndToken {
     /// [WorkOS Docs: Get a Profile and Token](https://workos.com/docs/reference/sso/profile/token)
     ///
@@ -102,7 +107,8 @@ pub trait GetProfileAndToken {
     ) -> WorkOsResult<GetProfileAndTokenResponse, GetProfileAndTokenError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetProfileAndToken for Sso<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_profile_and_token(
@@ -124,7 +130,7 @@ impl GetProfileAndToken for Sso<'_> {
             .await?
             .handle_get_profile_and_token_error()
             .await?
-            .json::<GetProfileAndTokenResponse>()
+            .decode_json::<GetProfileAndTokenResponse>()
             .await?;
 
         Ok(get_profile_and_token_response)
@@ -150,7 +156,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -210,7 +217,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -243,7 +251,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -276,7 +285,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/sso/token")
@@ -1,13 +1,17 @@
 mod access_token;
 mod authorization_code;
 mod client_id;
+mod code_challenge;
 mod connection;
 mod connection_type;
+mod oauth_provider;
 mod profile;
 
 pub use access_token::*;
 pub use authorization_code::*;
 pub use client_id::*;
+pub use code_challenge::*;
 pub use connection::*;
 pub use connection_type::*;
+pub use oauth_provider::*;
 pub use profile::*;
@@ -1,9 +1,9 @@
-use derive_more::{Deref, Display, From};
+use derive_more::{Deref, From};
 use serde::{Deserialize, Serialize};
 
+use crate::SecretString;
+
 /// An access token that may be exchanged for a [`Profile`](crate::sso::Profile).
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct AccessToken(String);
+pub struct AccessToken(SecretString);
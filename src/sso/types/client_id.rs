@@ -1,9 +1,11 @@
 use derive_more::{Deref, Display, From};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A client ID used to initiate SSO.
 ///
 /// Each environment will have its own client ID.
-#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
 #[from(forward)]
 pub struct ClientId(String);
@@ -0,0 +1,6 @@
+/// Code challenge used for the PKCE flow.
+#[derive(Debug)]
+pub enum CodeChallenge<'a> {
+    /// S256 code challenge method.
+    S256(&'a str),
+}
@@ -15,6 +15,12 @@ pub enum ConnectionType {
     #[serde(rename = "ADPOIDC")]
     AdpOidc,
 
+    /// Apple OAuth.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/apple)
+    #[serde(rename = "AppleOAuth")]
+    AppleOauth,
+
     /// Auth0 SAML.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/auth0-saml)
@@ -69,6 +75,12 @@ pub enum ConnectionType {
     #[serde(rename = "GenericSAML")]
     GenericSaml,
 
+    /// GitHub OAuth.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/github-oauth)
+    #[serde(rename = "GitHubOAuth")]
+    GitHubOauth,
+
     /// Google OAuth.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/g-suite-oauth)
@@ -93,6 +105,24 @@ pub enum ConnectionType {
     #[serde(rename = "KeycloakSAML")]
     KeycloakSaml,
 
+    /// LastPass SAML.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/lastpass-saml)
+    #[serde(rename = "LastPassSAML")]
+    LastPassSaml,
+
+    /// Login.gov OpenID Connect (OIDC).
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/login-gov-oidc)
+    #[serde(rename = "LoginGovOidc")]
+    LoginGovOidc,
+
+    /// Magic Link.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/magic-link)
+    #[serde(rename = "MagicLink")]
+    MagicLink,
+
     /// Microsoft OAuth.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/microsoft-oauth)
@@ -141,12 +171,24 @@ pub enum ConnectionType {
     #[serde(rename = "PingOneSAML")]
     PingOneSaml,
 
+    /// Rippling SAML.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/rippling-saml)
+    #[serde(rename = "RipplingSAML")]
+    RipplingSaml,
+
     /// Salesforce SAML.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/salesforce-saml)
     #[serde(rename = "SalesforceSAML")]
     SalesforceSaml,
 
+    /// Shibboleth Generic SAML.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/shibboleth-generic-saml)
+    #[serde(rename = "ShibbolethGenericSAML")]
+    ShibbolethGenericSaml,
+
     /// Shibboleth SAML.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/shibboleth)
@@ -187,4 +229,19 @@ mod test {
             ConnectionType::AdpOidc
         )
     }
+
+    #[test]
+    fn it_properly_deserializes_oauth_and_magic_link_types() {
+        assert_eq!(
+            serde_json::from_str::<Vec<ConnectionType>>(
+                &json!(["AppleOAuth", "GitHubOAuth", "MagicLink"]).to_string()
+            )
+            .unwrap(),
+            vec![
+                ConnectionType::AppleOauth,
+                ConnectionType::GitHubOauth,
+                ConnectionType::MagicLink
+            ]
+        )
+    }
 }
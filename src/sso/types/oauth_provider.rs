@@ -0,0 +1,87 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// The type of OAuth provider.
+#[derive(Clone, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OauthProvider {
+    /// Apple OAuth.
+    AppleOAuth,
+
+    /// Bitbucket OAuth.
+    BitbucketOAuth,
+
+    /// Discord OAuth.
+    DiscordOAuth,
+
+    /// GitHub OAuth.
+    #[display("GitHubOAuth")]
+    #[serde(rename = "GitHubOAuth", alias = "GithubOAuth")]
+    GithubOAuth,
+
+    /// GitLab OAuth.
+    GitLabOAuth,
+
+    /// Google OAuth.
+    GoogleOAuth,
+
+    /// LinkedIn OAuth.
+    LinkedInOAuth,
+
+    /// Microsoft OAuth.
+    MicrosoftOAuth,
+
+    /// Salesforce OAuth.
+    SalesforceOAuth,
+
+    /// Slack OAuth.
+    SlackOAuth,
+
+    /// Xero OAuth.
+    XeroOAuth,
+
+    /// An OAuth provider not yet known to this SDK.
+    ///
+    /// The contained value is passed through to the API as-is.
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::OauthProvider;
+
+    #[test]
+    fn it_serializes_github_oauth_with_the_api_casing() {
+        assert_eq!(
+            serde_json::to_string(&OauthProvider::GithubOAuth).unwrap(),
+            json!("GitHubOAuth").to_string()
+        );
+        assert_eq!(OauthProvider::GithubOAuth.to_string(), "GitHubOAuth");
+    }
+
+    #[test]
+    fn it_deserializes_a_known_provider() {
+        let provider: OauthProvider =
+            serde_json::from_str(&json!("MicrosoftOAuth").to_string()).unwrap();
+
+        assert_eq!(provider, OauthProvider::MicrosoftOAuth)
+    }
+
+    #[test]
+    fn it_deserializes_an_unknown_provider_as_custom() {
+        let provider: OauthProvider =
+            serde_json::from_str(&json!("AcmeOAuth").to_string()).unwrap();
+
+        assert_eq!(provider, OauthProvider::Custom("AcmeOAuth".to_string()))
+    }
+
+    #[test]
+    fn it_displays_a_custom_provider_as_its_value() {
+        assert_eq!(
+            OauthProvider::Custom("AcmeOAuth".to_string()).to_string(),
+            "AcmeOAuth"
+        )
+    }
+}
@@ -1,4 +1,5 @@
 use derive_more::{Deref, Display, From};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::organizations::OrganizationId;
@@ -15,7 +16,7 @@ pub struct ProfileId(String);
 
 /// [WorkOS Docs: Profile](https://workos.com/docs/reference/sso/profile)
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Profile {
+pub struct Profile<TRawAttributes = RawAttributes> {
     /// The ID of the profile.
     pub id: ProfileId,
 
@@ -41,5 +42,90 @@ pub struct Profile {
     pub last_name: Option<String>,
 
     /// The raw attributes received from the Identity Provider.
-    pub raw_attributes: RawAttributes,
+    pub raw_attributes: TRawAttributes,
+}
+
+impl Profile {
+    /// Deserializes the raw attributes of the [`Profile`] into the provided type.
+    ///
+    /// This is useful for working with SAML attribute mappings in a strongly typed manner.
+    pub fn raw_attributes_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::to_value(&self.raw_attributes).and_then(serde_json::from_value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::Profile;
+
+    #[test]
+    fn it_deserializes_a_profile_with_a_provided_raw_attributes_type() {
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct MyRawAttributes {
+            pub department: String,
+        }
+
+        let profile: Profile<MyRawAttributes> = serde_json::from_str(
+            &json!({
+                "object": "profile",
+                "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+                "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                "connection_type": "OktaSAML",
+                "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                "email": "todd@example.com",
+                "first_name": "Todd",
+                "last_name": "Rundgren",
+                "idp_id": "00u1a0ufowBJlzPlk357",
+                "raw_attributes": {
+                    "department": "Engineering"
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            profile.raw_attributes,
+            MyRawAttributes {
+                department: "Engineering".to_string()
+            }
+        )
+    }
+
+    #[test]
+    fn it_deserializes_the_raw_attributes_of_a_profile_into_a_provided_type() {
+        #[derive(Debug, PartialEq, Eq, Deserialize)]
+        struct MyRawAttributes {
+            pub department: String,
+        }
+
+        let profile: Profile = serde_json::from_str(
+            &json!({
+                "object": "profile",
+                "id": "prof_01DMC79VCBZ0NY2099737PSVF1",
+                "connection_id": "conn_01E4ZCR3C56J083X43JQXF3JK5",
+                "connection_type": "OktaSAML",
+                "organization_id": "org_01EHWNCE74X7JSDV0X3SZ3KJNY",
+                "email": "todd@example.com",
+                "first_name": "Todd",
+                "last_name": "Rundgren",
+                "idp_id": "00u1a0ufowBJlzPlk357",
+                "raw_attributes": {
+                    "department": "Engineering"
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            profile.raw_attributes_as::<MyRawAttributes>().unwrap(),
+            MyRawAttributes {
+                department: "Engineering".to_string()
+            }
+        )
+    }
 }
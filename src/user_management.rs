@@ -2,10 +2,46 @@
 //!
 //! [WorkOS Docs: User Management](https://workos.com/docs/user-management)
 
+#[cfg(feature = "jwt")]
+mod access_token_verifier;
+#[cfg(all(feature = "actix", feature = "jwt", feature = "sealed-session"))]
+pub mod actix;
+#[cfg(all(
+    any(feature = "actix", feature = "axum"),
+    feature = "jwt",
+    feature = "sealed-session"
+))]
+mod authkit;
+#[cfg(all(feature = "axum", feature = "jwt", feature = "sealed-session"))]
+pub mod axum;
+#[cfg(feature = "http")]
+mod client_info;
+#[cfg(feature = "jwt")]
+mod jwks_cache;
 mod operations;
+#[cfg(feature = "sealed-session")]
+mod sealed_session;
+#[cfg(feature = "jwt")]
+mod session_manager;
 mod types;
 
+#[cfg(feature = "jwt")]
+pub use access_token_verifier::*;
+#[cfg(all(
+    any(feature = "actix", feature = "axum"),
+    feature = "jwt",
+    feature = "sealed-session"
+))]
+pub use authkit::*;
+#[cfg(feature = "http")]
+pub use client_info::*;
+#[cfg(feature = "jwt")]
+pub use jwks_cache::*;
 pub use operations::*;
+#[cfg(feature = "sealed-session")]
+pub use sealed_session::*;
+#[cfg(feature = "jwt")]
+pub use session_manager::*;
 pub use types::*;
 
 use crate::WorkOs;
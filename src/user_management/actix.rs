@@ -0,0 +1,301 @@
+//! [Actix Web](https://docs.rs/actix-web) integration for authenticating requests with AuthKit
+//! sessions.
+//!
+//! Requires the `actix`, `jwt` and `sealed-session` features.
+//!
+//! # Examples
+//!
+//! ```
+//! use actix_web::middleware::from_fn;
+//! use actix_web::{App, web};
+//! use workos_sdk::sso::ClientId;
+//! use workos_sdk::user_management::actix::authkit_middleware;
+//! use workos_sdk::user_management::{AuthKit, AuthKitUser, SessionManager, SessionSealer};
+//! use workos_sdk::{ApiKey, WorkOs};
+//!
+//! async fn me(user: AuthKitUser) -> String {
+//!     user.claims.sub.to_string()
+//! }
+//!
+//! let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+//! let authkit = AuthKit::new(SessionManager::new(
+//!     &workos,
+//!     &ClientId::from("client_123456789"),
+//! ))
+//! .sealed_cookie(SessionSealer::new("a-cookie-password-at-least-32-characters-long").unwrap());
+//!
+//! let app = App::new()
+//!     .app_data(web::Data::new(authkit))
+//!     .wrap(from_fn(authkit_middleware))
+//!     .route("/me", web::get().to(me));
+//! ```
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{Payload, ServiceRequest, ServiceResponse};
+use actix_web::http::StatusCode;
+use actix_web::http::header::{AUTHORIZATION, COOKIE, HeaderMap, HeaderValue, SET_COOKIE};
+use actix_web::middleware::Next;
+use actix_web::{FromRequest, HttpMessage, HttpRequest, HttpResponse, ResponseError, web};
+use futures_util::future::LocalBoxFuture;
+
+use crate::user_management::authkit::AuthKitMiddlewareRejected;
+use crate::user_management::{AuthKit, AuthKitRejection, AuthKitUser};
+
+fn credentials(headers: &HeaderMap) -> (Option<&str>, impl Iterator<Item = &str>) {
+    let authorization = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let cookies = headers
+        .get_all(COOKIE)
+        .filter_map(|value| value.to_str().ok());
+
+    (authorization, cookies)
+}
+
+impl ResponseError for AuthKitRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status().as_u16()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.public_message())
+    }
+}
+
+/// Extracts the [`AuthKitUser`] authenticated by [`authkit_middleware`], or verifies the
+/// request with the [`AuthKit`] registered as `web::Data<AuthKit>` app data.
+///
+/// Without the middleware, sessions are verified but never refreshed, since the rotated
+/// refresh token could not be stored: requests whose access token has expired are
+/// rejected.
+impl FromRequest for AuthKitUser {
+    type Error = AuthKitRejection;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let user = request.extensions().get::<AuthKitUser>().cloned();
+        let rejected = request
+            .extensions()
+            .get::<AuthKitMiddlewareRejected>()
+            .is_some();
+        let authkit = request.app_data::<web::Data<AuthKit>>().cloned();
+        let headers = request.headers().clone();
+
+        Box::pin(async move {
+            if let Some(user) = user {
+                return Ok(user);
+            }
+            if rejected {
+                return Err(AuthKitRejection::Unauthenticated);
+            }
+
+            let authkit = authkit.ok_or(AuthKitRejection::NotConfigured)?;
+            let (authorization, cookies) = credentials(&headers);
+
+            authkit.verify(authorization, cookies).await
+        })
+    }
+}
+
+/// Middleware that authenticates requests with [`AuthKit`] and persists refreshed sessions.
+///
+/// The [`AuthKit`] is taken from the `web::Data<AuthKit>` app data. Authenticated users
+/// are made available to handlers through [`AuthKitUser`], and the updated session cookie
+/// is set on the response when the session was refreshed. Requests that cannot be
+/// authenticated are passed on unchanged, so that public routes keep working. Install it
+/// with [`from_fn`](actix_web::middleware::from_fn).
+///
+/// Without this middleware, [`AuthKitUser`] still verifies requests, but rejects those
+/// whose access token has expired instead of refreshing their session.
+pub async fn authkit_middleware(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let Some(authkit) = request.app_data::<web::Data<AuthKit>>().cloned() else {
+        return next.call(request).await;
+    };
+
+    let (authorization, cookies) = credentials(request.headers());
+    let Ok((user, set_cookie)) = authkit.authenticate(authorization, cookies).await else {
+        request.extensions_mut().insert(AuthKitMiddlewareRejected);
+        return next.call(request).await;
+    };
+
+    request.extensions_mut().insert(user);
+    let mut response = next.call(request).await?;
+
+    if let Some(value) = set_cookie.and_then(|cookie| HeaderValue::from_str(&cookie).ok()) {
+        response.headers_mut().append(SET_COOKIE, value);
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::middleware::from_fn;
+    use actix_web::test::{TestRequest, call_service, init_service};
+    use actix_web::{App, ResponseError};
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::user_management::RefreshToken;
+    use crate::user_management::access_token_verifier::test::{issued_by, sign};
+    use crate::user_management::authkit::test::{
+        authkit, refresh_response, sealed_session, unseal,
+    };
+
+    use super::*;
+
+    async fn me(user: AuthKitUser) -> String {
+        user.claims.sub.to_string()
+    }
+
+    #[tokio::test]
+    async fn it_extracts_the_user_from_a_bearer_token() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let request = TestRequest::default()
+            .app_data(web::Data::new(authkit))
+            .insert_header((
+                AUTHORIZATION,
+                format!("Bearer {}", sign(issued_by(&server, json!({})))),
+            ))
+            .to_http_request();
+
+        let user = AuthKitUser::extract(&request).await.unwrap();
+
+        assert_eq!(
+            user.claims.sub.to_string(),
+            "user_01E4ZCR3C56J083X43JQXF3JK5"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_rejects_unauthenticated_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let request = TestRequest::default()
+            .app_data(web::Data::new(authkit))
+            .to_http_request();
+
+        let rejection = AuthKitUser::extract(&request).await.unwrap_err();
+
+        assert_matches!(rejection, AuthKitRejection::Unauthenticated);
+        assert_eq!(rejection.status_code(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn it_rejects_requests_when_authkit_is_not_configured() {
+        let request = TestRequest::default().to_http_request();
+
+        let rejection = AuthKitUser::extract(&request).await.unwrap_err();
+
+        assert_matches!(rejection, AuthKitRejection::NotConfigured);
+        assert_eq!(rejection.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn it_sets_the_refreshed_session_cookie_from_the_middleware() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(refresh_response(&sign(issued_by(&server, json!({})))))
+            .create_async()
+            .await;
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(authkit))
+                .wrap(from_fn(authkit_middleware))
+                .route("/me", web::get().to(me)),
+        )
+        .await;
+
+        let expired = sign(issued_by(
+            &server,
+            json!({ "exp": chrono::Utc::now().timestamp() - 600 }),
+        ));
+        let response = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/me")
+                .insert_header((COOKIE, format!("wos-session={}", sealed_session(expired))))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let session = unseal(
+            response
+                .headers()
+                .get(SET_COOKIE)
+                .unwrap()
+                .to_str()
+                .unwrap(),
+        );
+        assert_eq!(session.refresh_token, RefreshToken::from("refresh_token_2"));
+    }
+
+    #[tokio::test]
+    async fn it_does_not_refresh_expired_sessions_without_the_middleware() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let expired = sign(issued_by(
+            &server,
+            json!({ "exp": chrono::Utc::now().timestamp() - 600 }),
+        ));
+        let request = TestRequest::default()
+            .app_data(web::Data::new(authkit))
+            .insert_header((COOKIE, format!("wos-session={}", sealed_session(expired))))
+            .to_http_request();
+
+        let rejection = AuthKitUser::extract(&request).await.unwrap_err();
+
+        assert_eq!(rejection.status_code(), StatusCode::UNAUTHORIZED);
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_disclose_why_a_session_was_rejected() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(authkit))
+                .route("/me", web::get().to(me)),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/me")
+                .insert_header((AUTHORIZATION, "Bearer not-a-jwt"))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            actix_web::body::to_bytes(response.into_body())
+                .await
+                .unwrap(),
+            "Unauthorized"
+        );
+    }
+}
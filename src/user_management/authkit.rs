@@ -0,0 +1,358 @@
+use std::fmt;
+use std::sync::Arc;
+
+use http::StatusCode;
+use thiserror::Error;
+
+use crate::sso::AccessToken;
+use crate::user_management::{
+    AccessTokenClaims, Impersonator, SealedSessionError, SessionError, SessionManager,
+    SessionSealer, User,
+};
+use crate::{ErrorClassification, OperationError, WorkOsError};
+
+/// The default name of the sealed session cookie.
+pub const DEFAULT_SESSION_COOKIE_NAME: &str = "wos-session";
+
+/// The configuration used to authenticate web requests with AuthKit sessions.
+///
+/// Requests are authenticated with a bearer token in the `Authorization` header or, if a
+/// [`SessionSealer`] is configured, with a sealed session cookie. Sessions from cookies
+/// are refreshed by the framework middleware when their access token has expired.
+///
+/// See the `axum` and `actix` modules of [`user_management`](crate::user_management) for
+/// the framework integrations.
+#[derive(Clone)]
+pub struct AuthKit {
+    sessions: Arc<SessionManager>,
+    sealer: Option<SessionSealer>,
+    cookie_name: String,
+}
+
+impl AuthKit {
+    /// Returns a new [`AuthKit`] that authenticates sessions with the provided manager.
+    pub fn new(sessions: SessionManager) -> Self {
+        Self {
+            sessions: Arc::new(sessions),
+            sealer: None,
+            cookie_name: DEFAULT_SESSION_COOKIE_NAME.to_string(),
+        }
+    }
+
+    /// Authenticates requests with a session cookie sealed by the provided sealer.
+    pub fn sealed_cookie(mut self, sealer: SessionSealer) -> Self {
+        self.sealer = Some(sealer);
+        self
+    }
+
+    /// Sets the name of the session cookie.
+    ///
+    /// Defaults to [`DEFAULT_SESSION_COOKIE_NAME`].
+    pub fn cookie_name(mut self, cookie_name: impl Into<String>) -> Self {
+        self.cookie_name = cookie_name.into();
+        self
+    }
+
+    /// Authenticates a request from its `Authorization` and `Cookie` header values,
+    /// returning the user and, if the session was refreshed, the `Set-Cookie` value for
+    /// the updated session.
+    ///
+    /// The `Set-Cookie` value must be sent to the client: refresh tokens are single-use, so
+    /// the previous session cookie is no longer valid once the session has been refreshed.
+    pub(crate) async fn authenticate<'a>(
+        &self,
+        authorization: Option<&str>,
+        cookies: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(AuthKitUser, Option<String>), AuthKitRejection> {
+        self.authenticate_request(authorization, cookies, true)
+            .await
+    }
+
+    /// Authenticates a request from its `Authorization` and `Cookie` header values without
+    /// refreshing the session, so requests whose access token has expired are rejected.
+    pub(crate) async fn verify<'a>(
+        &self,
+        authorization: Option<&str>,
+        cookies: impl IntoIterator<Item = &'a str>,
+    ) -> Result<AuthKitUser, AuthKitRejection> {
+        let (user, _) = self
+            .authenticate_request(authorization, cookies, false)
+            .await?;
+
+        Ok(user)
+    }
+
+    async fn authenticate_request<'a>(
+        &self,
+        authorization: Option<&str>,
+        cookies: impl IntoIterator<Item = &'a str>,
+        refresh: bool,
+    ) -> Result<(AuthKitUser, Option<String>), AuthKitRejection> {
+        if let Some(token) = authorization.and_then(bearer_token) {
+            let access_token = AccessToken::from(token.trim());
+            let claims = self.sessions.verify(&access_token).await?;
+
+            return Ok((
+                AuthKitUser {
+                    claims,
+                    access_token,
+                    user: None,
+                    impersonator: None,
+                },
+                None,
+            ));
+        }
+
+        let sealer = self
+            .sealer
+            .as_ref()
+            .ok_or(AuthKitRejection::Unauthenticated)?;
+        let cookie = cookies
+            .into_iter()
+            .flat_map(|value| value.split(';'))
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find_map(|(name, value)| (name == self.cookie_name).then_some(value))
+            .ok_or(AuthKitRejection::Unauthenticated)?;
+
+        let mut data = sealer.unseal(cookie)?;
+
+        if !refresh {
+            let claims = self.sessions.verify(&data.access_token).await?;
+
+            return Ok((
+                AuthKitUser {
+                    claims,
+                    access_token: data.access_token,
+                    user: Some(data.user),
+                    impersonator: data.impersonator,
+                },
+                None,
+            ));
+        }
+
+        let session = self
+            .sessions
+            .authenticate(&data.access_token, &data.refresh_token)
+            .await?;
+
+        let set_cookie = if session.refreshed {
+            data.access_token = session.access_token.clone();
+            data.refresh_token = session.refresh_token;
+            if let Some(user) = session.user {
+                data.user = user;
+            }
+
+            Some(format!(
+                "{}={}; Path=/; HttpOnly; Secure; SameSite=Lax",
+                self.cookie_name,
+                sealer.seal(&data)?
+            ))
+        } else {
+            None
+        };
+
+        Ok((
+            AuthKitUser {
+                claims: session.claims,
+                access_token: session.access_token,
+                user: Some(data.user),
+                impersonator: data.impersonator,
+            },
+            set_cookie,
+        ))
+    }
+}
+
+impl fmt::Debug for AuthKit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthKit")
+            .field("client_id", self.sessions.client_id())
+            .field("cookie_name", &self.cookie_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Marks a request that the AuthKit middleware failed to authenticate, so that extractors
+/// reject it instead of authenticating it a second time.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AuthKitMiddlewareRejected;
+
+/// The user of a request authenticated with an AuthKit session.
+///
+/// Used as an extractor in the framework integrations. Requests without a valid session
+/// are rejected with `401 Unauthorized`.
+#[derive(Clone, Debug)]
+pub struct AuthKitUser {
+    /// The claims of the access token.
+    pub claims: AccessTokenClaims,
+
+    /// The access token, which is refreshed if the session was refreshed.
+    pub access_token: AccessToken,
+
+    /// The user, if the request was authenticated with a session cookie.
+    pub user: Option<User>,
+
+    /// The WorkOS Dashboard user who is impersonating the user, if any.
+    pub impersonator: Option<Impersonator>,
+}
+
+/// The rejection returned when [`AuthKitUser`] cannot be extracted.
+#[derive(Debug, Error)]
+pub enum AuthKitRejection {
+    /// No [`AuthKit`] configuration is available to the request.
+    #[error("AuthKit is not configured for this application")]
+    NotConfigured,
+
+    /// The request has neither a bearer token nor a session cookie.
+    #[error("missing session cookie or bearer token")]
+    Unauthenticated,
+
+    /// The session cookie could not be unsealed.
+    #[error(transparent)]
+    InvalidSession(#[from] SealedSessionError),
+
+    /// The session could not be verified or refreshed.
+    #[error(transparent)]
+    Session(#[from] WorkOsError<SessionError>),
+}
+
+impl AuthKitRejection {
+    /// Returns the status code the request is rejected with.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::Unauthenticated | Self::InvalidSession(_) => StatusCode::UNAUTHORIZED,
+            Self::Session(WorkOsError::Operation(err))
+                if !matches!(
+                    err.classification(),
+                    ErrorClassification::ConfigurationError
+                ) =>
+            {
+                StatusCode::UNAUTHORIZED
+            }
+            Self::NotConfigured | Self::Session(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Returns the message sent to the client, which does not disclose why the session
+    /// could not be verified or refreshed.
+    pub(crate) fn public_message(&self) -> &'static str {
+        self.status().canonical_reason().unwrap_or("Error")
+    }
+}
+
+/// Returns the token of a `Bearer` authorization header, whose scheme is case-insensitive.
+fn bearer_token(authorization: &str) -> Option<&str> {
+    let (scheme, token) = authorization.trim_start().split_once(' ')?;
+
+    scheme.eq_ignore_ascii_case("bearer").then_some(token)
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use serde_json::{Value, json};
+
+    use crate::sso::ClientId;
+    use crate::user_management::access_token_verifier::test::{issued_by, jwks, sign};
+    use crate::user_management::{RefreshToken, SessionData, VerifyAccessTokenError};
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    pub(crate) const PASSWORD: &str = "kR8sX2vQ9mZ4tL7wN1pB6yH3cF5jD0gA";
+
+    pub(crate) fn user() -> Value {
+        json!({
+            "object": "user",
+            "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "email": "marcelina.davis@example.com",
+            "first_name": "Marcelina",
+            "last_name": "Davis",
+            "email_verified": true,
+            "profile_picture_url": null,
+            "metadata": {},
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        })
+    }
+
+    pub(crate) fn refresh_response(access_token: &str) -> String {
+        json!({
+            "user": user(),
+            "organization_id": null,
+            "access_token": access_token,
+            "refresh_token": "refresh_token_2",
+            "authentication_method": "Password",
+            "impersonator": null
+        })
+        .to_string()
+    }
+
+    pub(crate) async fn authkit(server: &mut mockito::Server) -> AuthKit {
+        server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .create_async()
+            .await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        AuthKit::new(SessionManager::new(&workos, &ClientId::from("client_456")))
+            .sealed_cookie(SessionSealer::new(PASSWORD).unwrap())
+    }
+
+    pub(crate) fn sealed_session(access_token: String) -> String {
+        let data = SessionData {
+            access_token: AccessToken::from(access_token),
+            refresh_token: RefreshToken::from("refresh_token_1"),
+            user: serde_json::from_value(user()).unwrap(),
+            organization_id: None,
+            impersonator: None,
+        };
+
+        SessionSealer::new(PASSWORD).unwrap().seal(&data).unwrap()
+    }
+
+    pub(crate) fn unseal(set_cookie: &str) -> SessionData {
+        let sealed = set_cookie
+            .strip_prefix("wos-session=")
+            .and_then(|cookie| cookie.split(';').next())
+            .unwrap();
+
+        SessionSealer::new(PASSWORD)
+            .unwrap()
+            .unseal(sealed)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_accepts_the_bearer_scheme_in_any_case() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+        let token = sign(issued_by(&server, json!({})));
+
+        for scheme in ["Bearer", "bearer", "BEARER"] {
+            let authorization = format!("{scheme} {token}");
+
+            assert!(
+                authkit.verify(Some(&authorization), []).await.is_ok(),
+                "{scheme}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_rejects_requests_for_a_missing_client_with_a_server_error() {
+        let rejection = AuthKitRejection::Session(WorkOsError::Operation(
+            SessionError::InvalidAccessToken(VerifyAccessTokenError::ClientNotFound {
+                client_id: ClientId::from("client_456"),
+            }),
+        ));
+
+        assert_eq!(rejection.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,317 @@
+//! [Axum](https://docs.rs/axum) integration for authenticating requests with AuthKit sessions.
+//!
+//! Requires the `axum`, `jwt` and `sealed-session` features.
+//!
+//! # Examples
+//!
+//! ```
+//! use axum::Router;
+//! use axum::middleware::from_fn_with_state;
+//! use axum::routing::get;
+//! use workos_sdk::sso::ClientId;
+//! use workos_sdk::user_management::axum::authkit_middleware;
+//! use workos_sdk::user_management::{AuthKit, AuthKitUser, SessionManager, SessionSealer};
+//! use workos_sdk::{ApiKey, WorkOs};
+//!
+//! async fn me(user: AuthKitUser) -> String {
+//!     user.claims.sub.to_string()
+//! }
+//!
+//! let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+//! let authkit = AuthKit::new(SessionManager::new(
+//!     &workos,
+//!     &ClientId::from("client_123456789"),
+//! ))
+//! .sealed_cookie(SessionSealer::new("a-cookie-password-at-least-32-characters-long").unwrap());
+//!
+//! let app: Router = Router::new()
+//!     .route("/me", get(me))
+//!     .layer(from_fn_with_state(authkit.clone(), authkit_middleware))
+//!     .with_state(authkit);
+//! ```
+
+use axum::extract::{FromRef, FromRequestParts, Request, State};
+use axum::http::HeaderMap;
+use axum::http::header::{AUTHORIZATION, COOKIE, HeaderValue, SET_COOKIE};
+use axum::http::request::Parts;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+
+use crate::user_management::authkit::AuthKitMiddlewareRejected;
+use crate::user_management::{AuthKit, AuthKitRejection, AuthKitUser};
+
+fn credentials(headers: &HeaderMap) -> (Option<&str>, impl Iterator<Item = &str>) {
+    let authorization = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let cookies = headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok());
+
+    (authorization, cookies)
+}
+
+impl IntoResponse for AuthKitRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.public_message()).into_response()
+    }
+}
+
+/// Extracts the [`AuthKitUser`] authenticated by [`authkit_middleware`], or verifies the
+/// request with the [`AuthKit`] from the router state.
+///
+/// Without the middleware, sessions are verified but never refreshed, since the rotated
+/// refresh token could not be stored: requests whose access token has expired are
+/// rejected.
+impl<S> FromRequestParts<S> for AuthKitUser
+where
+    AuthKit: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AuthKitRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(user) = parts.extensions.get::<AuthKitUser>() {
+            return Ok(user.clone());
+        }
+        if parts
+            .extensions
+            .get::<AuthKitMiddlewareRejected>()
+            .is_some()
+        {
+            return Err(AuthKitRejection::Unauthenticated);
+        }
+
+        let (authorization, cookies) = credentials(&parts.headers);
+
+        AuthKit::from_ref(state)
+            .verify(authorization, cookies)
+            .await
+    }
+}
+
+/// Middleware that authenticates requests with [`AuthKit`] and persists refreshed sessions.
+///
+/// Authenticated users are made available to handlers through [`AuthKitUser`], and the
+/// updated session cookie is set on the response when the session was refreshed. Requests
+/// that cannot be authenticated are passed on unchanged, so that public routes keep
+/// working. Install it with [`from_fn_with_state`](axum::middleware::from_fn_with_state).
+///
+/// Without this middleware, [`AuthKitUser`] still verifies requests, but rejects those
+/// whose access token has expired instead of refreshing their session.
+pub async fn authkit_middleware(
+    State(authkit): State<AuthKit>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let (authorization, cookies) = credentials(request.headers());
+    let Ok((user, set_cookie)) = authkit.authenticate(authorization, cookies).await else {
+        request.extensions_mut().insert(AuthKitMiddlewareRejected);
+        return next.run(request).await;
+    };
+
+    request.extensions_mut().insert(user);
+    let mut response = next.run(request).await;
+
+    if let Some(value) = set_cookie.and_then(|cookie| HeaderValue::from_str(&cookie).ok()) {
+        response.headers_mut().append(SET_COOKIE, value);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod test {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use matches::assert_matches;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::user_management::RefreshToken;
+    use crate::user_management::access_token_verifier::test::{issued_by, sign};
+    use crate::user_management::authkit::test::{
+        authkit, refresh_response, sealed_session, unseal,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_extracts_the_user_from_a_bearer_token() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let request = Request::builder()
+            .header(
+                AUTHORIZATION,
+                format!("Bearer {}", sign(issued_by(&server, json!({})))),
+            )
+            .body(Body::empty())
+            .unwrap();
+        let (mut parts, _) = request.into_parts();
+
+        let user = AuthKitUser::from_request_parts(&mut parts, &authkit)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            user.claims.sub.to_string(),
+            "user_01E4ZCR3C56J083X43JQXF3JK5"
+        );
+        assert!(user.user.is_none());
+    }
+
+    #[tokio::test]
+    async fn it_extracts_the_user_from_a_sealed_session_cookie() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let request = Request::builder()
+            .header(
+                COOKIE,
+                format!(
+                    "theme=dark; wos-session={}",
+                    sealed_session(sign(issued_by(&server, json!({}))))
+                ),
+            )
+            .body(Body::empty())
+            .unwrap();
+        let (mut parts, _) = request.into_parts();
+
+        let user = AuthKitUser::from_request_parts(&mut parts, &authkit)
+            .await
+            .unwrap();
+
+        assert_eq!(user.user.unwrap().email, "marcelina.davis@example.com");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_unauthenticated_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let (mut parts, _) = Request::new(Body::empty()).into_parts();
+
+        let rejection = AuthKitUser::from_request_parts(&mut parts, &authkit)
+            .await
+            .unwrap_err();
+
+        assert_matches!(rejection, AuthKitRejection::Unauthenticated);
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn it_sets_the_refreshed_session_cookie_from_the_middleware() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(refresh_response(&sign(issued_by(&server, json!({})))))
+            .create_async()
+            .await;
+
+        let app = Router::new()
+            .route(
+                "/me",
+                get(|user: AuthKitUser| async move { user.claims.sub.to_string() }),
+            )
+            .layer(from_fn_with_state(authkit.clone(), authkit_middleware))
+            .with_state(authkit);
+
+        let expired = sign(issued_by(
+            &server,
+            json!({ "exp": chrono::Utc::now().timestamp() - 600 }),
+        ));
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/me")
+                    .header(COOKIE, format!("wos-session={}", sealed_session(expired)))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let session = unseal(response.headers()[SET_COOKIE].to_str().unwrap());
+        assert_eq!(session.refresh_token, RefreshToken::from("refresh_token_2"));
+    }
+
+    #[tokio::test]
+    async fn it_does_not_refresh_expired_sessions_without_the_middleware() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let expired = sign(issued_by(
+            &server,
+            json!({ "exp": chrono::Utc::now().timestamp() - 600 }),
+        ));
+        let request = Request::builder()
+            .header(COOKIE, format!("wos-session={}", sealed_session(expired)))
+            .body(Body::empty())
+            .unwrap();
+        let (mut parts, _) = request.into_parts();
+
+        let rejection = AuthKitUser::from_request_parts(&mut parts, &authkit)
+            .await
+            .unwrap_err();
+
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_authenticate_again_when_the_middleware_failed() {
+        let mut server = mockito::Server::new_async().await;
+        let authkit = authkit(&mut server).await;
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(json!({ "error": "invalid_grant" }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let app = Router::new()
+            .route(
+                "/me",
+                get(|user: AuthKitUser| async move { user.claims.sub.to_string() }),
+            )
+            .layer(from_fn_with_state(authkit.clone(), authkit_middleware))
+            .with_state(authkit);
+
+        let expired = sign(issued_by(
+            &server,
+            json!({ "exp": chrono::Utc::now().timestamp() - 600 }),
+        ));
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/me")
+                    .header(COOKIE, format!("wos-session={}", sealed_session(expired)))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.headers().get(SET_COOKIE).is_none());
+        refresh.assert_async().await;
+    }
+}
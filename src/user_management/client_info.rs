@@ -0,0 +1,298 @@
+use std::net::{IpAddr, SocketAddr};
+
+use http::header::{FORWARDED, HeaderMap, USER_AGENT};
+
+use crate::user_management::{
+    AuthenticateWithCodeParams, AuthenticateWithEmailVerificationParams,
+    AuthenticateWithMagicAuthParams, AuthenticateWithOrganizationSelectionParams,
+    AuthenticateWithPasswordParams, AuthenticateWithRefreshTokenParams, AuthenticateWithTotpParams,
+};
+
+/// The `X-Forwarded-For` header, which is not defined by [`http::header`].
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// The IP address and user agent of the user making a request to your application.
+///
+/// Pass these to the authenticate operations with [`WithClientInfo::with_client_info`],
+/// so that WorkOS receives consistent signals for fraud detection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// The IP address of the client, if it could be determined.
+    pub ip_address: Option<IpAddr>,
+
+    /// The `User-Agent` of the client, if provided.
+    pub user_agent: Option<String>,
+}
+
+/// Extracts [`ClientInfo`] from incoming [`http::Request`]s.
+///
+/// The `Forwarded` and `X-Forwarded-For` headers are only honored when the request was
+/// received from a trusted proxy, since any client can set them. The client IP address
+/// is the last address in the forwarding chain that is not a trusted proxy.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::IpAddr;
+///
+/// use workos_sdk::user_management::ClientInfoExtractor;
+///
+/// let load_balancer: IpAddr = "10.0.0.1".parse().unwrap();
+/// let extractor = ClientInfoExtractor::new().trust_proxy(load_balancer);
+///
+/// let request = http::Request::builder()
+///     .header("X-Forwarded-For", "203.0.113.7, 10.0.0.1")
+///     .header("User-Agent", "Mozilla/5.0")
+///     .body(())
+///     .unwrap();
+///
+/// let client_info = extractor.extract(&request, load_balancer);
+///
+/// assert_eq!(client_info.ip_address, Some("203.0.113.7".parse().unwrap()));
+/// assert_eq!(client_info.user_agent.as_deref(), Some("Mozilla/5.0"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClientInfoExtractor {
+    trusted_proxies: Vec<IpAddr>,
+}
+
+impl ClientInfoExtractor {
+    /// Returns a new [`ClientInfoExtractor`] that trusts no proxies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trusts the forwarding headers set by the proxy with the provided IP address.
+    pub fn trust_proxy(mut self, proxy: IpAddr) -> Self {
+        self.trusted_proxies.push(proxy);
+        self
+    }
+
+    /// Extracts the client IP address and user agent from a request received from
+    /// `peer_addr`, the address of the connection's remote end.
+    pub fn extract<B>(&self, request: &http::Request<B>, peer_addr: IpAddr) -> ClientInfo {
+        ClientInfo {
+            ip_address: self.client_ip(request.headers(), peer_addr),
+            user_agent: request
+                .headers()
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+        }
+    }
+
+    fn client_ip(&self, headers: &HeaderMap, peer_addr: IpAddr) -> Option<IpAddr> {
+        if !self.is_trusted(&peer_addr) {
+            return Some(peer_addr);
+        }
+
+        let chain = forwarded_chain(headers);
+
+        // Walk the chain from the closest hop, skipping trusted proxies. An address that
+        // cannot be parsed, such as an obfuscated identifier, ends the search.
+        for hop in chain.iter().rev() {
+            match hop {
+                Some(addr) if self.is_trusted(addr) => continue,
+                Some(addr) => return Some(*addr),
+                None => return None,
+            }
+        }
+
+        // Every hop is a trusted proxy, so the original sender is the best guess.
+        Some(chain.first().copied().flatten().unwrap_or(peer_addr))
+    }
+
+    fn is_trusted(&self, addr: &IpAddr) -> bool {
+        self.trusted_proxies.contains(addr)
+    }
+}
+
+/// Returns the addresses in the forwarding chain, from the original client to the
+/// closest proxy. `Forwarded` takes precedence over `X-Forwarded-For`.
+fn forwarded_chain(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let values = |name| {
+        headers
+            .get_all(name)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+    };
+
+    let forwarded = values(FORWARDED.as_str());
+    if !forwarded.is_empty() {
+        return forwarded
+            .into_iter()
+            .filter_map(|element| {
+                element.split(';').find_map(|pair| {
+                    let (key, value) = pair.trim().split_once('=')?;
+                    key.eq_ignore_ascii_case("for")
+                        .then(|| parse_node(value.trim_matches('"')))
+                })
+            })
+            .collect();
+    }
+
+    values(X_FORWARDED_FOR)
+        .into_iter()
+        .map(parse_node)
+        .collect()
+}
+
+/// Parses a node of a forwarding header, which may include a port and, for IPv6
+/// addresses, brackets.
+fn parse_node(node: &str) -> Option<IpAddr> {
+    node.parse::<IpAddr>()
+        .ok()
+        .or_else(|| node.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+        .or_else(|| {
+            node.strip_prefix('[')
+                .and_then(|node| node.strip_suffix(']'))
+                .and_then(|node| node.parse().ok())
+        })
+}
+
+/// Sets the IP address and user agent fields of authenticate parameters.
+pub trait WithClientInfo<'a> {
+    /// Sets the IP address and user agent from the provided [`ClientInfo`].
+    fn with_client_info(self, client_info: &'a ClientInfo) -> Self;
+}
+
+macro_rules! impl_with_client_info {
+    ($($params:ident),* $(,)?) => {
+        $(
+            impl<'a> WithClientInfo<'a> for $params<'a> {
+                fn with_client_info(self, client_info: &'a ClientInfo) -> Self {
+                    Self {
+                        ip_address: client_info.ip_address.as_ref(),
+                        user_agent: client_info.user_agent.as_deref(),
+                        ..self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_with_client_info!(
+    AuthenticateWithCodeParams,
+    AuthenticateWithEmailVerificationParams,
+    AuthenticateWithMagicAuthParams,
+    AuthenticateWithOrganizationSelectionParams,
+    AuthenticateWithPasswordParams,
+    AuthenticateWithRefreshTokenParams,
+    AuthenticateWithTotpParams,
+);
+
+#[cfg(test)]
+mod test {
+    use crate::sso::ClientId;
+
+    use super::*;
+
+    fn ip(addr: &str) -> IpAddr {
+        addr.parse().unwrap()
+    }
+
+    fn request(headers: &[(&str, &str)]) -> http::Request<()> {
+        let mut builder = http::Request::builder();
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn it_ignores_forwarding_headers_from_untrusted_peers() {
+        let extractor = ClientInfoExtractor::new();
+
+        let client_info = extractor.extract(
+            &request(&[("X-Forwarded-For", "203.0.113.7")]),
+            ip("198.51.100.1"),
+        );
+
+        assert_eq!(client_info.ip_address, Some(ip("198.51.100.1")));
+    }
+
+    #[test]
+    fn it_skips_trusted_proxies_in_x_forwarded_for() {
+        let extractor = ClientInfoExtractor::new()
+            .trust_proxy(ip("10.0.0.1"))
+            .trust_proxy(ip("10.0.0.2"));
+
+        let client_info = extractor.extract(
+            &request(&[("X-Forwarded-For", "192.0.2.1, 203.0.113.7, 10.0.0.2")]),
+            ip("10.0.0.1"),
+        );
+
+        assert_eq!(client_info.ip_address, Some(ip("203.0.113.7")));
+    }
+
+    #[test]
+    fn it_prefers_the_forwarded_header() {
+        let extractor = ClientInfoExtractor::new().trust_proxy(ip("10.0.0.1"));
+
+        let client_info = extractor.extract(
+            &request(&[
+                ("Forwarded", "for=\"[2001:db8:cafe::17]:4711\";proto=https"),
+                ("X-Forwarded-For", "203.0.113.7"),
+                ("User-Agent", "Mozilla/5.0"),
+            ]),
+            ip("10.0.0.1"),
+        );
+
+        assert_eq!(
+            client_info,
+            ClientInfo {
+                ip_address: Some(ip("2001:db8:cafe::17")),
+                user_agent: Some("Mozilla/5.0".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn it_does_not_guess_past_an_obfuscated_hop() {
+        let extractor = ClientInfoExtractor::new().trust_proxy(ip("10.0.0.1"));
+
+        let client_info = extractor.extract(
+            &request(&[("Forwarded", "for=_hidden, for=unknown")]),
+            ip("10.0.0.1"),
+        );
+
+        assert_eq!(client_info.ip_address, None);
+    }
+
+    #[test]
+    fn it_uses_the_peer_address_without_forwarding_headers() {
+        let extractor = ClientInfoExtractor::new().trust_proxy(ip("10.0.0.1"));
+
+        let client_info = extractor.extract(&request(&[]), ip("10.0.0.1"));
+
+        assert_eq!(client_info.ip_address, Some(ip("10.0.0.1")));
+    }
+
+    #[test]
+    fn it_sets_the_authenticate_params() {
+        let client_info = ClientInfo {
+            ip_address: Some(ip("203.0.113.7")),
+            user_agent: Some("Mozilla/5.0".to_string()),
+        };
+
+        let client_id = ClientId::from("client_123456789");
+        let email = "marcelina@example.com".parse().unwrap();
+        let params = AuthenticateWithPasswordParams {
+            client_id: &client_id,
+            email: &email,
+            password: "i8uv6g34kd490s",
+            invitation_token: None,
+            ip_address: None,
+            user_agent: None,
+        }
+        .with_client_info(&client_info);
+
+        assert_eq!(params.ip_address, Some(&ip("203.0.113.7")));
+        assert_eq!(params.user_agent, Some("Mozilla/5.0"));
+    }
+}
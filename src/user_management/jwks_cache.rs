@@ -0,0 +1,544 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use jsonwebtoken::jwk::JwkSet;
+
+use crate::core::{Instant, log_serving_stale, may_serve_stale};
+use crate::sso::ClientId;
+use crate::user_management::{
+    AccessTokenVerifier, GetJwks, GetJwksError, VerifiedAccessToken, VerifyAccessTokenError,
+};
+use crate::{WorkOs, WorkOsError, WorkOsResult};
+
+/// The minimum time between refetches of the JWKS caused by access tokens signed with an
+/// unknown key, so that such tokens cannot be used to exhaust the rate limit.
+const MIN_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+
+struct CachedJwks {
+    jwks: JwkSet,
+    fetched_at: Instant,
+}
+
+/// A cache of the JSON Web Key Set (JWKS) used to verify access tokens for a client.
+///
+/// The JWKS is fetched with [`GetJwks`] the first time it is needed and reused until
+/// the configured TTL elapses or the cache is explicitly invalidated.
+///
+/// With [`JwksCache::stale_if_error`], an expired JWKS keeps being used for a bounded
+/// time when refreshing it fails, so that a transient WorkOS outage does not fail token
+/// verification.
+///
+/// Use [`JwksCache::verify`] to verify access tokens against the cached JWKS.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// # use workos_sdk::WorkOsResult;
+/// # use workos_sdk::user_management::*;
+/// use workos_sdk::sso::ClientId;
+/// use workos_sdk::{ApiKey, WorkOs};
+///
+/// # async fn run() -> WorkOsResult<(), GetJwksError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+/// let cache = JwksCache::new(
+///     &workos,
+///     &ClientId::from("client_123456789"),
+///     Duration::from_secs(300),
+/// )
+/// .stale_if_error(Duration::from_secs(3600));
+///
+/// let jwks = cache.get().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct JwksCache {
+    workos: WorkOs,
+    client_id: ClientId,
+    ttl: Duration,
+    max_staleness: Option<Duration>,
+    issuer: Option<String>,
+    entry: Mutex<Option<CachedJwks>>,
+    refetched_at: Mutex<Option<Instant>>,
+}
+
+impl JwksCache {
+    /// Returns a new [`JwksCache`] for the provided client that keeps the JWKS for the
+    /// provided TTL.
+    pub fn new(workos: &WorkOs, client_id: &ClientId, ttl: Duration) -> Self {
+        Self {
+            workos: workos.clone(),
+            client_id: client_id.clone(),
+            ttl,
+            max_staleness: None,
+            issuer: None,
+            entry: Mutex::new(None),
+            refetched_at: Mutex::new(None),
+        }
+    }
+
+    /// Allows an expired JWKS to be used for up to `max_staleness` past its TTL when
+    /// refreshing it fails with an error that is not caused by misconfiguration.
+    pub fn stale_if_error(mut self, max_staleness: Duration) -> Self {
+        self.max_staleness = Some(max_staleness);
+        self
+    }
+
+    /// Only accepts access tokens whose `iss` claim is the provided issuer, such as a custom
+    /// authentication domain, instead of the User Management issuer of the client ID.
+    pub fn issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = Some(issuer.into());
+        self
+    }
+
+    /// Returns the client ID whose JWKS is cached.
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    /// Returns the JWKS, fetching it if it is not cached or has expired.
+    pub async fn get(&self) -> WorkOsResult<JwkSet, GetJwksError> {
+        if let Some(cached) = self.lock().as_ref()
+            && cached.fetched_at.elapsed() < self.ttl
+        {
+            return Ok(cached.jwks.clone());
+        }
+
+        match self.fetch().await {
+            Ok(jwks) => Ok(jwks),
+            Err(err) => {
+                let entry = self.lock();
+                match entry.as_ref() {
+                    Some(cached)
+                        if may_serve_stale(
+                            &err,
+                            cached.fetched_at,
+                            self.ttl,
+                            self.max_staleness,
+                        ) =>
+                    {
+                        log_serving_stale("jwks_cache", cached.fetched_at.elapsed(), &err);
+                        Ok(cached.jwks.clone())
+                    }
+                    _ => Err(err),
+                }
+            }
+        }
+    }
+
+    /// Fetches the JWKS, replacing the cached one only if the fetch succeeds.
+    async fn fetch(&self) -> WorkOsResult<JwkSet, GetJwksError> {
+        let jwks = self
+            .workos
+            .user_management()
+            .get_jwks(&self.client_id)
+            .await?;
+
+        *self.lock() = Some(CachedJwks {
+            jwks: jwks.clone(),
+            fetched_at: Instant::now(),
+        });
+
+        Ok(jwks)
+    }
+
+    /// Removes the cached JWKS, so that it is fetched again on next use.
+    pub fn invalidate(&self) {
+        *self.lock() = None;
+    }
+
+    /// Verifies the access token against the cached JWKS, accepting tokens issued for
+    /// the cached client ID.
+    ///
+    /// When the token is signed with a key that is not in the cached JWKS, e.g. because
+    /// the signing keys were rotated, the JWKS is fetched again before the token is
+    /// rejected. This happens at most once a minute, so that tokens signed with an unknown
+    /// key cannot be used to exhaust the rate limit. The cached JWKS is kept if this fetch
+    /// fails, so that such tokens cannot evict the keys used to verify valid tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::sso::ClientId;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), VerifyAccessTokenError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    /// let cache = JwksCache::new(
+    ///     &workos,
+    ///     &ClientId::from("client_123456789"),
+    ///     Duration::from_secs(300),
+    /// );
+    ///
+    /// let token = cache.verify("eyJhbGciOiJSUzI1NiJ9...").await?;
+    ///
+    /// println!("session {} for user {}", token.claims.sid, token.claims.sub);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify(
+        &self,
+        access_token: &str,
+    ) -> WorkOsResult<VerifiedAccessToken, VerifyAccessTokenError> {
+        let jwks = self.get().await.map_err(|err| {
+            err.map_operation(|GetJwksError::ClientNotFound { client_id }| {
+                VerifyAccessTokenError::ClientNotFound { client_id }
+            })
+        })?;
+        let result = self.verify_with_jwks(jwks, access_token);
+
+        if let Err(WorkOsError::Operation(VerifyAccessTokenError::KeyNotFound(_))) = result
+            && self.may_refetch()
+            && let Ok(jwks) = self.fetch().await
+        {
+            return self.verify_with_jwks(jwks, access_token);
+        }
+
+        result
+    }
+
+    fn verify_with_jwks(
+        &self,
+        jwks: JwkSet,
+        access_token: &str,
+    ) -> WorkOsResult<VerifiedAccessToken, VerifyAccessTokenError> {
+        let mut verifier = AccessTokenVerifier::new(jwks, [self.client_id.clone()])
+            .base_url(self.workos.base_url());
+        if let Some(issuer) = &self.issuer {
+            verifier = verifier.issuer(issuer);
+        }
+
+        verifier.verify(access_token).map_err(WorkOsError::from)
+    }
+
+    /// Returns whether the JWKS may be refetched for a token signed with an unknown key,
+    /// recording the refetch if so.
+    fn may_refetch(&self) -> bool {
+        let mut refetched_at = self
+            .refetched_at
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if refetched_at.is_some_and(|at| at.elapsed() < MIN_REFETCH_INTERVAL) {
+            return false;
+        }
+
+        *refetched_at = Some(Instant::now());
+        true
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<CachedJwks>> {
+        self.entry
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+    use tokio;
+
+    use crate::user_management::access_token_verifier::test::{
+        issued_by, jwks, sign, sign_with_key_id,
+    };
+    use crate::{ApiKey, WorkOsError};
+
+    use super::*;
+
+    fn jwks_body() -> String {
+        json!({
+            "keys": [
+                {
+                    "kty": "RSA",
+                    "kid": "key_01HXYZ",
+                    "alg": "RS256",
+                    "use": "sig",
+                    "n": "sXchDaQebHnPiGvyDOAT4saGEUetSyo9MKLOoWFsueri23bOdgWp4Dy1WlUzewbgBHod5pcM9H95GQRV3JDXboIRROSBigeC5yjU1hGzHHyXss8UDprecbAYxknTcQkhslANGRUZmdTOQ5qTRsLAt6BTYuyvVRdhS8exSZEy_c4gs_7svlJJQ4H9_NxsiIoLwAEk7-Q3UXERGYw_75IDrGA84-lA_-Ct4eTlXHBIY2EaV7t7LjJaynVJCpkv4LKjTTAumiGUIuQhrNhZLuF_RJLqHpM2kgWFLU7-VTdL1VbC2tejvcI2BlMkEpk1BzBZI0KQB0GaDWFLN-aEAw3vRw",
+                    "e": "AQAB"
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn it_reuses_the_jwks_until_the_ttl_elapses() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(jwks_body())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = JwksCache::new(
+            &workos,
+            &ClientId::from("client_123456789"),
+            Duration::from_secs(60),
+        );
+
+        let first = cache.get().await.unwrap();
+        let second = cache.get().await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.keys.len(), 1);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_serves_a_stale_jwks_when_the_refresh_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let cache = JwksCache::new(&workos, &ClientId::from("client_123456789"), Duration::ZERO)
+            .stale_if_error(Duration::from_secs(60));
+
+        let succeeding = server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(jwks_body())
+            .create_async()
+            .await;
+
+        let fresh = cache.get().await.unwrap();
+        succeeding.remove_async().await;
+
+        let failing = server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let stale = cache.get().await.unwrap();
+
+        assert_eq!(stale, fresh);
+        failing.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_serve_a_stale_jwks_when_the_client_is_not_found() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let cache = JwksCache::new(&workos, &ClientId::from("client_123456789"), Duration::ZERO)
+            .stale_if_error(Duration::from_secs(60));
+
+        let succeeding = server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(jwks_body())
+            .create_async()
+            .await;
+
+        cache.get().await.unwrap();
+        succeeding.remove_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        assert_matches!(
+            cache.get().await,
+            Err(WorkOsError::Operation(GetJwksError::ClientNotFound { .. }))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_verifies_access_tokens_against_the_cached_jwks() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = JwksCache::new(
+            &workos,
+            &ClientId::from("client_456"),
+            Duration::from_secs(60),
+        );
+
+        for _ in 0..2 {
+            let token = cache
+                .verify(&sign(issued_by(&server, json!({}))))
+                .await
+                .unwrap();
+            assert_eq!(token.audience, ClientId::from("client_456"));
+        }
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_checks_the_issuer_of_access_tokens() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .create_async()
+            .await;
+
+        let cache = JwksCache::new(
+            &workos,
+            &ClientId::from("client_456"),
+            Duration::from_secs(60),
+        );
+        let custom_domain = sign(issued_by(
+            &server,
+            json!({ "iss": "https://auth.example.com", "aud": "client_456" }),
+        ));
+
+        assert_matches!(
+            cache.verify(&custom_domain).await,
+            Err(WorkOsError::Operation(
+                VerifyAccessTokenError::InvalidToken(_)
+            ))
+        );
+
+        let cache = cache.issuer("https://auth.example.com");
+
+        assert!(cache.verify(&custom_domain).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_refetches_the_jwks_for_tokens_signed_with_a_rotated_key() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let cache = JwksCache::new(
+            &workos,
+            &ClientId::from("client_456"),
+            Duration::from_secs(60),
+        );
+
+        let outdated = server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(jwks_body())
+            .create_async()
+            .await;
+
+        cache.get().await.unwrap();
+        outdated.remove_async().await;
+
+        let rotated = server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let token = cache
+            .verify(&sign(issued_by(&server, json!({}))))
+            .await
+            .unwrap();
+
+        assert_eq!(token.audience, ClientId::from("client_456"));
+        rotated.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_cached_jwks_when_refetching_for_an_unknown_key_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let cache = JwksCache::new(
+            &workos,
+            &ClientId::from("client_456"),
+            Duration::from_secs(60),
+        );
+
+        let succeeding = server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .create_async()
+            .await;
+
+        cache.get().await.unwrap();
+        succeeding.remove_async().await;
+
+        let failing = server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+
+        assert_matches!(
+            cache
+                .verify(&sign_with_key_id(
+                    issued_by(&server, json!({})),
+                    "key_01HXUNKNOWN"
+                ))
+                .await,
+            Err(WorkOsError::Operation(VerifyAccessTokenError::KeyNotFound(
+                _
+            )))
+        );
+
+        let token = cache
+            .verify(&sign(issued_by(&server, json!({}))))
+            .await
+            .unwrap();
+
+        assert_eq!(token.audience, ClientId::from("client_456"));
+        failing.assert_async().await;
+    }
+}
@@ -1,53 +1,107 @@
 mod authenticate_with_code;
 mod authenticate_with_email_verification;
 mod authenticate_with_magic_auth;
+mod authenticate_with_organization_selection;
 mod authenticate_with_password;
 mod authenticate_with_refresh_token;
+mod authenticate_with_totp;
+mod batch_create_organization_memberships;
 mod create_magic_auth;
 mod create_organization_membership;
 mod create_password_reset;
 mod create_user;
 mod deactivate_organization_membership;
+mod delete_auth_factor;
+mod delete_organization_membership;
 mod delete_user;
 mod enroll_auth_factor;
+mod find_invitation_by_token;
 mod get_authorization_url;
 mod get_email_verification;
+mod get_invitation;
+#[cfg(feature = "jwt")]
 mod get_jwks;
 mod get_jwks_url;
 mod get_logout_url;
 mod get_magic_auth;
+mod get_organization_membership;
 mod get_password_reset;
+mod get_session;
 mod get_user;
+mod get_user_by_external_id;
 mod get_user_identities;
+mod get_user_url;
+mod import_users;
+mod list_auth_factors;
+mod list_invitations;
 mod list_organization_memberships;
+mod list_sessions;
 mod list_users;
+mod reactivate_organization_membership;
+mod resend_invitation;
 mod reset_password;
+mod revoke_all_sessions_for_user;
+mod revoke_invitation;
+mod revoke_session;
+mod send_invitation;
+mod send_verification_email;
 mod update_external_id;
+mod update_organization_membership;
 mod update_user;
+#[cfg(feature = "jwt")]
+mod verify_access_token;
+mod verify_email;
 
 pub use authenticate_with_code::*;
 pub use authenticate_with_email_verification::*;
 pub use authenticate_with_magic_auth::*;
+pub use authenticate_with_organization_selection::*;
 pub use authenticate_with_password::*;
 pub use authenticate_with_refresh_token::*;
+pub use authenticate_with_totp::*;
+pub use batch_create_organization_memberships::*;
 pub use create_magic_auth::*;
 pub use create_organization_membership::*;
 pub use create_password_reset::*;
 pub use create_user::*;
 pub use deactivate_organization_membership::*;
+pub use delete_auth_factor::*;
+pub use delete_organization_membership::*;
 pub use delete_user::*;
 pub use enroll_auth_factor::*;
+pub use find_invitation_by_token::*;
 pub use get_authorization_url::*;
 pub use get_email_verification::*;
+pub use get_invitation::*;
+#[cfg(feature = "jwt")]
 pub use get_jwks::*;
 pub use get_jwks_url::*;
 pub use get_logout_url::*;
 pub use get_magic_auth::*;
+pub use get_organization_membership::*;
 pub use get_password_reset::*;
+pub use get_session::*;
 pub use get_user::*;
+pub use get_user_by_external_id::*;
 pub use get_user_identities::*;
+pub use get_user_url::*;
+pub use import_users::*;
+pub use list_auth_factors::*;
+pub use list_invitations::*;
 pub use list_organization_memberships::*;
+pub use list_sessions::*;
 pub use list_users::*;
+pub use reactivate_organization_membership::*;
+pub use resend_invitation::*;
 pub use reset_password::*;
+pub use revoke_all_sessions_for_user::*;
+pub use revoke_invitation::*;
+pub use revoke_session::*;
+pub use send_invitation::*;
+pub use send_verification_email::*;
 pub use update_external_id::*;
+pub use update_organization_membership::*;
 pub use update_user::*;
+#[cfg(feature = "jwt")]
+pub use verify_access_token::*;
+pub use verify_email::*;
@@ -6,8 +6,9 @@ use serde::Serialize;
 use crate::sso::{AuthorizationCode, ClientId};
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, UserManagement,
+    log_authentication_outcome,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, ResponseExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithCode`].
 #[derive(Debug, Serialize)]
@@ -44,7 +45,8 @@ struct AuthenticateWithCodeBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with code](https://workos.com/docs/reference/user-management/authentication/code)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithCode {
     /// Authenticates a user using AuthKit, OAuth or an organization's SSO connection.
     ///
@@ -83,7 +85,8 @@ pub trait AuthenticateWithCode {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithCode for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn authenticate_with_code(
@@ -101,16 +104,23 @@ impl AuthenticateWithCode for UserManagement<'_> {
             params,
         };
 
-        let authenticate_with_code_response = self
-            .workos
-            .send(self.workos.client().post(url).json(&body))
-            .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
-
-        Ok(authenticate_with_code_response)
+        let authenticate_with_code_response = async {
+            let response = self
+                .workos
+                .send(self.workos.client().post(url).json(&body))
+                .await?
+                .handle_authenticate_error()
+                .await?
+                .decode_json::<AuthenticationResponse>()
+                .await?;
+
+            Ok(response)
+        }
+        .await;
+
+        log_authentication_outcome("authorization_code", &authenticate_with_code_response);
+
+        authenticate_with_code_response
     }
 }
 
@@ -134,7 +144,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -207,7 +218,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -244,7 +256,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -281,7 +294,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
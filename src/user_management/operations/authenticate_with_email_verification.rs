@@ -6,9 +6,9 @@ use serde::Serialize;
 use crate::sso::ClientId;
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, EmailVerificationCode, HandleAuthenticateError,
-    PendingAuthenticationToken, UserManagement,
+    PendingAuthenticationToken, UserManagement, log_authentication_outcome,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, ResponseExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithEmailVerification`].
 #[derive(Debug, Serialize)]
@@ -42,7 +42,8 @@ struct AuthenticateWithEmailVerificationBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with an email verification code](https://workos.com/docs/reference/user-management/authentication/email-verification)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithEmailVerification {
     /// Authenticates a user with an unverified email and verifies their email address.
     ///
@@ -80,7 +81,8 @@ pub trait AuthenticateWithEmailVerification {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithEmailVerification for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn authenticate_with_email_verification(
@@ -98,16 +100,26 @@ impl AuthenticateWithEmailVerification for UserManagement<'_> {
             params,
         };
 
-        let authenticate_with_email_verification_response = self
-            .workos
-            .send(self.workos.client().post(url).json(&body))
-            .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
-
-        Ok(authenticate_with_email_verification_response)
+        let authenticate_with_email_verification_response = async {
+            let response = self
+                .workos
+                .send(self.workos.client().post(url).json(&body))
+                .await?
+                .handle_authenticate_error()
+                .await?
+                .decode_json::<AuthenticationResponse>()
+                .await?;
+
+            Ok(response)
+        }
+        .await;
+
+        log_authentication_outcome(
+            "email_verification",
+            &authenticate_with_email_verification_response,
+        );
+
+        authenticate_with_email_verification_response
     }
 }
 
@@ -131,7 +143,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -202,7 +215,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -240,7 +254,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -278,7 +293,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
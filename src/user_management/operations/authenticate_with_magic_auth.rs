@@ -6,9 +6,9 @@ use serde::Serialize;
 use crate::sso::ClientId;
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, MagicAuthCode,
-    UserManagement,
+    UserManagement, log_authentication_outcome,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, Email, ResponseExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithMagicAuth`].
 #[derive(Debug, Serialize)]
@@ -20,7 +20,7 @@ pub struct AuthenticateWithMagicAuthParams<'a> {
     pub code: &'a MagicAuthCode,
 
     /// The email address of the user.
-    pub email: &'a str,
+    pub email: &'a Email,
 
     /// The token of an invitation.
     pub invitation_token: Option<&'a str>,
@@ -45,7 +45,8 @@ struct AuthenticateWithMagicAuthBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with Magic Auth](https://workos.com/docs/reference/user-management/authentication/magic-auth)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithMagicAuth {
     /// Authenticates a user by verifying the Magic Auth code sent to the user's email.
     ///
@@ -69,7 +70,7 @@ pub trait AuthenticateWithMagicAuth {
     ///     .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
     ///         client_id: &ClientId::from("client_123456789"),
     ///         code: &MagicAuthCode::from("123456"),
-    ///         email: "marcelina.davis@example.com",
+    ///         email: &"marcelina.davis@example.com".parse().unwrap(),
     ///         invitation_token: None,
     ///         ip_address: Some(&IpAddr::from_str("192.0.2.1")?),
     ///         user_agent: Some("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/108.0.0.0 Safari/537.36"),
@@ -84,7 +85,8 @@ pub trait AuthenticateWithMagicAuth {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithMagicAuth for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn authenticate_with_magic_auth(
@@ -102,16 +104,23 @@ impl AuthenticateWithMagicAuth for UserManagement<'_> {
             params,
         };
 
-        let authenticate_with_magic_auth_response = self
-            .workos
-            .send(self.workos.client().post(url).json(&body))
-            .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
-
-        Ok(authenticate_with_magic_auth_response)
+        let authenticate_with_magic_auth_response = async {
+            let response = self
+                .workos
+                .send(self.workos.client().post(url).json(&body))
+                .await?
+                .handle_authenticate_error()
+                .await?
+                .decode_json::<AuthenticationResponse>()
+                .await?;
+
+            Ok(response)
+        }
+        .await;
+
+        log_authentication_outcome("magic_auth", &authenticate_with_magic_auth_response);
+
+        authenticate_with_magic_auth_response
     }
 }
 
@@ -135,7 +144,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -176,7 +186,7 @@ mod test {
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
                 client_id: &ClientId::from("client_123456789"),
                 code: &MagicAuthCode::from("123456"),
-                email: "marcelina.davis@example.com",
+                email: &"marcelina.davis@example.com".parse().unwrap(),
                 invitation_token: None,
                 ip_address: None,
                 user_agent: None,
@@ -205,7 +215,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -225,7 +236,7 @@ mod test {
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
                 client_id: &ClientId::from("client_123456789"),
                 code: &MagicAuthCode::from("123456"),
-                email: "marcelina.davis@example.com",
+                email: &"marcelina.davis@example.com".parse().unwrap(),
                 invitation_token: None,
                 ip_address: None,
                 user_agent: None,
@@ -242,7 +253,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -262,7 +274,7 @@ mod test {
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
                 client_id: &ClientId::from("client_123456789"),
                 code: &MagicAuthCode::from("123456"),
-                email: "marcelina.davis@example.com",
+                email: &"marcelina.davis@example.com".parse().unwrap(),
                 invitation_token: None,
                 ip_address: None,
                 user_agent: None,
@@ -279,7 +291,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -299,7 +312,7 @@ mod test {
             .authenticate_with_magic_auth(&AuthenticateWithMagicAuthParams {
                 client_id: &ClientId::from("client_123456789"),
                 code: &MagicAuthCode::from("123456"),
-                email: "marcelina.davis@example.com",
+                email: &"marcelina.davis@example.com".parse().unwrap(),
                 invitation_token: None,
                 ip_address: None,
                 user_agent: None,
@@ -6,8 +6,9 @@ use serde::Serialize;
 use crate::sso::ClientId;
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, UserManagement,
+    log_authentication_outcome,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, Email, ResponseExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithPassword`].
 #[derive(Debug, Serialize)]
@@ -16,7 +17,7 @@ pub struct AuthenticateWithPasswordParams<'a> {
     pub client_id: &'a ClientId,
 
     /// The email address of the user.
-    pub email: &'a str,
+    pub email: &'a Email,
 
     /// The password of the user.
     pub password: &'a str,
@@ -44,7 +45,8 @@ struct AuthenticateWithPasswordBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with password](https://workos.com/docs/reference/user-management/authentication/password)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithPassword {
     /// Authenticates a user with email and password.
     ///
@@ -67,7 +69,7 @@ pub trait AuthenticateWithPassword {
     ///     .user_management()
     ///     .authenticate_with_password(&AuthenticateWithPasswordParams {
     ///         client_id: &ClientId::from("client_123456789"),
-    ///         email: "marcelina@example.com",
+    ///         email: &"marcelina@example.com".parse().unwrap(),
     ///         password: "i8uv6g34kd490s",
     ///         invitation_token: None,
     ///         ip_address: Some(&IpAddr::from_str("192.0.2.1")?),
@@ -83,7 +85,8 @@ pub trait AuthenticateWithPassword {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithPassword for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn authenticate_with_password(
@@ -101,16 +104,23 @@ impl AuthenticateWithPassword for UserManagement<'_> {
             params,
         };
 
-        let authenticate_with_password_response = self
-            .workos
-            .send(self.workos.client().post(url).json(&body))
-            .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
-
-        Ok(authenticate_with_password_response)
+        let authenticate_with_password_response = async {
+            let response = self
+                .workos
+                .send(self.workos.client().post(url).json(&body))
+                .await?
+                .handle_authenticate_error()
+                .await?
+                .decode_json::<AuthenticationResponse>()
+                .await?;
+
+            Ok(response)
+        }
+        .await;
+
+        log_authentication_outcome("password", &authenticate_with_password_response);
+
+        authenticate_with_password_response
     }
 }
 
@@ -134,7 +144,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -174,7 +185,7 @@ mod test {
             .user_management()
             .authenticate_with_password(&AuthenticateWithPasswordParams {
                 client_id: &ClientId::from("client_123456789"),
-                email: "marcelina@example.com",
+                email: &"marcelina@example.com".parse().unwrap(),
                 password: "i8uv6g34kd490s",
                 invitation_token: None,
                 ip_address: None,
@@ -204,7 +215,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -223,7 +235,7 @@ mod test {
             .user_management()
             .authenticate_with_password(&AuthenticateWithPasswordParams {
                 client_id: &ClientId::from("client_123456789"),
-                email: "marcelina@example.com",
+                email: &"marcelina@example.com".parse().unwrap(),
                 password: "i8uv6g34kd490s",
                 invitation_token: None,
                 ip_address: None,
@@ -241,7 +253,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -260,7 +273,7 @@ mod test {
             .user_management()
             .authenticate_with_password(&AuthenticateWithPasswordParams {
                 client_id: &ClientId::from("client_123456789"),
-                email: "marcelina@example.com",
+                email: &"marcelina@example.com".parse().unwrap(),
                 password: "i8uv6g34kd490s",
                 invitation_token: None,
                 ip_address: None,
@@ -270,4 +283,155 @@ mod test {
 
         assert_matches!(result, Err(WorkOsError::Unauthorized))
     }
+
+    #[cfg(feature = "tracing")]
+    mod outcome_events {
+        use std::collections::BTreeMap;
+        use std::fmt;
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::{Event, Metadata, Subscriber, span};
+
+        use super::*;
+
+        type Fields = BTreeMap<String, String>;
+
+        /// Captures the fields of authentication outcome events.
+        #[derive(Clone, Default)]
+        struct OutcomeEvents(Arc<Mutex<Vec<Fields>>>);
+
+        impl OutcomeEvents {
+            fn take(&self) -> Vec<Fields> {
+                std::mem::take(&mut self.0.lock().unwrap())
+            }
+        }
+
+        impl Subscriber for OutcomeEvents {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+
+            fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+            fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                if event.metadata().target() != "workos_sdk::authentication" {
+                    return;
+                }
+
+                let mut fields = Fields::new();
+                event.record(&mut FieldsVisitor(&mut fields));
+                self.0.lock().unwrap().push(fields);
+            }
+
+            fn enter(&self, _span: &span::Id) {}
+
+            fn exit(&self, _span: &span::Id) {}
+        }
+
+        struct FieldsVisitor<'a>(&'a mut Fields);
+
+        impl Visit for FieldsVisitor<'_> {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.insert(field.name().to_string(), value.to_string());
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0
+                    .insert(field.name().to_string(), format!("{:?}", value));
+            }
+        }
+
+        async fn authenticate(status: usize, body: serde_json::Value) -> Vec<Fields> {
+            let mut server = mockito::Server::new_async().await;
+
+            let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+                .base_url(&server.url())
+                .unwrap()
+                .build()
+                .unwrap();
+
+            server
+                .mock("POST", "/user_management/authenticate")
+                .with_status(status)
+                .with_body(body.to_string())
+                .create_async()
+                .await;
+
+            let events = OutcomeEvents::default();
+            let _guard = tracing::subscriber::set_default(events.clone());
+
+            let _ = workos
+                .user_management()
+                .authenticate_with_password(&AuthenticateWithPasswordParams {
+                    client_id: &ClientId::from("client_123456789"),
+                    email: &"marcelina@example.com".parse().unwrap(),
+                    password: "i8uv6g34kd490s",
+                    invitation_token: None,
+                    ip_address: None,
+                    user_agent: None,
+                })
+                .await;
+
+            events.take()
+        }
+
+        #[tokio::test]
+        async fn it_records_a_successful_authentication() {
+            let events = authenticate(
+                200,
+                json!({
+                    "user": {
+                        "object": "user",
+                        "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                        "email": "marcelina.davis@example.com",
+                        "first_name": "Marcelina",
+                        "last_name": "Davis",
+                        "email_verified": true,
+                        "profile_picture_url": "https://workoscdn.com/images/v1/123abc",
+                        "metadata": {},
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+                    "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                    "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                    "authentication_method": "Password",
+                }),
+            )
+            .await;
+
+            assert_eq!(events.len(), 1);
+            let event = &events[0];
+            assert_eq!(event["method"], "password");
+            assert_eq!(event["outcome"], "success");
+            assert_eq!(event["user_id"], "user_01E4ZCR3C56J083X43JQXF3JK5");
+            assert_eq!(event["organization_id"], "org_01H945H0YD4F97JN9MATX7BYAG");
+        }
+
+        #[tokio::test]
+        async fn it_records_a_failed_authentication_with_its_code() {
+            let events = authenticate(
+                400,
+                json!({
+                    "code": "invalid_credentials",
+                    "message": "Invalid credentials."
+                }),
+            )
+            .await;
+
+            assert_eq!(events.len(), 1);
+            let event = &events[0];
+            assert_eq!(event["method"], "password");
+            assert_eq!(event["outcome"], "failure");
+            assert_eq!(event["code"], "invalid_credentials");
+            assert!(!event.contains_key("user_id"));
+        }
+    }
 }
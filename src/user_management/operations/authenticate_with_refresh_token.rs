@@ -7,9 +7,9 @@ use crate::organizations::OrganizationId;
 use crate::sso::ClientId;
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, RefreshToken,
-    UserManagement,
+    UserManagement, log_authentication_outcome,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, ResponseExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithRefreshToken`].
 #[derive(Debug, Serialize)]
@@ -43,7 +43,8 @@ struct AuthenticateWithRefreshTokenBody<'a> {
 }
 
 /// [WorkOS Docs: Authenticate with refresh token](https://workos.com/docs/reference/user-management/authentication/refresh-token)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait AuthenticateWithRefreshToken {
     /// Use this endpoint to exchange a refresh token for a new access token.
     ///
@@ -81,7 +82,8 @@ pub trait AuthenticateWithRefreshToken {
     ) -> WorkOsResult<AuthenticationResponse, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl AuthenticateWithRefreshToken for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn authenticate_with_refresh_token(
@@ -99,16 +101,23 @@ impl AuthenticateWithRefreshToken for UserManagement<'_> {
             params,
         };
 
-        let authenticate_with_refresh_token_response = self
-            .workos
-            .send(self.workos.client().post(url).json(&body))
-            .await?
-            .handle_authenticate_error()
-            .await?
-            .json::<AuthenticationResponse>()
-            .await?;
-
-        Ok(authenticate_with_refresh_token_response)
+        let authenticate_with_refresh_token_response = async {
+            let response = self
+                .workos
+                .send(self.workos.client().post(url).json(&body))
+                .await?
+                .handle_authenticate_error()
+                .await?
+                .decode_json::<AuthenticationResponse>()
+                .await?;
+
+            Ok(response)
+        }
+        .await;
+
+        log_authentication_outcome("refresh_token", &authenticate_with_refresh_token_response);
+
+        authenticate_with_refresh_token_response
     }
 }
 
@@ -132,7 +141,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -197,6 +207,66 @@ mod test {
         )
     }
 
+    #[tokio::test]
+    async fn it_sends_the_organization_id_to_switch_organizations() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::PartialJson(json!({
+                "grant_type": "refresh_token",
+                "refresh_token": "abc123",
+                "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+            })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "user": {
+                        "object": "user",
+                        "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                        "email": "marcelina.davis@example.com",
+                        "first_name": "Marcelina",
+                        "last_name": "Davis",
+                        "email_verified": true,
+                        "profile_picture_url": null,
+                        "metadata": {},
+                        "created_at": "2021-06-25T19:07:33.155Z",
+                        "updated_at": "2021-06-25T19:07:33.155Z"
+                    },
+                    "organization_id": "org_01H945H0YD4F97JN9MATX7BYAG",
+                    "access_token": "eyJhb.nNzb19vaWRjX2tleV9.lc5Uk4yWVk5In0",
+                    "refresh_token": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                    "authentication_method": "SSO"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = workos
+            .user_management()
+            .authenticate_with_refresh_token(&AuthenticateWithRefreshTokenParams {
+                client_id: &ClientId::from("client_123456789"),
+                refresh_token: &RefreshToken::from("abc123"),
+                organization_id: Some(&OrganizationId::from("org_01H945H0YD4F97JN9MATX7BYAG")),
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.organization_id,
+            Some(OrganizationId::from("org_01H945H0YD4F97JN9MATX7BYAG"))
+        )
+    }
+
     #[tokio::test]
     async fn it_returns_an_unauthorized_error_with_an_invalid_client() {
         let mut server = mockito::Server::new_async().await;
@@ -204,7 +274,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -240,7 +311,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/authenticate")
@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+
+use crate::core::run_batch;
+use crate::user_management::{
+    CreateOrganizationMembership, CreateOrganizationMembershipError,
+    CreateOrganizationMembershipParams, OrganizationMembership, UserManagement,
+};
+use crate::{BatchFailure, BatchReport};
+
+/// The parameters for [`BatchCreateOrganizationMemberships`].
+#[derive(Debug)]
+pub struct BatchCreateOrganizationMembershipsParams<'a> {
+    /// The organization memberships to create.
+    pub memberships: &'a [CreateOrganizationMembershipParams<'a>],
+
+    /// The maximum number of organization memberships to create concurrently.
+    ///
+    /// Defaults to 4.
+    pub concurrency: Option<usize>,
+}
+
+/// An organization membership that could not be created, with its index in
+/// [`BatchCreateOrganizationMembershipsParams::memberships`].
+pub type BatchOrganizationMembershipFailure = BatchFailure<CreateOrganizationMembershipError>;
+
+/// The outcome of [`BatchCreateOrganizationMemberships`].
+pub type BatchOrganizationMembershipReport =
+    BatchReport<OrganizationMembership, CreateOrganizationMembershipError>;
+
+/// [WorkOS Docs: Create an organization membership](https://workos.com/docs/reference/user-management/organization-membership/create)
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait BatchCreateOrganizationMemberships {
+    /// Creates many organization memberships, with a bounded number of requests in flight.
+    ///
+    /// Requests that are rate limited are retried after the delay requested by the API.
+    /// Every membership is attempted, and the outcome of each is reported in the returned
+    /// [`BatchOrganizationMembershipReport`]: memberships that already exist are reported as
+    /// conflicts, and any other error is reported as a failure.
+    ///
+    /// [WorkOS Docs: Create an organization membership](https://workos.com/docs/reference/user-management/organization-membership/create)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::organizations::OrganizationId;
+    /// # use workos_sdk::roles::RoleSlug;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let organization_id = OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5");
+    /// let admin = RoleSlug::from("admin");
+    /// let users = [
+    ///     UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E"),
+    ///     UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9F"),
+    /// ];
+    ///
+    /// let memberships = users
+    ///     .iter()
+    ///     .map(|user_id| CreateOrganizationMembershipParams {
+    ///         user_id,
+    ///         organization_id: &organization_id,
+    ///         role_slug: Some(&admin),
+    ///         idempotency_key: None,
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let report = workos
+    ///     .user_management()
+    ///     .batch_create_organization_memberships(&BatchCreateOrganizationMembershipsParams {
+    ///         memberships: &memberships,
+    ///         concurrency: Some(8),
+    ///     })
+    ///     .await;
+    ///
+    /// for failure in &report.failures {
+    ///     eprintln!("membership {} failed: {}", failure.index, failure.error);
+    /// }
+    /// # }
+    /// ```
+    async fn batch_create_organization_memberships(
+        &self,
+        params: &BatchCreateOrganizationMembershipsParams<'_>,
+    ) -> BatchOrganizationMembershipReport;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl BatchCreateOrganizationMemberships for UserManagement<'_> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, params)))]
+    async fn batch_create_organization_memberships(
+        &self,
+        params: &BatchCreateOrganizationMembershipsParams<'_>,
+    ) -> BatchOrganizationMembershipReport {
+        let operations = params
+            .memberships
+            .iter()
+            .map(|membership| move || self.create_organization_membership(membership));
+
+        run_batch(operations, params.concurrency).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::organizations::OrganizationId;
+    use crate::user_management::{OrganizationMembershipId, UserId};
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_reports_created_memberships_conflicts_and_failures() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/user_management/organization_memberships")
+            .match_body(Matcher::PartialJson(
+                json!({ "user_id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E" }),
+            ))
+            .with_status(201)
+            .with_body(
+                json!({
+                    "object": "organization_membership",
+                    "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                    "user_id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E",
+                    "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+                    "role": {
+                        "slug": "member"
+                    },
+                    "status": "active",
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/user_management/organization_memberships")
+            .match_body(Matcher::PartialJson(
+                json!({ "user_id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9F" }),
+            ))
+            .with_status(400)
+            .with_body(
+                json!({
+                    "code": "organization_membership_already_exists",
+                    "message": "An organization membership already exists."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/user_management/organization_memberships")
+            .match_body(Matcher::PartialJson(
+                json!({ "user_id": "user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9G" }),
+            ))
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let organization_id = OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5");
+        let users = [
+            UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E"),
+            UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9F"),
+            UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9G"),
+        ];
+        let memberships = users
+            .iter()
+            .map(|user_id| CreateOrganizationMembershipParams {
+                user_id,
+                organization_id: &organization_id,
+                role_slug: None,
+                idempotency_key: None,
+            })
+            .collect::<Vec<_>>();
+
+        let report = workos
+            .user_management()
+            .batch_create_organization_memberships(&BatchCreateOrganizationMembershipsParams {
+                memberships: &memberships,
+                concurrency: Some(2),
+            })
+            .await;
+
+        assert_eq!(report.created.len(), 1);
+        assert_eq!(report.created[0].0, 0);
+        assert_eq!(
+            report.created[0].1.id,
+            OrganizationMembershipId::from("om_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert_eq!(report.conflicts, vec![1]);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].index, 2);
+        assert!(!report.is_success());
+    }
+}
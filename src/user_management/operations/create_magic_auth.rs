@@ -3,13 +3,13 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::user_management::{MagicAuth, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{Email, OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreateMagicAuth`].
 #[derive(Debug, Serialize)]
 pub struct CreateMagicAuthParams<'a> {
     /// The email address of the user.
-    pub email: &'a str,
+    pub email: &'a Email,
 
     /// The token of an invitation.
     pub invitation_token: Option<&'a str>,
@@ -25,8 +25,11 @@ impl From<CreateMagicAuthError> for WorkOsError<CreateMagicAuthError> {
     }
 }
 
+impl OperationError for CreateMagicAuthError {}
+
 /// [WorkOS Docs: Create a Magic Auth code](https://workos.com/docs/reference/user-management/magic-auth/create)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CreateMagicAuth {
     /// Creates a one-time authentication code that can be sent to the user's email address.
     ///
@@ -47,7 +50,7 @@ pub trait CreateMagicAuth {
     /// let magic_auth = workos
     ///     .user_management()
     ///     .create_magic_auth(&CreateMagicAuthParams {
-    ///          email: "marcelina@example.com",
+    ///          email: &"marcelina@example.com".parse().unwrap(),
     ///          invitation_token: None,
     ///     })
     ///     .await?;
@@ -60,7 +63,8 @@ pub trait CreateMagicAuth {
     ) -> WorkOsResult<MagicAuth, CreateMagicAuthError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CreateMagicAuth for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn create_magic_auth(
@@ -80,7 +84,7 @@ impl CreateMagicAuth for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<MagicAuth>()
+            .decode_json::<MagicAuth>()
             .await?;
 
         Ok(user)
@@ -104,7 +108,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/magic_auth")
@@ -128,7 +133,7 @@ mod test {
         let magic_auth = workos
             .user_management()
             .create_magic_auth(&CreateMagicAuthParams {
-                email: "marcelina@example.com",
+                email: &"marcelina@example.com".parse().unwrap(),
                 invitation_token: None,
             })
             .await
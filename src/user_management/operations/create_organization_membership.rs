@@ -2,10 +2,11 @@ use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::core::IdempotencyKeyExt;
 use crate::organizations::OrganizationId;
 use crate::roles::RoleSlug;
 use crate::user_management::{OrganizationMembership, UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{IdempotencyKey, OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreateOrganizationMembership`].
 #[derive(Debug, Serialize)]
@@ -20,6 +21,11 @@ pub struct CreateOrganizationMembershipParams<'a> {
     ///
     /// Defaults to `member`.
     pub role_slug: Option<&'a RoleSlug>,
+
+    /// A key that prevents the organization membership from being created twice when the
+    /// request is retried.
+    #[serde(skip)]
+    pub idempotency_key: Option<&'a IdempotencyKey>,
 }
 
 /// An error returned from [`CreateOrganizationMembership`].
@@ -32,8 +38,11 @@ impl From<CreateOrganizationMembershipError> for WorkOsError<CreateOrganizationM
     }
 }
 
+impl OperationError for CreateOrganizationMembershipError {}
+
 /// [WorkOS Docs: Create an organization membership](https://workos.com/docs/reference/user-management/organization-membership/create)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CreateOrganizationMembership {
     /// Creates a new `active` organization membership for the given organization and user.
     ///
@@ -59,6 +68,7 @@ pub trait CreateOrganizationMembership {
     ///          user_id: &UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E"),
     ///          organization_id: &OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5"),
     ///          role_slug: Some(&RoleSlug::from("admin")),
+    ///          idempotency_key: None,
     ///     })
     ///     .await?;
     /// # Ok(())
@@ -70,7 +80,8 @@ pub trait CreateOrganizationMembership {
     ) -> WorkOsResult<OrganizationMembership, CreateOrganizationMembershipError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CreateOrganizationMembership for UserManagement<'_> {
     async fn create_organization_membership(
         &self,
@@ -87,12 +98,13 @@ impl CreateOrganizationMembership for UserManagement<'_> {
                     .client()
                     .post(url)
                     .bearer_auth(self.workos.key())
+                    .idempotency_key(params.idempotency_key)
                     .json(&params),
             )
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<OrganizationMembership>()
+            .decode_json::<OrganizationMembership>()
             .await?;
 
         Ok(organization_membership)
@@ -116,7 +128,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/organization_memberships")
@@ -146,6 +159,7 @@ mod test {
                 user_id: &UserId::from("user_01E4ZCR3C5A4QZ2Z2JQXGKZJ9E"),
                 organization_id: &OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5"),
                 role_slug: Some(&RoleSlug::from("admin")),
+                idempotency_key: None,
             })
             .await
             .unwrap();
@@ -5,13 +5,13 @@ use thiserror::Error;
 
 use crate::core::response_to_request_error;
 use crate::user_management::{PasswordReset, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{Email, OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreatePasswordReset`].
 #[derive(Debug, Serialize)]
 pub struct CreatePasswordResetParams<'a> {
     /// The email address of the user.
-    pub email: &'a str,
+    pub email: &'a Email,
 }
 
 /// An error returned from [`CreatePasswordReset`].
@@ -35,7 +35,10 @@ impl From<CreatePasswordResetError> for WorkOsError<CreatePasswordResetError> {
     }
 }
 
-#[async_trait]
+impl OperationError for CreatePasswordResetError {}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub(crate) trait HandleCreatePasswordResetError
 where
     Self: Sized,
@@ -45,7 +48,8 @@ where
     ) -> WorkOsResult<Self, CreatePasswordResetError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl HandleCreatePasswordResetError for Response {
     async fn handle_create_password_reset_error(
         self,
@@ -55,7 +59,7 @@ impl HandleCreatePasswordResetError for Response {
         }
 
         if self.status() == StatusCode::NOT_FOUND {
-            let error = self.json::<CreatePasswordResetError>().await?;
+            let error = self.decode_json::<CreatePasswordResetError>().await?;
             return Err(WorkOsError::Operation(error));
         }
 
@@ -64,7 +68,8 @@ impl HandleCreatePasswordResetError for Response {
 }
 
 /// [WorkOS Docs: Create a password reset token](https://workos.com/docs/reference/user-management/password-reset/create)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CreatePasswordReset {
     /// Creates a one-time token that can be used to reset a user's password.
     ///
@@ -85,7 +90,7 @@ pub trait CreatePasswordReset {
     /// let password_reset = workos
     ///     .user_management()
     ///     .create_password_reset(&CreatePasswordResetParams {
-    ///          email: "marcelina@example.com",
+    ///          email: &"marcelina@example.com".parse().unwrap(),
     ///     })
     ///     .await?;
     /// # Ok(())
@@ -97,7 +102,8 @@ pub trait CreatePasswordReset {
     ) -> WorkOsResult<PasswordReset, CreatePasswordResetError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CreatePasswordReset for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn create_password_reset(
@@ -122,7 +128,7 @@ impl CreatePasswordReset for UserManagement<'_> {
             .await?
             .handle_create_password_reset_error()
             .await?
-            .json::<PasswordReset>()
+            .decode_json::<PasswordReset>()
             .await?;
 
         Ok(user)
@@ -146,7 +152,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/password_reset")
@@ -170,7 +177,7 @@ mod test {
         let password_reset = workos
             .user_management()
             .create_password_reset(&CreatePasswordResetParams {
-                email: "marcelina.davis@example.com",
+                email: &"marcelina.davis@example.com".parse().unwrap(),
             })
             .await
             .unwrap();
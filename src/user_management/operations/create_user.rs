@@ -2,14 +2,17 @@ use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::user_management::{PasswordParams, User, UserManagement};
-use crate::{Metadata, ResponseExt, WorkOsError, WorkOsResult};
+use crate::core::IdempotencyKeyExt;
+use crate::user_management::{PasswordParams, User, UserManagement, ValidationError};
+use crate::{
+    Email, IdempotencyKey, Metadata, OperationError, ResponseExt, WorkOsError, WorkOsResult,
+};
 
 /// The parameters for [`CreateUser`].
 #[derive(Debug, Serialize)]
 pub struct CreateUserParams<'a> {
     /// The email address of the user.
-    pub email: &'a str,
+    pub email: &'a Email,
 
     /// The password to set for the user.
     #[serde(flatten)]
@@ -29,11 +32,30 @@ pub struct CreateUserParams<'a> {
 
     /// Object containing metadata key/value pairs associated with the user.
     pub metadata: Option<Metadata>,
+
+    /// A key that prevents the user from being created twice when the request is retried.
+    #[serde(skip)]
+    pub idempotency_key: Option<&'a IdempotencyKey>,
+}
+
+impl CreateUserParams<'_> {
+    /// Validates the password locally.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(password) = self.password {
+            password.validate()?;
+        }
+
+        Ok(())
+    }
 }
 
 /// An error returned from [`CreateUser`].
 #[derive(Debug, Error)]
-pub enum CreateUserError {}
+pub enum CreateUserError {
+    /// The parameters failed local validation.
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+}
 
 impl From<CreateUserError> for WorkOsError<CreateUserError> {
     fn from(err: CreateUserError) -> Self {
@@ -41,8 +63,11 @@ impl From<CreateUserError> for WorkOsError<CreateUserError> {
     }
 }
 
+impl OperationError for CreateUserError {}
+
 /// [WorkOS Docs: Create an User](https://workos.com/docs/reference/user-management/user/create)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CreateUser {
     /// Creates an [`User`].
     ///
@@ -63,7 +88,7 @@ pub trait CreateUser {
     /// let user = workos
     ///     .user_management()
     ///     .create_user(&CreateUserParams {
-    ///          email: "marcelina@example.com",
+    ///          email: &"marcelina@example.com".parse().unwrap(),
     ///          password: Some(&PasswordParams::Password {
     ///              password: "i8uv6g34kd490s",
     ///          }),
@@ -72,6 +97,7 @@ pub trait CreateUser {
     ///          email_verified: Some(false),
     ///          external_id: None,
     ///          metadata: None,
+    ///          idempotency_key: None,
     ///     })
     ///     .await?;
     /// # Ok(())
@@ -83,13 +109,18 @@ pub trait CreateUser {
     ) -> WorkOsResult<User, CreateUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CreateUser for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn create_user(
         &self,
         params: &CreateUserParams<'_>,
     ) -> WorkOsResult<User, CreateUserError> {
+        if self.workos.local_validation() {
+            params.validate().map_err(CreateUserError::from)?;
+        }
+
         let url = self.workos.base_url().join("/user_management/users")?;
         let user = self
             .workos
@@ -98,12 +129,13 @@ impl CreateUser for UserManagement<'_> {
                     .client()
                     .post(url)
                     .bearer_auth(self.workos.key())
+                    .idempotency_key(params.idempotency_key)
                     .json(&params),
             )
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<User>()
+            .decode_json::<User>()
             .await?;
 
         Ok(user)
@@ -112,10 +144,12 @@ impl CreateUser for UserManagement<'_> {
 
 #[cfg(test)]
 mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
     use serde_json::json;
     use tokio;
 
-    use crate::user_management::UserId;
+    use crate::user_management::{PasswordHashType, UserId};
     use crate::{ApiKey, WorkOs};
 
     use super::*;
@@ -127,7 +161,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/users")
@@ -154,7 +189,7 @@ mod test {
         let user = workos
             .user_management()
             .create_user(&CreateUserParams {
-                email: "marcelina@example.com",
+                email: &"marcelina@example.com".parse().unwrap(),
                 password: Some(&PasswordParams::Password {
                     password: "i8uv6g34kd490s",
                 }),
@@ -163,10 +198,114 @@ mod test {
                 email_verified: Some(false),
                 external_id: None,
                 metadata: None,
+                idempotency_key: None,
             })
             .await
             .unwrap();
 
         assert_eq!(user.id, UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"))
     }
+
+    #[tokio::test]
+    async fn it_imports_a_password_hash() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .local_validation(true)
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/user_management/users")
+            .match_body(Matcher::Json(json!({
+                "email": "marcelina@example.com",
+                "password_hash": "$2b$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy",
+                "password_hash_type": "bcrypt",
+                "first_name": null,
+                "last_name": null,
+                "email_verified": true,
+                "external_id": null,
+                "metadata": null
+            })))
+            .with_status(201)
+            .with_body(
+                json!({
+                    "object": "user",
+                    "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "email": "marcelina@example.com",
+                    "first_name": null,
+                    "last_name": null,
+                    "email_verified": true,
+                    "profile_picture_url": null,
+                    "last_sign_in_at": null,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let user = workos
+            .user_management()
+            .create_user(&CreateUserParams {
+                email: &"marcelina@example.com".parse().unwrap(),
+                password: Some(&PasswordParams::PasswordHash {
+                    password_hash: "$2b$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy",
+                    password_hash_type: PasswordHashType::Bcrypt,
+                }),
+                first_name: None,
+                last_name: None,
+                email_verified: Some(true),
+                external_id: None,
+                metadata: None,
+                idempotency_key: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(user.id, UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"))
+    }
+
+    #[tokio::test]
+    async fn it_validates_the_params_locally_when_enabled() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .local_validation(true)
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("POST", "/user_management/users")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .create_user(&CreateUserParams {
+                email: &"marcelina.davis@example.com".parse().unwrap(),
+                password: Some(&PasswordParams::Password { password: "short" }),
+                first_name: None,
+                last_name: None,
+                email_verified: None,
+                external_id: None,
+                metadata: None,
+                idempotency_key: None,
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(CreateUserError::Validation(
+                ValidationError::PasswordTooShort { .. }
+            )))
+        );
+        mock.assert_async().await;
+    }
 }
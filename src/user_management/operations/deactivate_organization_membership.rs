@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::user_management::{OrganizationMembership, OrganizationMembershipId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`DeactivateOrganizationMembership`].
 #[derive(Debug, Error)]
@@ -16,8 +16,11 @@ impl From<DeactivateOrganizationMembershipError>
     }
 }
 
+impl OperationError for DeactivateOrganizationMembershipError {}
+
 /// [WorkOS Docs: Deactivate an organization membership](https://workos.com/docs/reference/user-management/organization-membership/deactivate)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeactivateOrganizationMembership {
     /// Deactivates an [`OrganizationMembership`].
     ///
@@ -46,7 +49,8 @@ pub trait DeactivateOrganizationMembership {
     ) -> WorkOsResult<OrganizationMembership, DeactivateOrganizationMembershipError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeactivateOrganizationMembership for UserManagement<'_> {
     async fn deactivate_organization_membership(
         &self,
@@ -67,7 +71,7 @@ impl DeactivateOrganizationMembership for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<OrganizationMembership>()
+            .decode_json::<OrganizationMembership>()
             .await?;
 
         Ok(organization_membership)
@@ -91,7 +95,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use thiserror::Error;
 
-use crate::user_management::{UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::user_management::{GetUserUrl, UserId, UserManagement};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`DeleteUser`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<DeleteUserError> for WorkOsError<DeleteUserError> {
     }
 }
 
+impl OperationError for DeleteUserError {}
+
 /// [WorkOS Docs: Delete a User](https://workos.com/docs/reference/user-management/user/delete)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait DeleteUser {
     /// Permanently deletes a [`User`](crate::user_management::User).
     ///
@@ -41,14 +44,12 @@ pub trait DeleteUser {
     async fn delete_user(&self, user_id: &UserId) -> WorkOsResult<(), DeleteUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl DeleteUser for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn delete_user(&self, user_id: &UserId) -> WorkOsResult<(), DeleteUserError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/user_management/users/{user_id}"))?;
+        let url = self.get_user_url(user_id)?;
         self.workos
             .send(
                 self.workos
@@ -79,7 +80,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -6,7 +6,7 @@ use thiserror::Error;
 use crate::core::response_to_request_error;
 use crate::mfa::{AuthenticationChallenge, AuthenticationFactor};
 use crate::user_management::{UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`EnrollAuthFactor`].
 #[derive(Debug, Serialize)]
@@ -68,7 +68,10 @@ impl From<EnrollAuthFactorError> for WorkOsError<EnrollAuthFactorError> {
     }
 }
 
-#[async_trait]
+impl OperationError for EnrollAuthFactorError {}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub(crate) trait HandleEnrollAuthFactorError
 where
     Self: Sized,
@@ -76,7 +79,8 @@ where
     async fn handle_enroll_auth_factor_error(self) -> WorkOsResult<Self, EnrollAuthFactorError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl HandleEnrollAuthFactorError for Response {
     async fn handle_enroll_auth_factor_error(self) -> WorkOsResult<Self, EnrollAuthFactorError> {
         if self.status().is_success() {
@@ -84,7 +88,7 @@ impl HandleEnrollAuthFactorError for Response {
         }
 
         if self.status() == StatusCode::BAD_REQUEST {
-            let error = self.json::<EnrollAuthFactorError>().await?;
+            let error = self.decode_json::<EnrollAuthFactorError>().await?;
             return Err(WorkOsError::Operation(error));
         }
 
@@ -93,7 +97,8 @@ impl HandleEnrollAuthFactorError for Response {
 }
 
 /// [WorkOS Docs: Enroll an authentication factor](https://workos.com/docs/reference/user-management/mfa/enroll-auth-factor)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait EnrollAuthFactor {
     /// Enrolls a user in a new authentication factor.
     ///
@@ -131,7 +136,8 @@ pub trait EnrollAuthFactor {
     ) -> WorkOsResult<EnrollAuthFactorResponse, EnrollAuthFactorError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl EnrollAuthFactor for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn enroll_auth_factor(
@@ -156,7 +162,7 @@ impl EnrollAuthFactor for UserManagement<'_> {
             .await?
             .handle_enroll_auth_factor_error()
             .await?
-            .json::<EnrollAuthFactorResponse>()
+            .decode_json::<EnrollAuthFactorResponse>()
             .await?;
 
         Ok(user)
@@ -180,7 +186,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/users/user_01FVYZ5QM8N98T9ME5BCB2BBMJ/auth_factors")
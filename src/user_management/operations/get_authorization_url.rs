@@ -1,15 +1,9 @@
 use url::{ParseError, Url};
 
+use crate::core::AuthorizationUrlBuilder;
 use crate::organizations::OrganizationId;
-use crate::sso::{ClientId, ConnectionId};
-use crate::user_management::{OauthProvider, UserManagement};
-
-/// Code challenge used for the PKCE flow.
-#[derive(Debug)]
-pub enum CodeChallenge<'a> {
-    /// S256 code challenge method.
-    S256(&'a str),
-}
+use crate::sso::{ClientId, CodeChallenge, ConnectionId, OauthProvider};
+use crate::user_management::UserManagement;
 
 /// Which AuthKit screen users should land on upon redirection.
 #[derive(Clone, Copy, Debug)]
@@ -22,12 +16,21 @@ pub enum ScreenHint {
 }
 
 /// An OAuth provider to use for Single Sign-On (SSO) or AuthKit.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub enum Provider {
     /// Sign in with AuthKit.
+    ///
+    /// The [`login_hint`](GetAuthorizationUrlParams::login_hint) and
+    /// [`domain_hint`](GetAuthorizationUrlParams::domain_hint) parameters also pre-fill the
+    /// hosted AuthKit screens.
     AuthKit {
-        /// Specify which AuthKit screen users should land on upon redirection (
+        /// Specify which AuthKit screen users should land on upon redirection.
         screen_hint: Option<ScreenHint>,
+
+        /// The locale in which the hosted AuthKit screens should be rendered, e.g. `fr-FR`.
+        ///
+        /// If omitted, AuthKit detects the locale from the user's browser.
+        locale: Option<String>,
     },
 
     /// Sign in with OAuth.
@@ -123,68 +126,49 @@ impl GetAuthorizationUrl for UserManagement<'_> {
             domain_hint,
         } = params;
 
-        let query = {
-            let client_id = client_id.to_string();
-
-            let connection_selector_param = match connection_selector {
-                ConnectionSelector::Connection(connection_id) => {
-                    ("connection", connection_id.to_string())
-                }
-                ConnectionSelector::Organization(organization_id) => {
-                    ("organization", organization_id.to_string())
-                }
-                ConnectionSelector::Provider(provider) => (
-                    "provider",
-                    match provider {
-                        Provider::AuthKit { .. } => "authkit".to_string(),
-                        Provider::Oauth(provider) => provider.to_string(),
-                    },
-                ),
-            };
-
-            let mut query_params: querystring::QueryParams = vec![
-                ("response_type", "code"),
-                ("client_id", &client_id),
-                ("redirect_uri", redirect_uri),
-                (connection_selector_param.0, &connection_selector_param.1),
-            ];
-
-            if let Some(state) = state {
-                query_params.push(("state", state));
-            }
-            if let Some(code_challenge) = code_challenge {
-                match code_challenge {
-                    CodeChallenge::S256(code_challenge) => {
-                        query_params.push(("code_challenge", code_challenge));
-                        query_params.push(("code_challenge_method", "S256"));
-                    }
-                }
-            }
-            if let Some(login_hint) = login_hint {
-                query_params.push(("login_hint", login_hint));
+        let builder = AuthorizationUrlBuilder::new(
+            self.workos.base_url(),
+            "/user_management/authorize",
+            client_id,
+            redirect_uri,
+        )?;
+
+        let mut builder = match connection_selector {
+            ConnectionSelector::Connection(connection_id) => {
+                builder.param("connection", connection_id)
             }
-            if let Some(domain_hint) = domain_hint {
-                query_params.push(("domain_hint", domain_hint));
+            ConnectionSelector::Organization(organization_id) => {
+                builder.param("organization", organization_id)
             }
-            if let ConnectionSelector::Provider(Provider::AuthKit {
-                screen_hint: Some(screen_hint),
-            }) = connection_selector
-            {
-                query_params.push((
+            ConnectionSelector::Provider(provider) => match provider {
+                Provider::AuthKit { .. } => builder.param("provider", "authkit"),
+                Provider::Oauth(provider) => builder.param("provider", &provider.to_string()),
+            },
+        };
+
+        builder = builder
+            .optional_param("state", *state)
+            .code_challenge(code_challenge.as_ref())
+            .optional_param("login_hint", *login_hint)
+            .optional_param("domain_hint", *domain_hint);
+
+        if let ConnectionSelector::Provider(Provider::AuthKit {
+            screen_hint,
+            locale,
+        }) = connection_selector
+        {
+            builder = builder
+                .optional_param(
                     "screen_hint",
-                    match screen_hint {
+                    screen_hint.map(|screen_hint| match screen_hint {
                         ScreenHint::SignUp => "sign-up",
                         ScreenHint::SignIn => "sign-in",
-                    },
-                ));
-            }
-
-            String::from(querystring::stringify(query_params).trim_end_matches('&'))
-        };
+                    }),
+                )
+                .optional_param("locale", locale.as_deref());
+        }
 
-        self.workos
-            .base_url()
-            .join(&format!("/user_management/authorize?{}", query))
+        Ok(builder.build())
     }
 }
 
@@ -216,7 +200,7 @@ mod test {
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&connection=conn_1234"
+                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&connection=conn_1234"
             )
             .unwrap()
         )
@@ -244,7 +228,7 @@ mod test {
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&organization=org_1234"
+                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&organization=org_1234"
             )
             .unwrap()
         )
@@ -272,7 +256,35 @@ mod test {
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&provider=GoogleOAuth"
+                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=GoogleOAuth"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_builds_an_authorization_url_when_given_a_custom_provider() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .user_management()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: "https://your-app.com/callback",
+                connection_selector: ConnectionSelector::Provider(&Provider::Oauth(
+                    OauthProvider::Custom("AcmeOAuth".to_string()),
+                )),
+                state: None,
+                code_challenge: None,
+                login_hint: None,
+                domain_hint: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=AcmeOAuth"
             )
             .unwrap()
         )
@@ -289,6 +301,36 @@ mod test {
                 redirect_uri: "https://your-app.com/callback",
                 connection_selector: ConnectionSelector::Provider(&Provider::AuthKit {
                     screen_hint: Some(ScreenHint::SignIn),
+                    locale: None,
+                }),
+                state: None,
+                code_challenge: None,
+                login_hint: None,
+                domain_hint: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=authkit&screen_hint=sign-in"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_builds_an_authorization_url_when_given_authkit_provider_with_a_locale() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .user_management()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: "https://your-app.com/callback",
+                connection_selector: ConnectionSelector::Provider(&Provider::AuthKit {
+                    screen_hint: Some(ScreenHint::SignUp),
+                    locale: Some("fr-FR".to_string()),
                 }),
                 state: None,
                 code_challenge: None,
@@ -300,7 +342,36 @@ mod test {
         assert_eq!(
             authorization_url,
             Url::parse(
-                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https://your-app.com/callback&provider=authkit&screen_hint=sign-in"
+                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=authkit&screen_hint=sign-up&locale=fr-FR"
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_builds_an_authorization_url_when_given_authkit_provider_with_hints() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let authorization_url = workos
+            .user_management()
+            .get_authorization_url(&GetAuthorizationUrlParams {
+                client_id: &ClientId::from("client_123456789"),
+                redirect_uri: "https://your-app.com/callback",
+                connection_selector: ConnectionSelector::Provider(&Provider::AuthKit {
+                    screen_hint: Some(ScreenHint::SignIn),
+                    locale: Some("de".to_string()),
+                }),
+                state: None,
+                code_challenge: None,
+                login_hint: Some("marcelina@example.com"),
+                domain_hint: Some("example.com"),
+            })
+            .unwrap();
+
+        assert_eq!(
+            authorization_url,
+            Url::parse(
+                "https://api.workos.com/user_management/authorize?response_type=code&client_id=client_123456789&redirect_uri=https%3A%2F%2Fyour-app.com%2Fcallback&provider=authkit&login_hint=marcelina%40example.com&domain_hint=example.com&screen_hint=sign-in&locale=de"
             )
             .unwrap()
         )
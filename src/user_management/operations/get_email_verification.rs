@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::user_management::{EmailVerification, EmailVerificationId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetEmailVerification`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetEmailVerificationError> for WorkOsError<GetEmailVerificationError>
     }
 }
 
+impl OperationError for GetEmailVerificationError {}
+
 /// [WorkOS Docs: Get an email verification code](https://workos.com/docs/reference/user-management/email-verification/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetEmailVerification {
     /// Get the details of an existing email verification code that can be used to send an email to a user for verification.
     ///
@@ -44,7 +47,8 @@ pub trait GetEmailVerification {
     ) -> WorkOsResult<EmailVerification, GetEmailVerificationError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetEmailVerification for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_email_verification(
@@ -61,7 +65,7 @@ impl GetEmailVerification for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<EmailVerification>()
+            .decode_json::<EmailVerification>()
             .await?;
 
         Ok(organization)
@@ -84,7 +88,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -1,19 +1,72 @@
 use async_trait::async_trait;
 use jsonwebtoken::jwk::JwkSet;
+use reqwest::{Response, StatusCode};
 use thiserror::Error;
 
+use crate::core::response_to_request_error;
 use crate::sso::ClientId;
 use crate::user_management::UserManagement;
-use crate::{ResponseExt, WorkOsResult};
+use crate::{ErrorClassification, OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 use super::GetJwksUrl;
 
 /// An error returned from [`GetJwks`].
 #[derive(Debug, Error)]
-pub enum GetJwksError {}
+pub enum GetJwksError {
+    /// No JWKS exists for the provided client ID.
+    ///
+    /// This usually indicates that the client ID is misconfigured.
+    #[error("client not found: {client_id}")]
+    ClientNotFound {
+        /// The client ID that was not found.
+        client_id: ClientId,
+    },
+}
+
+impl From<GetJwksError> for WorkOsError<GetJwksError> {
+    fn from(err: GetJwksError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for GetJwksError {
+    fn classification(&self) -> ErrorClassification {
+        match self {
+            GetJwksError::ClientNotFound { .. } => ErrorClassification::ConfigurationError,
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+trait HandleGetJwksError
+where
+    Self: Sized,
+{
+    async fn handle_get_jwks_error(self, client_id: &ClientId) -> WorkOsResult<Self, GetJwksError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl HandleGetJwksError for Response {
+    async fn handle_get_jwks_error(self, client_id: &ClientId) -> WorkOsResult<Self, GetJwksError> {
+        if self.status().is_success() {
+            return Ok(self);
+        }
+
+        if self.status() == StatusCode::NOT_FOUND {
+            return Err(WorkOsError::Operation(GetJwksError::ClientNotFound {
+                client_id: client_id.clone(),
+            }));
+        }
+
+        Err(response_to_request_error(self).await)
+    }
+}
 
 /// [WorkOS Docs: Get JWKS](https://workos.com/docs/reference/user-management/session-tokens/jwks)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetJwks {
     /// Get JSON Web Key Set (JWKS).
     ///
@@ -40,7 +93,8 @@ pub trait GetJwks {
     async fn get_jwks(&self, client_id: &ClientId) -> WorkOsResult<JwkSet, GetJwksError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetJwks for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_jwks(&self, client_id: &ClientId) -> WorkOsResult<JwkSet, GetJwksError> {
@@ -50,9 +104,9 @@ impl GetJwks for UserManagement<'_> {
             .workos
             .send(self.workos.client().get(url))
             .await?
-            .handle_generic_error()
+            .handle_get_jwks_error(client_id)
             .await?
-            .json::<JwkSet>()
+            .decode_json::<JwkSet>()
             .await?;
 
         Ok(jwks)
@@ -76,7 +130,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/sso/jwks/client_123456789")
@@ -100,13 +155,14 @@ mod test {
     }
 
     #[tokio::test]
-    async fn it_returns_an_error_when_the_get_jwks_endpoint_returns_not_found() {
+    async fn it_returns_a_client_not_found_error_when_the_get_jwks_endpoint_returns_not_found() {
         let mut server = mockito::Server::new_async().await;
 
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/sso/jwks/client_123456789")
@@ -125,6 +181,34 @@ mod test {
             .get_jwks(&ClientId::from("client_123456789"))
             .await;
 
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(GetJwksError::ClientNotFound { client_id }))
+                if client_id == ClientId::from("client_123456789")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_request_error_when_the_get_jwks_endpoint_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .get_jwks(&ClientId::from("client_123456789"))
+            .await;
+
         assert_matches!(result, Err(WorkOsError::RequestError(_)))
     }
 }
@@ -47,23 +47,18 @@ impl GetLogoutUrl for UserManagement<'_> {
             return_to,
         } = params;
 
-        let session_id = session_id.to_string();
-        let return_to = return_to.map(|return_to| return_to.to_string());
-
-        let query = {
-            let mut query_params: querystring::QueryParams = vec![("session_id", &session_id)];
-
-            if let Some(return_to) = &return_to {
-                query_params.push(("return_to", return_to));
-            }
-
-            String::from(querystring::stringify(query_params).trim_end_matches('&'))
-        };
-
-        let url = self
+        let mut url = self
             .workos
             .base_url()
-            .join(&format!("/user_management/sessions/logout?{}", query))?;
+            .join("/user_management/sessions/logout")?;
+
+        {
+            let mut query = url.query_pairs_mut();
+            query.clear().append_pair("session_id", session_id);
+            if let Some(return_to) = return_to {
+                query.append_pair("return_to", return_to.as_str());
+            }
+        }
 
         Ok(url)
     }
@@ -92,7 +87,36 @@ mod test {
 
         assert_eq!(
             logout_url,
-            Url::parse("https://api.workos.com/user_management/sessions/logout?session_id=session_01HQAG1HENBZMAZD82YRXDFC0B&return_to=https://your-app.com/signed-out").unwrap()
+            Url::parse("https://api.workos.com/user_management/sessions/logout?session_id=session_01HQAG1HENBZMAZD82YRXDFC0B&return_to=https%3A%2F%2Fyour-app.com%2Fsigned-out").unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_encodes_the_return_to_url() -> Result<(), ParseError> {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let logout_url = workos
+            .user_management()
+            .get_logout_url(&GetLogoutUrlParams {
+                session_id: &SessionId::from("session_01HQAG1HENBZMAZD82YRXDFC0B"),
+                return_to: Some(&Url::parse("https://your-app.com/signed-out?a=1&b=2")?),
+            })
+            .unwrap();
+
+        assert_eq!(
+            logout_url.query_pairs().collect::<Vec<_>>(),
+            vec![
+                (
+                    "session_id".into(),
+                    "session_01HQAG1HENBZMAZD82YRXDFC0B".into()
+                ),
+                (
+                    "return_to".into(),
+                    "https://your-app.com/signed-out?a=1&b=2".into()
+                ),
+            ]
         );
 
         Ok(())
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::user_management::{MagicAuth, MagicAuthId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetMagicAuth`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetMagicAuthError> for WorkOsError<GetMagicAuthError> {
     }
 }
 
+impl OperationError for GetMagicAuthError {}
+
 /// [WorkOS Docs: Get a Magic Auth code](https://workos.com/docs/reference/user-management/magic-auth/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetMagicAuth {
     /// Get the details of an existing Magic Auth code that can be used to send an email to a user for authentication.
     ///
@@ -41,7 +44,8 @@ pub trait GetMagicAuth {
     async fn get_magic_auth(&self, id: &MagicAuthId) -> WorkOsResult<MagicAuth, GetMagicAuthError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetMagicAuth for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_magic_auth(&self, id: &MagicAuthId) -> WorkOsResult<MagicAuth, GetMagicAuthError> {
@@ -55,7 +59,7 @@ impl GetMagicAuth for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<MagicAuth>()
+            .decode_json::<MagicAuth>()
             .await?;
 
         Ok(organization)
@@ -78,7 +82,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
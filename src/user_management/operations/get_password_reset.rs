@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::user_management::{PasswordReset, PasswordResetId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetPasswordReset`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetPasswordResetError> for WorkOsError<GetPasswordResetError> {
     }
 }
 
+impl OperationError for GetPasswordResetError {}
+
 /// [WorkOS Docs: Get a password reset token](https://workos.com/docs/reference/user-management/password-reset/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetPasswordReset {
     /// Get the details of an existing password reset token that can be used to reset a user's password.
     ///
@@ -44,7 +47,8 @@ pub trait GetPasswordReset {
     ) -> WorkOsResult<PasswordReset, GetPasswordResetError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetPasswordReset for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_password_reset(
@@ -61,7 +65,7 @@ impl GetPasswordReset for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<PasswordReset>()
+            .decode_json::<PasswordReset>()
             .await?;
 
         Ok(organization)
@@ -84,7 +88,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
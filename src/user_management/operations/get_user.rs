@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use thiserror::Error;
 
-use crate::user_management::{User, UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::user_management::{GetUserUrl, User, UserId, UserManagement};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`CreateUser`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetUserError> for WorkOsError<GetUserError> {
     }
 }
 
+impl OperationError for GetUserError {}
+
 /// [WorkOS Docs: get a User](https://workos.com/docs/reference/user-management/user/get)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetUser {
     /// Retrieves a [`User`].
     ///
@@ -43,14 +46,12 @@ pub trait GetUser {
     async fn get_user(&self, user_id: &UserId) -> WorkOsResult<User, GetUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetUser for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_user(&self, user_id: &UserId) -> WorkOsResult<User, GetUserError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/user_management/users/{user_id}"))?;
+        let url = self.get_user_url(user_id)?;
 
         let user = self
             .workos
@@ -58,7 +59,7 @@ impl GetUser for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<User>()
+            .decode_json::<User>()
             .await?;
 
         Ok(user)
@@ -82,7 +83,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
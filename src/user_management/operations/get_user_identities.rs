@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::user_management::{Identity, UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetUserIdentities`].
 #[derive(Debug, Error)]
@@ -14,8 +14,11 @@ impl From<GetUserIdentitiesError> for WorkOsError<GetUserIdentitiesError> {
     }
 }
 
+impl OperationError for GetUserIdentitiesError {}
+
 /// [WorkOS Docs: Get User Identities](https://workos.com/docs/reference/user-management/identity/list)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait GetUserIdentities {
     /// Get a list of identities associated with the user.
     ///
@@ -44,7 +47,8 @@ pub trait GetUserIdentities {
     ) -> WorkOsResult<Vec<Identity>, GetUserIdentitiesError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl GetUserIdentities for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn get_user_identities(
@@ -62,7 +66,7 @@ impl GetUserIdentities for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<Vec<Identity>>()
+            .decode_json::<Vec<Identity>>()
             .await?;
 
         Ok(users)
@@ -86,7 +90,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -0,0 +1,63 @@
+use url::{ParseError, Url};
+
+use crate::user_management::{UserId, UserManagement};
+
+/// Builds the API URL of a [`User`](crate::user_management::User).
+pub trait GetUserUrl {
+    /// Returns the API URL of the user with the provided ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use url::ParseError;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let user_url = workos
+    ///     .user_management()
+    ///     .get_user_url(&UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_user_url(&self, user_id: &UserId) -> Result<Url, ParseError>;
+}
+
+impl GetUserUrl for UserManagement<'_> {
+    fn get_user_url(&self, user_id: &UserId) -> Result<Url, ParseError> {
+        self.workos
+            .base_url()
+            .join(&format!("/user_management/users/{user_id}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    #[test]
+    fn it_builds_a_user_url_from_the_base_url() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url("https://auth.your-app.com")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let user_url = workos
+            .user_management()
+            .get_user_url(&UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"))
+            .unwrap();
+
+        assert_eq!(
+            user_url,
+            Url::parse(
+                "https://auth.your-app.com/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5"
+            )
+            .unwrap()
+        )
+    }
+}
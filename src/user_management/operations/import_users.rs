@@ -0,0 +1,320 @@
+use async_trait::async_trait;
+
+use crate::core::run_batch;
+use crate::user_management::{CreateUser, CreateUserError, CreateUserParams, User, UserManagement};
+use crate::{BatchFailure, BatchReport};
+
+/// The parameters for [`ImportUsers`].
+#[derive(Debug)]
+pub struct ImportUsersParams<'a> {
+    /// The users to create.
+    pub users: &'a [CreateUserParams<'a>],
+
+    /// The maximum number of users to create concurrently.
+    ///
+    /// Defaults to 4.
+    pub concurrency: Option<usize>,
+}
+
+/// A user that could not be imported, with its index in [`ImportUsersParams::users`].
+pub type UserImportFailure = BatchFailure<CreateUserError>;
+
+/// The outcome of [`ImportUsers`].
+///
+/// Users that were skipped because a user with the same email address already exists are
+/// reported as [`conflicts`](BatchReport::conflicts).
+pub type ImportUsersReport = BatchReport<User, CreateUserError>;
+
+/// [WorkOS Docs: Create a user](https://workos.com/docs/reference/user-management/user/create)
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait ImportUsers {
+    /// Creates many users, with a bounded number of requests in flight.
+    ///
+    /// Requests that are rate limited are retried after the delay requested by the API.
+    /// Every user is attempted, and the outcome of each is reported in the returned
+    /// [`ImportUsersReport`]: users that already exist are reported as conflicts, and any
+    /// other error is reported as a failure.
+    ///
+    /// Combine with [`PasswordParams::PasswordHash`](crate::user_management::PasswordParams::PasswordHash)
+    /// to migrate users along with their existing passwords.
+    ///
+    /// [WorkOS Docs: Create a user](https://workos.com/docs/reference/user-management/user/create)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, Email, InvalidEmailError, WorkOs};
+    ///
+    /// # async fn run() -> Result<(), InvalidEmailError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let emails = ["marcelina.davis@example.com", "ali.khan@example.com"]
+    ///     .into_iter()
+    ///     .map(str::parse)
+    ///     .collect::<Result<Vec<Email>, _>>()?;
+    ///
+    /// let users = emails
+    ///     .iter()
+    ///     .map(|email| CreateUserParams {
+    ///         email,
+    ///         password: None,
+    ///         first_name: None,
+    ///         last_name: None,
+    ///         email_verified: Some(true),
+    ///         external_id: None,
+    ///         metadata: None,
+    ///         idempotency_key: None,
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let report = workos
+    ///     .user_management()
+    ///     .import_users(&ImportUsersParams {
+    ///         users: &users,
+    ///         concurrency: Some(8),
+    ///     })
+    ///     .await;
+    ///
+    /// for failure in &report.failures {
+    ///     eprintln!("user {} failed: {}", failure.index, failure.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn import_users(&self, params: &ImportUsersParams<'_>) -> ImportUsersReport;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ImportUsers for UserManagement<'_> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, params)))]
+    async fn import_users(&self, params: &ImportUsersParams<'_>) -> ImportUsersReport {
+        let operations = params
+            .users
+            .iter()
+            .map(|user| move || self.create_user(user));
+
+        run_batch(operations, params.concurrency).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::user_management::UserId;
+    use std::time::Duration;
+
+    use crate::{ApiKey, Email, RetryPolicy, WorkOs, WorkOsError};
+
+    use super::*;
+
+    fn user_params(email: &Email) -> CreateUserParams<'_> {
+        CreateUserParams {
+            email,
+            password: None,
+            first_name: None,
+            last_name: None,
+            email_verified: None,
+            external_id: None,
+            metadata: None,
+            idempotency_key: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_reports_created_skipped_and_failed_users() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("POST", "/user_management/users")
+            .match_body(Matcher::PartialJson(
+                json!({ "email": "marcelina.davis@example.com" }),
+            ))
+            .with_status(201)
+            .with_body(
+                json!({
+                    "object": "user",
+                    "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "email": "marcelina.davis@example.com",
+                    "first_name": null,
+                    "last_name": null,
+                    "email_verified": false,
+                    "profile_picture_url": null,
+                    "last_sign_in_at": null,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/user_management/users")
+            .match_body(Matcher::PartialJson(
+                json!({ "email": "ali.khan@example.com" }),
+            ))
+            .with_status(422)
+            .with_body(
+                json!({
+                    "code": "user_creation_error",
+                    "message": "Could not create user.",
+                    "errors": [
+                        {
+                            "code": "email_not_available",
+                            "message": "This email is not available."
+                        }
+                    ]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/user_management/users")
+            .match_body(Matcher::PartialJson(
+                json!({ "email": "blocked@example.com" }),
+            ))
+            .with_status(422)
+            .with_body(
+                json!({
+                    "code": "invalid_request_parameters",
+                    "message": "Validation failed."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let emails = [
+            "marcelina.davis@example.com".parse().unwrap(),
+            "ali.khan@example.com".parse().unwrap(),
+            "blocked@example.com".parse().unwrap(),
+        ];
+        let users = emails.iter().map(user_params).collect::<Vec<_>>();
+
+        let report = workos
+            .user_management()
+            .import_users(&ImportUsersParams {
+                users: &users,
+                concurrency: Some(2),
+            })
+            .await;
+
+        assert_eq!(report.created.len(), 1);
+        assert_eq!(report.created[0].0, 0);
+        assert_eq!(
+            report.created[0].1.id,
+            UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert_eq!(report.conflicts, vec![1]);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].index, 2);
+        assert!(!report.is_success());
+    }
+
+    #[tokio::test]
+    async fn it_retries_rate_limited_users() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(1))
+            .build()
+            .unwrap();
+
+        let rate_limited = server
+            .mock("POST", "/user_management/users")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(1)
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/user_management/users")
+            .with_status(201)
+            .with_body(
+                json!({
+                    "object": "user",
+                    "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "email": "marcelina.davis@example.com",
+                    "first_name": null,
+                    "last_name": null,
+                    "email_verified": false,
+                    "profile_picture_url": null,
+                    "last_sign_in_at": null,
+                    "created_at": "2021-06-25T19:07:33.155Z",
+                    "updated_at": "2021-06-25T19:07:33.155Z"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let email = "marcelina.davis@example.com".parse().unwrap();
+        let users = [user_params(&email)];
+
+        let report = workos
+            .user_management()
+            .import_users(&ImportUsersParams {
+                users: &users,
+                concurrency: None,
+            })
+            .await;
+
+        rate_limited.assert_async().await;
+        assert!(report.is_success());
+        assert_eq!(report.created.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn it_does_not_stack_rate_limit_retries_on_the_retry_policy() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .retry_policy(RetryPolicy::new(3).base_delay(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        // Each rate limited request is retried by the import alone: 1 attempt and 5
+        // retries, rather than 3 attempts by the retry policy for each of those.
+        let rate_limited = server
+            .mock("POST", "/user_management/users")
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(6)
+            .create_async()
+            .await;
+
+        let email = "marcelina.davis@example.com".parse().unwrap();
+        let users = [user_params(&email)];
+
+        let report = workos
+            .user_management()
+            .import_users(&ImportUsersParams {
+                users: &users,
+                concurrency: None,
+            })
+            .await;
+
+        rate_limited.assert_async().await;
+        assert_eq!(report.failures.len(), 1);
+        matches::assert_matches!(report.failures[0].error, WorkOsError::RateLimited { .. });
+    }
+}
@@ -0,0 +1,360 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::roles::RoleSlug;
+use crate::user_management::{
+    GetInvitation, Invitation, InvitationId, InvitationState, RevokeInvitation, SendInvitation,
+    SendInvitationParams, UserManagement,
+};
+use crate::{Email, KnownOrUnknown, OperationError, WorkOsError, WorkOsResult};
+
+/// The parameters for [`ResendInvitation`].
+#[derive(Debug)]
+pub struct ResendInvitationParams<'a> {
+    /// The ID of the invitation to resend.
+    pub invitation_id: &'a InvitationId,
+
+    /// The role that the recipient will receive when they join the organization.
+    ///
+    /// Defaults to the role of the original invitation.
+    pub role_slug: Option<&'a RoleSlug>,
+
+    /// How many days the new invitation will be valid for.
+    ///
+    /// Must be between 1 and 30. Defaults to 7.
+    pub expires_in_days: Option<u8>,
+}
+
+/// An error returned from [`ResendInvitation`].
+#[derive(Debug, Error)]
+pub enum ResendInvitationError {
+    /// The invitation has already been accepted, so there is nothing to resend.
+    #[error("invitation already accepted: {invitation_id}")]
+    AlreadyAccepted {
+        /// The ID of the accepted invitation.
+        invitation_id: InvitationId,
+    },
+}
+
+impl From<ResendInvitationError> for WorkOsError<ResendInvitationError> {
+    fn from(err: ResendInvitationError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for ResendInvitationError {}
+
+/// Replaces an invitation with a fresh one.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait ResendInvitation {
+    /// Revokes an invitation, if it is still pending, and sends a new one to the same
+    /// recipient for the same organization, from the same inviter, and with the same role.
+    ///
+    /// Returns the new invitation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::roles::RoleSlug;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), ResendInvitationError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let invitation = workos
+    ///     .user_management()
+    ///     .resend_invitation(&ResendInvitationParams {
+    ///         invitation_id: &InvitationId::from("invitation_01E4ZCR3C56J083X43JQXF3JK5"),
+    ///         role_slug: Some(&RoleSlug::from("admin")),
+    ///         expires_in_days: None,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn resend_invitation(
+        &self,
+        params: &ResendInvitationParams<'_>,
+    ) -> WorkOsResult<Invitation, ResendInvitationError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl ResendInvitation for UserManagement<'_> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    async fn resend_invitation(
+        &self,
+        params: &ResendInvitationParams<'_>,
+    ) -> WorkOsResult<Invitation, ResendInvitationError> {
+        let invitation = self
+            .get_invitation(params.invitation_id)
+            .await
+            .map_err(|err| err.map_operation(|err| match err {}))?;
+
+        match invitation.state {
+            KnownOrUnknown::Known(InvitationState::Accepted) => {
+                return Err(WorkOsError::Operation(
+                    ResendInvitationError::AlreadyAccepted {
+                        invitation_id: invitation.id,
+                    },
+                ));
+            }
+            KnownOrUnknown::Known(InvitationState::Pending) => {
+                self.revoke_invitation(&invitation.id)
+                    .await
+                    .map_err(|err| err.map_operation(|err| match err {}))?;
+            }
+            _ => {}
+        }
+
+        self.send_invitation(&SendInvitationParams {
+            email: &Email::new_unchecked(invitation.email),
+            organization_id: invitation.organization_id.as_ref(),
+            expires_in_days: params.expires_in_days,
+            inviter_user_id: invitation.inviter_user_id.as_ref(),
+            role_slug: params.role_slug.or(invitation.role_slug.as_ref()),
+        })
+        .await
+        .map_err(|err| err.map_operation(|err| match err {}))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::{Value, json};
+    use tokio;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn invitation_json(id: &str, state: &str) -> Value {
+        json!({
+            "object": "invitation",
+            "id": id,
+            "email": "marcelina.davis@example.com",
+            "state": state,
+            "accepted_at": null,
+            "revoked_at": null,
+            "expires_at": "2021-07-01T19:07:33.155Z",
+            "token": "Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "accept_invitation_url": "https://your-app.com/invite?invitation_token=Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+            "inviter_user_id": "user_01HYGBX8ZGD19949T3BM4FW1C3",
+            "role_slug": "member",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn it_revokes_a_pending_invitation_and_sends_a_new_one() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock(
+                "GET",
+                "/user_management/invitations/invitation_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(200)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK5", "pending").to_string(),
+            )
+            .create_async()
+            .await;
+
+        let revoke = server
+            .mock(
+                "POST",
+                "/user_management/invitations/invitation_01E4ZCR3C56J083X43JQXF3JK5/revoke",
+            )
+            .with_status(200)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK5", "revoked").to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let send = server
+            .mock("POST", "/user_management/invitations")
+            .match_body(Matcher::Json(json!({
+                "email": "marcelina.davis@example.com",
+                "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+                "inviter_user_id": "user_01HYGBX8ZGD19949T3BM4FW1C3",
+                "role_slug": "admin"
+            })))
+            .with_status(201)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK6", "pending").to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let invitation = workos
+            .user_management()
+            .resend_invitation(&ResendInvitationParams {
+                invitation_id: &InvitationId::from("invitation_01E4ZCR3C56J083X43JQXF3JK5"),
+                role_slug: Some(&RoleSlug::from("admin")),
+                expires_in_days: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            invitation.id,
+            InvitationId::from("invitation_01E4ZCR3C56J083X43JQXF3JK6")
+        );
+        revoke.assert_async().await;
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_role_of_the_original_invitation_by_default() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock(
+                "GET",
+                "/user_management/invitations/invitation_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(200)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK5", "expired").to_string(),
+            )
+            .create_async()
+            .await;
+
+        let send = server
+            .mock("POST", "/user_management/invitations")
+            .match_body(Matcher::PartialJson(json!({
+                "role_slug": "member"
+            })))
+            .with_status(201)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK6", "pending").to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        workos
+            .user_management()
+            .resend_invitation(&ResendInvitationParams {
+                invitation_id: &InvitationId::from("invitation_01E4ZCR3C56J083X43JQXF3JK5"),
+                role_slug: None,
+                expires_in_days: None,
+            })
+            .await
+            .unwrap();
+
+        send.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_revoke_an_expired_invitation() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock(
+                "GET",
+                "/user_management/invitations/invitation_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(200)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK5", "expired").to_string(),
+            )
+            .create_async()
+            .await;
+
+        let revoke = server
+            .mock("POST", Matcher::Regex("/revoke$".to_string()))
+            .expect(0)
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/user_management/invitations")
+            .with_status(201)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK6", "pending").to_string(),
+            )
+            .create_async()
+            .await;
+
+        workos
+            .user_management()
+            .resend_invitation(&ResendInvitationParams {
+                invitation_id: &InvitationId::from("invitation_01E4ZCR3C56J083X43JQXF3JK5"),
+                role_slug: None,
+                expires_in_days: Some(14),
+            })
+            .await
+            .unwrap();
+
+        revoke.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_for_an_accepted_invitation() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock(
+                "GET",
+                "/user_management/invitations/invitation_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(200)
+            .with_body(
+                invitation_json("invitation_01E4ZCR3C56J083X43JQXF3JK5", "accepted").to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .resend_invitation(&ResendInvitationParams {
+                invitation_id: &InvitationId::from("invitation_01E4ZCR3C56J083X43JQXF3JK5"),
+                role_slug: None,
+                expires_in_days: None,
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(
+                ResendInvitationError::AlreadyAccepted { .. }
+            ))
+        )
+    }
+}
@@ -4,8 +4,10 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::core::response_to_request_error;
-use crate::user_management::{PasswordResetToken, User, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::user_management::{
+    PasswordResetToken, User, UserManagement, ValidationError, validate_password,
+};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`ResetPassword`].
 #[derive(Debug, Serialize)]
@@ -44,6 +46,11 @@ pub enum ResetPasswordError {
         /// List of errors.
         errors: Vec<PasswordResetError>,
     },
+
+    /// The new password failed local validation.
+    #[error(transparent)]
+    #[serde(skip)]
+    Validation(#[from] ValidationError),
 }
 
 impl From<ResetPasswordError> for WorkOsError<ResetPasswordError> {
@@ -52,6 +59,8 @@ impl From<ResetPasswordError> for WorkOsError<ResetPasswordError> {
     }
 }
 
+impl OperationError for ResetPasswordError {}
+
 /// Password reset error.
 #[derive(Debug, Error, Deserialize, Serialize)]
 #[serde(tag = "code", rename_all = "snake_case")]
@@ -77,7 +86,8 @@ pub enum PasswordResetError {
     },
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub(crate) trait HandleResetPasswordError
 where
     Self: Sized,
@@ -85,7 +95,8 @@ where
     async fn handle_reset_password_error(self) -> WorkOsResult<Self, ResetPasswordError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl HandleResetPasswordError for Response {
     async fn handle_reset_password_error(self) -> WorkOsResult<Self, ResetPasswordError> {
         if self.status().is_success() {
@@ -96,7 +107,7 @@ impl HandleResetPasswordError for Response {
             self.status(),
             StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND
         ) {
-            let error = self.json::<ResetPasswordError>().await?;
+            let error = self.decode_json::<ResetPasswordError>().await?;
             return Err(WorkOsError::Operation(error));
         }
 
@@ -105,7 +116,8 @@ impl HandleResetPasswordError for Response {
 }
 
 /// [WorkOS Docs: Reset the password](https://workos.com/docs/reference/user-management/password-reset/reset-password)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait ResetPassword {
     /// Sets a new password using the token query parameter from the link that the user received.
     ///
@@ -139,13 +151,18 @@ pub trait ResetPassword {
     ) -> WorkOsResult<ResetPasswordResponse, ResetPasswordError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl ResetPassword for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn reset_password(
         &self,
         params: &ResetPasswordParams<'_>,
     ) -> WorkOsResult<ResetPasswordResponse, ResetPasswordError> {
+        if self.workos.local_validation() {
+            validate_password(params.new_password).map_err(ResetPasswordError::from)?;
+        }
+
         let url = self
             .workos
             .base_url()
@@ -165,7 +182,7 @@ impl ResetPassword for UserManagement<'_> {
             .await?
             .handle_reset_password_error()
             .await?
-            .json::<ResetPasswordResponse>()
+            .decode_json::<ResetPasswordResponse>()
             .await?;
 
         Ok(response)
@@ -189,7 +206,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("POST", "/user_management/password_reset/confirm")
@@ -229,4 +247,39 @@ mod test {
             UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")
         )
     }
+
+    #[tokio::test]
+    async fn it_validates_the_password_length_locally_when_enabled() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .local_validation(true)
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("POST", "/user_management/password_reset/confirm")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .reset_password(&ResetPasswordParams {
+                token: &PasswordResetToken::from("stpIJ48IFJt0HhSIqjf8eppe0"),
+                new_password: "short",
+            })
+            .await;
+
+        matches::assert_matches!(
+            result,
+            Err(WorkOsError::Operation(ResetPasswordError::Validation(
+                ValidationError::PasswordTooShort { .. }
+            )))
+        );
+
+        mock.assert_async().await;
+    }
 }
@@ -2,8 +2,8 @@ use async_trait::async_trait;
 use serde_json::json;
 use thiserror::Error;
 
-use crate::user_management::{ExternalId, User, UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::user_management::{ExternalId, GetUserUrl, User, UserId, UserManagement};
+use crate::{OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`UpdateExternalId`].
 #[derive(Debug, Error)]
@@ -15,8 +15,11 @@ impl From<UpdateExternalIdError> for WorkOsError<UpdateExternalIdError> {
     }
 }
 
+impl OperationError for UpdateExternalIdError {}
+
 /// [WorkOS Docs: Update a User's External ID](https://workos.com/docs/reference/user-management/user/update)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait UpdateExternalId {
     /// Updates a user's external ID.
     ///
@@ -51,7 +54,8 @@ pub trait UpdateExternalId {
     ) -> WorkOsResult<User, UpdateExternalIdError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl UpdateExternalId for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn update_external_id(
@@ -59,10 +63,7 @@ impl UpdateExternalId for UserManagement<'_> {
         user_id: &UserId,
         external_id: &ExternalId,
     ) -> WorkOsResult<User, UpdateExternalIdError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/user_management/users/{user_id}"))?;
+        let url = self.get_user_url(user_id)?;
 
         let body = json!({
             "external_id": external_id
@@ -80,7 +81,7 @@ impl UpdateExternalId for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<User>()
+            .decode_json::<User>()
             .await?;
 
         Ok(user)
@@ -104,7 +105,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -2,14 +2,16 @@ use async_trait::async_trait;
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::user_management::{PasswordParams, User, UserId, UserManagement};
-use crate::{Metadata, ResponseExt, WorkOsError, WorkOsResult};
+use crate::user_management::{
+    GetUserUrl, PasswordParams, User, UserId, UserManagement, ValidationError,
+};
+use crate::{Email, Metadata, OperationError, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`UpdateUser`].
 #[derive(Debug, Serialize)]
 pub struct UpdateUserParams<'a> {
     /// The email address of the user.
-    pub email: Option<&'a str>,
+    pub email: Option<&'a Email>,
 
     /// The password to set for the user.
     #[serde(flatten)]
@@ -31,9 +33,24 @@ pub struct UpdateUserParams<'a> {
     pub metadata: Option<Metadata>,
 }
 
+impl UpdateUserParams<'_> {
+    /// Validates the password locally, if provided.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(password) = self.password {
+            password.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
 /// An error returned from [`UpdateUser`].
 #[derive(Debug, Error)]
-pub enum UpdateUserError {}
+pub enum UpdateUserError {
+    /// The parameters failed local validation.
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+}
 
 impl From<UpdateUserError> for WorkOsError<UpdateUserError> {
     fn from(err: UpdateUserError) -> Self {
@@ -41,8 +58,11 @@ impl From<UpdateUserError> for WorkOsError<UpdateUserError> {
     }
 }
 
+impl OperationError for UpdateUserError {}
+
 /// [WorkOS Docs: Update a User](https://workos.com/docs/reference/user-management/user/update)
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait UpdateUser {
     /// Updates a [`User`].
     ///
@@ -65,7 +85,7 @@ pub trait UpdateUser {
     ///     .update_user(
     ///         &UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
     ///         &UpdateUserParams {
-    ///             email: Some("marcelina.updated@example.com"),
+    ///             email: Some(&"marcelina.updated@example.com".parse().unwrap()),
     ///             password: None,
     ///             first_name: Some("Marcelina"),
     ///             last_name: Some("Davis-Updated"),
@@ -85,7 +105,8 @@ pub trait UpdateUser {
     ) -> WorkOsResult<User, UpdateUserError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl UpdateUser for UserManagement<'_> {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
     async fn update_user(
@@ -93,10 +114,11 @@ impl UpdateUser for UserManagement<'_> {
         user_id: &UserId,
         params: &UpdateUserParams<'_>,
     ) -> WorkOsResult<User, UpdateUserError> {
-        let url = self
-            .workos
-            .base_url()
-            .join(&format!("/user_management/users/{user_id}"))?;
+        if self.workos.local_validation() {
+            params.validate().map_err(UpdateUserError::from)?;
+        }
+
+        let url = self.get_user_url(user_id)?;
 
         let user = self
             .workos
@@ -110,7 +132,7 @@ impl UpdateUser for UserManagement<'_> {
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
-            .json::<User>()
+            .decode_json::<User>()
             .await?;
 
         Ok(user)
@@ -134,7 +156,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock(
@@ -166,7 +189,7 @@ mod test {
             .update_user(
                 &UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
                 &UpdateUserParams {
-                    email: Some("marcelina.updated@example.com"),
+                    email: Some(&"marcelina.updated@example.com".parse().unwrap()),
                     password: None,
                     first_name: Some("Marcelina"),
                     last_name: Some("Davis-Updated"),
@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+
+use crate::sso::ClientId;
+use crate::user_management::{
+    AccessTokenVerifier, GetJwks, GetJwksError, UserManagement, VerifiedAccessToken,
+    VerifyAccessTokenError,
+};
+use crate::{WorkOsError, WorkOsResult};
+
+/// The parameters for [`VerifyAccessToken`].
+#[derive(Debug)]
+pub struct VerifyAccessTokenParams<'a> {
+    /// The client ID the access token was issued for.
+    pub client_id: &'a ClientId,
+
+    /// The access token to verify.
+    pub access_token: &'a str,
+
+    /// The expected issuer of the access token, if it is not the User Management issuer of
+    /// the client ID, such as a custom authentication domain.
+    pub issuer: Option<&'a str>,
+}
+
+/// [WorkOS Docs: Access token](https://workos.com/docs/user-management/sessions/access-token)
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait VerifyAccessToken {
+    /// Verifies an access token against the JWKS of the client ID and returns its claims.
+    ///
+    /// The JWKS is fetched on every call. To verify many tokens, use
+    /// [`JwksCache::verify`](crate::user_management::JwksCache::verify) instead.
+    ///
+    /// [WorkOS Docs: Access token](https://workos.com/docs/user-management/sessions/access-token)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::sso::ClientId;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), VerifyAccessTokenError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let token = workos
+    ///     .user_management()
+    ///     .verify_access_token(&VerifyAccessTokenParams {
+    ///         client_id: &ClientId::from("client_123456789"),
+    ///         access_token: "eyJhbGciOiJSUzI1NiJ9...",
+    ///         issuer: None,
+    ///     })
+    ///     .await?;
+    ///
+    /// println!("session {} for user {}", token.claims.sid, token.claims.sub);
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn verify_access_token(
+        &self,
+        params: &VerifyAccessTokenParams<'_>,
+    ) -> WorkOsResult<VerifiedAccessToken, VerifyAccessTokenError>;
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl VerifyAccessToken for UserManagement<'_> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    async fn verify_access_token(
+        &self,
+        params: &VerifyAccessTokenParams<'_>,
+    ) -> WorkOsResult<VerifiedAccessToken, VerifyAccessTokenError> {
+        let jwks = self.get_jwks(params.client_id).await.map_err(|err| {
+            err.map_operation(|GetJwksError::ClientNotFound { client_id }| {
+                VerifyAccessTokenError::ClientNotFound { client_id }
+            })
+        })?;
+
+        let mut verifier = AccessTokenVerifier::new(jwks, [params.client_id.clone()])
+            .base_url(self.workos.base_url());
+        if let Some(issuer) = params.issuer {
+            verifier = verifier.issuer(issuer);
+        }
+
+        verifier
+            .verify(params.access_token)
+            .map_err(WorkOsError::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::user_management::access_token_verifier::test::{claims, jwks, sign};
+    use crate::{ApiKey, WorkOs, WorkOsError};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_verifies_the_access_token_against_the_jwks() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .create_async()
+            .await;
+
+        let token = workos
+            .user_management()
+            .verify_access_token(&VerifyAccessTokenParams {
+                client_id: &ClientId::from("client_456"),
+                access_token: &sign(claims(json!({}))),
+                issuer: Some("https://api.workos.com/user_management/client_456"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(token.audience, ClientId::from("client_456"));
+        assert_eq!(token.claims.entitlements, vec!["audit-logs".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_client_not_found_error_when_the_jwks_does_not_exist() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let result = workos
+            .user_management()
+            .verify_access_token(&VerifyAccessTokenParams {
+                client_id: &ClientId::from("client_456"),
+                access_token: &sign(claims(json!({}))),
+                issuer: None,
+            })
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(VerifyAccessTokenError::ClientNotFound { client_id }))
+                if client_id == ClientId::from("client_456")
+        )
+    }
+}
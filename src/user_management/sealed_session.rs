@@ -0,0 +1,526 @@
+use std::fmt;
+use std::time::Duration;
+
+use aes::Aes256;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use cbc::cipher::block_padding::Pkcs7;
+use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha1::Sha1;
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::SecretString;
+use crate::core::SystemTime;
+use crate::organizations::OrganizationId;
+use crate::sso::AccessToken;
+use crate::user_management::{AuthenticationResponse, Impersonator, RefreshToken, User};
+
+/// The prefix of sealed sessions, identifying the iron format version they were sealed with.
+const MAC_PREFIX: &str = "Fe26.2";
+
+/// The ID iron-session gives a password provided as a single string.
+const PASSWORD_ID: &str = "1";
+
+/// The iron-session version appended to sealed sessions, after a `~`.
+const SEAL_VERSION: &str = "2";
+
+/// The minimum length of a cookie password.
+const MIN_PASSWORD_LENGTH: usize = 32;
+
+/// The length of the salts the encryption and integrity keys are derived with, in bytes.
+const SALT_LENGTH: usize = 32;
+
+/// The length of the AES-CBC initialization vector, in bytes.
+const IV_LENGTH: usize = 16;
+
+/// How long a sealed session remains valid, matching the iron-session default.
+const TTL: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// How far past its expiration a sealed session is still accepted, to allow for clock skew.
+const TIMESTAMP_SKEW: Duration = Duration::from_secs(60);
+
+/// The data stored in a sealed session cookie.
+///
+/// Fields are serialized with the names used by the WorkOS Node SDK.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionData {
+    /// A JWT containing information about the session.
+    pub access_token: AccessToken,
+
+    /// Exchange this token for a new access token.
+    pub refresh_token: RefreshToken,
+
+    /// The user the session belongs to.
+    #[serde(with = "node_user")]
+    pub user: User,
+
+    /// The organization the user signed in to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<OrganizationId>,
+
+    /// The WorkOS Dashboard user who is impersonating the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonator: Option<Impersonator>,
+}
+
+impl From<AuthenticationResponse> for SessionData {
+    fn from(response: AuthenticationResponse) -> Self {
+        Self {
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            user: response.user,
+            organization_id: response.organization_id,
+            impersonator: response.impersonator,
+        }
+    }
+}
+
+/// (De)serializes a [`User`] with the camelCase field names of the WorkOS Node SDK.
+mod node_user {
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::{Map, Value};
+
+    use crate::user_management::User;
+
+    pub(super) fn serialize<S: Serializer>(user: &User, serializer: S) -> Result<S::Ok, S::Error> {
+        let Value::Object(fields) = serde_json::to_value(user).map_err(S::Error::custom)? else {
+            return Err(S::Error::custom("user must serialize to an object"));
+        };
+
+        let mut fields = fields
+            .into_iter()
+            .map(|(name, value)| (to_camel_case(&name), value))
+            .collect::<Map<_, _>>();
+        fields.insert("object".to_string(), Value::from("user"));
+
+        fields.serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<User, D::Error> {
+        let fields = Map::<String, Value>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(name, value)| (to_snake_case(&name), value))
+            .collect();
+
+        serde_json::from_value(Value::Object(fields)).map_err(D::Error::custom)
+    }
+
+    fn to_camel_case(name: &str) -> String {
+        let mut camel = String::with_capacity(name.len());
+        let mut capitalize = false;
+        for c in name.chars() {
+            match c {
+                '_' => capitalize = true,
+                c if capitalize => {
+                    camel.push(c.to_ascii_uppercase());
+                    capitalize = false;
+                }
+                c => camel.push(c),
+            }
+        }
+        camel
+    }
+
+    fn to_snake_case(name: &str) -> String {
+        let mut snake = String::with_capacity(name.len() + 4);
+        for c in name.chars() {
+            if c.is_ascii_uppercase() {
+                snake.push('_');
+                snake.push(c.to_ascii_lowercase());
+            } else {
+                snake.push(c);
+            }
+        }
+        snake
+    }
+}
+
+/// An error returned from [`SessionSealer`].
+#[derive(Debug, Error)]
+pub enum SealedSessionError {
+    /// The cookie password is shorter than 32 characters.
+    #[error("cookie password must be at least {MIN_PASSWORD_LENGTH} characters long")]
+    PasswordTooShort,
+
+    /// The session data could not be encrypted.
+    #[error("session data could not be encrypted")]
+    Encryption,
+
+    /// The sealed session is not in the expected format.
+    #[error("sealed session is malformed")]
+    Malformed,
+
+    /// The sealed session could not be decrypted, because it was sealed with a different
+    /// password or has been tampered with.
+    #[error("sealed session could not be decrypted")]
+    Decryption,
+
+    /// The sealed session has expired.
+    #[error("sealed session has expired")]
+    Expired,
+
+    /// The session data could not be serialized or deserialized.
+    #[error(transparent)]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Encrypts [`SessionData`] into a cookie-safe string and decrypts it again.
+///
+/// Sessions are sealed in the iron format (`Fe26.2`) used by iron-session, so that cookies
+/// can be shared with the WorkOS Node SDK and the other SDKs built on it: each can unseal
+/// the sessions sealed by the others, given the same cookie password.
+///
+/// Sealed sessions are encrypted with AES-256-CBC and signed with HMAC-SHA256, using keys
+/// derived from the cookie password with a random salt for each session, so they can be
+/// neither read nor modified without the password. They expire after 14 days.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::user_management::*;
+/// # fn run(session: SessionData) -> Result<(), SealedSessionError> {
+/// let sealer = SessionSealer::new("a-cookie-password-at-least-32-characters-long")?;
+///
+/// let cookie = sealer.seal(&session)?;
+/// let session = sealer.unseal(&cookie)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SessionSealer {
+    password: SecretString,
+}
+
+impl SessionSealer {
+    /// Returns a new [`SessionSealer`] for the provided cookie password.
+    ///
+    /// The password must be at least 32 characters long.
+    pub fn new(password: &str) -> Result<Self, SealedSessionError> {
+        if password.chars().count() < MIN_PASSWORD_LENGTH {
+            return Err(SealedSessionError::PasswordTooShort);
+        }
+
+        Ok(Self {
+            password: SecretString::from(password),
+        })
+    }
+
+    /// Derives a key from the password and a hex-encoded salt, as iron does.
+    fn key(&self, salt: &str) -> [u8; 32] {
+        pbkdf2::pbkdf2_hmac_array::<Sha1, 32>(
+            self.password.expose_secret().as_bytes(),
+            salt.as_bytes(),
+            1,
+        )
+    }
+
+    fn mac(&self, salt: &str, base: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key(salt))
+            .expect("HMAC accepts keys of any length");
+        mac.update(base.as_bytes());
+        mac
+    }
+
+    /// Encrypts the session data into a cookie-safe string.
+    pub fn seal(&self, session: &SessionData) -> Result<String, SealedSessionError> {
+        self.seal_at(session, SystemTime::now())
+    }
+
+    fn seal_at(
+        &self,
+        session: &SessionData,
+        now: SystemTime,
+    ) -> Result<String, SealedSessionError> {
+        let plaintext = serde_json::to_vec(session)?;
+
+        let encryption_salt = random_salt()?;
+        let mut iv = [0; IV_LENGTH];
+        getrandom::getrandom(&mut iv).map_err(|_| SealedSessionError::Encryption)?;
+        let ciphertext =
+            cbc::Encryptor::<Aes256>::new(&self.key(&encryption_salt).into(), &iv.into())
+                .encrypt_padded_vec_mut::<Pkcs7>(&plaintext);
+
+        let expiration = unix_millis(now + TTL);
+        let base = format!(
+            "{MAC_PREFIX}*{PASSWORD_ID}*{encryption_salt}*{}*{}*{expiration}",
+            URL_SAFE_NO_PAD.encode(iv),
+            URL_SAFE_NO_PAD.encode(ciphertext),
+        );
+
+        let mac_salt = random_salt()?;
+        let digest = self.mac(&mac_salt, &base).finalize().into_bytes();
+
+        Ok(format!(
+            "{base}*{mac_salt}*{}~{SEAL_VERSION}",
+            URL_SAFE_NO_PAD.encode(digest)
+        ))
+    }
+
+    /// Decrypts a sealed session, verifying that it was sealed with the same password, has
+    /// not been modified and has not expired.
+    pub fn unseal(&self, sealed: &str) -> Result<SessionData, SealedSessionError> {
+        self.unseal_at(sealed, SystemTime::now())
+    }
+
+    fn unseal_at(&self, sealed: &str, now: SystemTime) -> Result<SessionData, SealedSessionError> {
+        let (sealed, version) = match sealed.split_once('~') {
+            Some((sealed, version)) => (sealed, Some(version)),
+            None => (sealed, None),
+        };
+
+        let parts = sealed.split('*').collect::<Vec<_>>();
+        let [
+            prefix,
+            password_id,
+            encryption_salt,
+            iv,
+            ciphertext,
+            expiration,
+            mac_salt,
+            digest,
+        ] = parts[..]
+        else {
+            return Err(SealedSessionError::Malformed);
+        };
+        if prefix != MAC_PREFIX {
+            return Err(SealedSessionError::Malformed);
+        }
+
+        if !expiration.is_empty() {
+            let expiration = expiration
+                .parse::<u128>()
+                .map_err(|_| SealedSessionError::Malformed)?;
+            if expiration <= unix_millis(now).saturating_sub(TIMESTAMP_SKEW.as_millis()) {
+                return Err(SealedSessionError::Expired);
+            }
+        }
+
+        if password_id != PASSWORD_ID {
+            return Err(SealedSessionError::Decryption);
+        }
+
+        let base = parts[..6].join("*");
+        let digest = URL_SAFE_NO_PAD
+            .decode(digest)
+            .map_err(|_| SealedSessionError::Malformed)?;
+        self.mac(mac_salt, &base)
+            .verify_slice(&digest)
+            .map_err(|_| SealedSessionError::Decryption)?;
+
+        let iv: [u8; IV_LENGTH] = URL_SAFE_NO_PAD
+            .decode(iv)
+            .ok()
+            .and_then(|iv| iv.try_into().ok())
+            .ok_or(SealedSessionError::Malformed)?;
+        let ciphertext = URL_SAFE_NO_PAD
+            .decode(ciphertext)
+            .map_err(|_| SealedSessionError::Malformed)?;
+        let plaintext =
+            cbc::Decryptor::<Aes256>::new(&self.key(encryption_salt).into(), &iv.into())
+                .decrypt_padded_vec_mut::<Pkcs7>(&ciphertext)
+                .map_err(|_| SealedSessionError::Decryption)?;
+
+        // Sessions sealed before version 2 of iron-session keep their data in `persistent`.
+        match version {
+            Some(SEAL_VERSION) => Ok(serde_json::from_slice(&plaintext)?),
+            _ => {
+                let mut data = serde_json::from_slice::<Value>(&plaintext)?;
+                Ok(serde_json::from_value(data["persistent"].take())?)
+            }
+        }
+    }
+}
+
+/// Returns a random salt, hex-encoded as iron expects.
+fn random_salt() -> Result<String, SealedSessionError> {
+    let mut salt = [0u8; SALT_LENGTH];
+    getrandom::getrandom(&mut salt).map_err(|_| SealedSessionError::Encryption)?;
+
+    Ok(salt.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+fn unix_millis(time: SystemTime) -> u128 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
+impl fmt::Debug for SessionSealer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionSealer").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use super::*;
+
+    const PASSWORD: &str = "kR8sX2vQ9mZ4tL7wN1pB6yH3cF5jD0gA";
+
+    /// A session sealed by iron-session with [`PASSWORD`], as the WorkOS Node SDK seals it.
+    const NODE_SEALED_SESSION: &str = concat!(
+        "Fe26.2*1*aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa*BwcHBw",
+        "cHBwcHBwcHBwcHBw*ynX32ECXy_4dsFCvA57yFF3CiK4hFUGPKgUPXEIMQ2Bsj4zCDt5psAltfTFdyH7",
+        "qbpADHdRnPFva8eQT3JsAK6E9BlnxRAImjTGQrrSmm19-mi3F7S41AVe2Whg5L9dPecJEzGWAr12Zzxt",
+        "j4SoC8wTqemOg6ycYqR9lngSqTZdhicOZ8Z47MTbFd7NDR8PeDUYzv7jZ2qrtwuuY73y4VPjsiXKPPBu",
+        "EpDXOGzJG83apkETcFM_9O88mi7mgtA59HMpEHw4kUBBM-JCld75GW-aEqa8XJKt4osSV63NHH3L_Ogv",
+        "yDiWvey4m6rou280MVsC4Tx_PdV8VtOf5wGEYeu4EA4pGyNIOivC9MJQ7seQJGhNzwH8RldsuAu3SCes",
+        "vQ7a-UziqABDrCdHOhEn-PADpHel_w2FG1uzxQbd3VXEjQyVOwXr9_4fk42z1luQB0C_351Fg96M24Gg",
+        "T-6eb6DSolzn3PDG0kK0DyuLay44iAn_CSuPehAoI_gPcaPWFx9hf0iqpB3zBCUOb1Jntj9MHzE4X9r6",
+        "RVxHLiM3Ge7H2rSA_Fj9fn5LbNZWcnS-a-22SLF2FbeAMzaN0RvuD3191Agr_PZqQ_q_JHINwR70*410",
+        "2444800000*bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb*GzEu",
+        "J-U5GNGg5gs5n3GP_dI9QMs8tpXSciFQw8IAkac~2",
+    );
+
+    fn session() -> SessionData {
+        serde_json::from_value(json!({
+            "accessToken": "eyJhb.access_token",
+            "refreshToken": "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+            "user": {
+                "object": "user",
+                "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "email": "marcelina.davis@example.com",
+                "firstName": "Marcelina",
+                "lastName": "Davis",
+                "emailVerified": true,
+                "profilePictureUrl": null,
+                "lastSignInAt": null,
+                "externalId": null,
+                "metadata": null,
+                "createdAt": "2021-06-25T19:07:33.155Z",
+                "updatedAt": "2021-06-25T19:07:33.155Z"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_round_trips_session_data() {
+        let sealer = SessionSealer::new(PASSWORD).unwrap();
+
+        let sealed = sealer.seal(&session()).unwrap();
+        assert!(sealed.starts_with("Fe26.2*1*"));
+        assert!(sealed.ends_with("~2"));
+        assert!(!sealed.contains("marcelina"));
+
+        let unsealed = sealer.unseal(&sealed).unwrap();
+        assert_eq!(unsealed.user, session().user);
+        assert_eq!(unsealed.refresh_token, session().refresh_token);
+    }
+
+    #[test]
+    fn it_unseals_sessions_sealed_by_iron_session() {
+        let session = SessionSealer::new(PASSWORD)
+            .unwrap()
+            .unseal(NODE_SEALED_SESSION)
+            .unwrap();
+
+        assert_eq!(
+            session.access_token,
+            AccessToken::from("eyJhb.access_token")
+        );
+        assert_eq!(session.user.email, "marcelina.davis@example.com");
+        assert_eq!(session.user.first_name.as_deref(), Some("Marcelina"));
+        assert!(session.user.email_verified);
+        assert_eq!(
+            session.organization_id,
+            Some(OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+        );
+    }
+
+    #[test]
+    fn it_serializes_session_data_with_the_node_sdk_field_names() {
+        let data = serde_json::to_value(session()).unwrap();
+
+        assert_eq!(data["accessToken"], json!("eyJhb.access_token"));
+        assert_eq!(data["user"]["object"], json!("user"));
+        assert_eq!(data["user"]["emailVerified"], json!(true));
+        assert_eq!(data["user"]["firstName"], json!("Marcelina"));
+        assert!(data.get("organizationId").is_none());
+    }
+
+    #[test]
+    fn it_rejects_short_passwords() {
+        assert_matches!(
+            SessionSealer::new("too-short"),
+            Err(SealedSessionError::PasswordTooShort)
+        );
+        // 16 characters, but 32 bytes.
+        assert_matches!(
+            SessionSealer::new("éééééééééééééééé"),
+            Err(SealedSessionError::PasswordTooShort)
+        );
+    }
+
+    #[test]
+    fn it_seals_each_session_with_a_different_salt() {
+        let sealer = SessionSealer::new(PASSWORD).unwrap();
+
+        let first = sealer.seal(&session()).unwrap();
+        let second = sealer.seal(&session()).unwrap();
+
+        assert_ne!(first[..73], second[..73]);
+        assert_eq!(sealer.unseal(&second).unwrap().user, session().user);
+    }
+
+    #[test]
+    fn it_rejects_sessions_sealed_with_a_different_password() {
+        let sealed = SessionSealer::new(PASSWORD)
+            .unwrap()
+            .seal(&session())
+            .unwrap();
+
+        let result = SessionSealer::new("a-different-password-of-32-chars")
+            .unwrap()
+            .unseal(&sealed);
+
+        assert_matches!(result, Err(SealedSessionError::Decryption));
+    }
+
+    #[test]
+    fn it_rejects_tampered_sessions() {
+        let sealer = SessionSealer::new(PASSWORD).unwrap();
+        let sealed = sealer.seal(&session()).unwrap();
+
+        let mut parts = sealed.split('*').map(str::to_string).collect::<Vec<_>>();
+        let replacement = if parts[4].starts_with('A') { "B" } else { "A" };
+        parts[4].replace_range(..1, replacement);
+        let tampered = parts.join("*");
+
+        assert_matches!(
+            sealer.unseal(&tampered),
+            Err(SealedSessionError::Decryption)
+        );
+        assert_matches!(
+            sealer.unseal("not-a-sealed-session"),
+            Err(SealedSessionError::Malformed)
+        );
+    }
+
+    #[test]
+    fn it_rejects_expired_sessions() {
+        let sealer = SessionSealer::new(PASSWORD).unwrap();
+        let sealed = sealer
+            .seal_at(
+                &session(),
+                SystemTime::now() - Duration::from_secs(15 * 24 * 60 * 60),
+            )
+            .unwrap();
+
+        assert_matches!(sealer.unseal(&sealed), Err(SealedSessionError::Expired));
+    }
+}
@@ -0,0 +1,455 @@
+use std::time::Duration;
+
+use jsonwebtoken::errors::ErrorKind;
+use thiserror::Error;
+
+use crate::sso::{AccessToken, ClientId};
+use crate::user_management::{
+    AccessTokenClaims, AuthenticateError, AuthenticateWithRefreshToken,
+    AuthenticateWithRefreshTokenParams, JwksCache, RefreshToken, User, VerifyAccessTokenError,
+};
+use crate::{ErrorClassification, OperationError, WorkOs, WorkOsError, WorkOsResult};
+
+/// The default time the JWKS is cached for.
+const DEFAULT_JWKS_TTL: Duration = Duration::from_secs(300);
+
+/// The default margin before expiry at which access tokens are refreshed.
+const DEFAULT_CLOCK_SKEW: Duration = Duration::from_secs(30);
+
+/// An error returned from [`SessionManager`].
+#[derive(Debug, Error)]
+pub enum SessionError {
+    /// The access token is invalid for a reason other than having expired.
+    #[error(transparent)]
+    InvalidAccessToken(VerifyAccessTokenError),
+
+    /// The access token could not be refreshed, e.g. because the session has ended.
+    #[error(transparent)]
+    Refresh(AuthenticateError),
+}
+
+impl From<SessionError> for WorkOsError<SessionError> {
+    fn from(err: SessionError) -> Self {
+        Self::Operation(err)
+    }
+}
+
+impl OperationError for SessionError {
+    fn classification(&self) -> ErrorClassification {
+        match self {
+            SessionError::InvalidAccessToken(err) => err.classification(),
+            SessionError::Refresh(err) => err.classification(),
+        }
+    }
+}
+
+/// An authenticated session returned from [`SessionManager::authenticate`].
+#[derive(Clone, Debug)]
+pub struct AuthenticatedSession {
+    /// The claims of the current access token.
+    pub claims: AccessTokenClaims,
+
+    /// The current access token.
+    pub access_token: AccessToken,
+
+    /// The current refresh token.
+    pub refresh_token: RefreshToken,
+
+    /// Whether the tokens were refreshed, in which case the new tokens should be stored.
+    pub refreshed: bool,
+
+    /// The up-to-date user, if the tokens were refreshed.
+    pub user: Option<User>,
+}
+
+/// Authenticates sessions from an access and refresh token pair, refreshing the access
+/// token when it has expired.
+///
+/// Access tokens are verified locally against a cached JWKS. When an access token has
+/// expired, or will expire within the configured clock skew, the refresh token is
+/// exchanged for new tokens with [`AuthenticateWithRefreshToken`].
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::WorkOsResult;
+/// # use workos_sdk::sso::{AccessToken, ClientId};
+/// # use workos_sdk::user_management::*;
+/// use workos_sdk::{ApiKey, WorkOs};
+///
+/// # async fn run() -> WorkOsResult<(), SessionError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+/// let sessions = SessionManager::new(&workos, &ClientId::from("client_123456789"));
+///
+/// let session = sessions
+///     .authenticate(
+///         &AccessToken::from("eyJhbGciOiJSUzI1NiJ9..."),
+///         &RefreshToken::from("Xw0NsCVXMBf7svAoIoKBmkpEK"),
+///     )
+///     .await?;
+///
+/// if session.refreshed {
+///     // Store `session.access_token` and `session.refresh_token`.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct SessionManager {
+    workos: WorkOs,
+    jwks: JwksCache,
+    issuer: Option<String>,
+    clock_skew: Duration,
+}
+
+impl SessionManager {
+    /// Returns a new [`SessionManager`] for the provided client.
+    pub fn new(workos: &WorkOs, client_id: &ClientId) -> Self {
+        Self {
+            workos: workos.clone(),
+            jwks: JwksCache::new(workos, client_id, DEFAULT_JWKS_TTL),
+            issuer: None,
+            clock_skew: DEFAULT_CLOCK_SKEW,
+        }
+    }
+
+    /// Sets how long before it expires an access token is refreshed.
+    ///
+    /// Defaults to 30 seconds.
+    pub fn clock_skew(mut self, clock_skew: Duration) -> Self {
+        self.clock_skew = clock_skew;
+        self
+    }
+
+    /// Sets how long the JWKS used to verify access tokens is cached for.
+    ///
+    /// Defaults to 5 minutes.
+    pub fn jwks_ttl(mut self, ttl: Duration) -> Self {
+        self.jwks = JwksCache::new(&self.workos, self.jwks.client_id(), ttl);
+        if let Some(issuer) = &self.issuer {
+            self.jwks = self.jwks.issuer(issuer);
+        }
+        self
+    }
+
+    /// Only accepts access tokens whose `iss` claim is the provided issuer, such as a custom
+    /// authentication domain, instead of the User Management issuer of the client ID.
+    pub fn issuer(mut self, issuer: impl Into<String>) -> Self {
+        let issuer = issuer.into();
+        self.jwks = self.jwks.issuer(&issuer);
+        self.issuer = Some(issuer);
+        self
+    }
+
+    /// Returns the client ID sessions are authenticated for.
+    pub fn client_id(&self) -> &ClientId {
+        self.jwks.client_id()
+    }
+
+    /// Verifies the access token, refreshing the session if the access token has expired.
+    pub async fn authenticate(
+        &self,
+        access_token: &AccessToken,
+        refresh_token: &RefreshToken,
+    ) -> WorkOsResult<AuthenticatedSession, SessionError> {
+        match self.verify(access_token).await {
+            Ok(claims) if !self.is_expiring(&claims) => {
+                return Ok(AuthenticatedSession {
+                    claims,
+                    access_token: access_token.clone(),
+                    refresh_token: refresh_token.clone(),
+                    refreshed: false,
+                    user: None,
+                });
+            }
+            Ok(_) => {}
+            Err(WorkOsError::Operation(SessionError::InvalidAccessToken(
+                VerifyAccessTokenError::InvalidToken(err),
+            ))) if matches!(err.kind(), ErrorKind::ExpiredSignature) => {}
+            Err(err) => return Err(err),
+        }
+
+        self.refresh(refresh_token).await
+    }
+
+    /// Exchanges the refresh token for new tokens, regardless of whether the current
+    /// access token has expired.
+    pub async fn refresh(
+        &self,
+        refresh_token: &RefreshToken,
+    ) -> WorkOsResult<AuthenticatedSession, SessionError> {
+        let response = self
+            .workos
+            .user_management()
+            .authenticate_with_refresh_token(&AuthenticateWithRefreshTokenParams {
+                client_id: self.client_id(),
+                refresh_token,
+                organization_id: None,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+            .map_err(|err| err.map_operation(SessionError::Refresh))?;
+
+        let claims = self.verify(&response.access_token).await?;
+
+        Ok(AuthenticatedSession {
+            claims,
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            refreshed: true,
+            user: Some(response.user),
+        })
+    }
+
+    /// Verifies the access token without refreshing it, e.g. for bearer tokens that are not
+    /// accompanied by a refresh token.
+    pub async fn verify(
+        &self,
+        access_token: &AccessToken,
+    ) -> WorkOsResult<AccessTokenClaims, SessionError> {
+        self.jwks
+            .verify(access_token.expose_secret())
+            .await
+            .map(|token| token.claims)
+            .map_err(|err| err.map_operation(SessionError::InvalidAccessToken))
+    }
+
+    fn is_expiring(&self, claims: &AccessTokenClaims) -> bool {
+        let skew = i64::try_from(self.clock_skew.as_secs()).unwrap_or(i64::MAX);
+
+        claims.exp.saturating_sub(skew) <= chrono::Utc::now().timestamp()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+
+    use crate::user_management::access_token_verifier::test::{issued_by, jwks, sign};
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn refresh_response(access_token: &str) -> String {
+        json!({
+            "user": {
+                "object": "user",
+                "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "email": "marcelina.davis@example.com",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "email_verified": true,
+                "profile_picture_url": null,
+                "metadata": {},
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+            },
+            "organization_id": null,
+            "access_token": access_token,
+            "refresh_token": "refresh_token_2",
+            "authentication_method": "Password",
+            "impersonator": null
+        })
+        .to_string()
+    }
+
+    async fn setup(server: &mut mockito::Server) -> SessionManager {
+        server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .create_async()
+            .await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        SessionManager::new(&workos, &ClientId::from("client_456"))
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_session_when_the_access_token_is_valid() {
+        let mut server = mockito::Server::new_async().await;
+        let sessions = setup(&mut server).await;
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let access_token = AccessToken::from(sign(issued_by(&server, json!({}))));
+        let session = sessions
+            .authenticate(&access_token, &RefreshToken::from("refresh_token_1"))
+            .await
+            .unwrap();
+
+        assert!(!session.refreshed);
+        assert_eq!(session.access_token, access_token);
+        assert_eq!(session.refresh_token, RefreshToken::from("refresh_token_1"));
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_refreshes_the_session_when_the_access_token_has_expired() {
+        let mut server = mockito::Server::new_async().await;
+        let sessions = setup(&mut server).await;
+
+        let now = chrono::Utc::now().timestamp();
+        let new_access_token = sign(issued_by(&server, json!({ "jti": "refreshed" })));
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::PartialJson(json!({
+                "client_id": "client_456",
+                "grant_type": "refresh_token",
+                "refresh_token": "refresh_token_1",
+            })))
+            .with_status(200)
+            .with_body(refresh_response(&new_access_token))
+            .create_async()
+            .await;
+
+        let session = sessions
+            .authenticate(
+                &AccessToken::from(sign(issued_by(&server, json!({ "exp": now - 600 })))),
+                &RefreshToken::from("refresh_token_1"),
+            )
+            .await
+            .unwrap();
+
+        assert!(session.refreshed);
+        assert_eq!(session.user.unwrap().email, "marcelina.davis@example.com");
+        assert_eq!(session.access_token, AccessToken::from(new_access_token));
+        assert_eq!(session.refresh_token, RefreshToken::from("refresh_token_2"));
+        assert_eq!(session.claims.jti.as_deref(), Some("refreshed"));
+    }
+
+    #[tokio::test]
+    async fn it_refreshes_the_session_when_the_access_token_expires_within_the_clock_skew() {
+        let mut server = mockito::Server::new_async().await;
+        let sessions = setup(&mut server)
+            .await
+            .clock_skew(Duration::from_secs(120));
+
+        let now = chrono::Utc::now().timestamp();
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(refresh_response(&sign(issued_by(&server, json!({})))))
+            .create_async()
+            .await;
+
+        let session = sessions
+            .authenticate(
+                &AccessToken::from(sign(issued_by(&server, json!({ "exp": now + 60 })))),
+                &RefreshToken::from("refresh_token_1"),
+            )
+            .await
+            .unwrap();
+
+        assert!(session.refreshed);
+    }
+
+    #[tokio::test]
+    async fn it_does_not_refresh_sessions_with_an_invalid_access_token() {
+        let mut server = mockito::Server::new_async().await;
+        let sessions = setup(&mut server).await;
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let result = sessions
+            .authenticate(
+                &AccessToken::from("not-a-jwt"),
+                &RefreshToken::from("refresh_token_1"),
+            )
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(SessionError::InvalidAccessToken(_)))
+        );
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_refresh_fails() {
+        let mut server = mockito::Server::new_async().await;
+        let sessions = setup(&mut server).await;
+
+        let now = chrono::Utc::now().timestamp();
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "invalid_grant",
+                    "error_description": "Session has already ended."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let result = sessions
+            .authenticate(
+                &AccessToken::from(sign(issued_by(&server, json!({ "exp": now - 600 })))),
+                &RefreshToken::from("refresh_token_1"),
+            )
+            .await;
+
+        assert_matches!(
+            result,
+            Err(WorkOsError::Operation(SessionError::Refresh(_)))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_limits_jwks_refetches_for_tokens_signed_with_an_unknown_key() {
+        let mut server = mockito::Server::new_async().await;
+
+        let jwks = server
+            .mock("GET", "/sso/jwks/client_456")
+            .with_status(200)
+            .with_body(serde_json::to_string(&jwks()).unwrap())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build()
+            .unwrap();
+        let sessions = SessionManager::new(&workos, &ClientId::from("client_456"));
+
+        let token = sign(issued_by(&server, json!({})));
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT","kid":"key_unknown"}"#);
+        let unknown_key =
+            AccessToken::from(format!("{header}.{}", token.split_once('.').unwrap().1));
+
+        for _ in 0..3 {
+            assert_matches!(
+                sessions.verify(&unknown_key).await,
+                Err(WorkOsError::Operation(SessionError::InvalidAccessToken(
+                    VerifyAccessTokenError::KeyNotFound(_)
+                )))
+            );
+        }
+
+        jwks.assert_async().await;
+    }
+}
@@ -5,15 +5,19 @@ mod email_verification;
 mod external_id;
 mod identity;
 mod impersonator;
+mod invitation;
 mod magic_auth;
 mod organization_membership;
 mod password;
 mod password_reset;
+#[cfg(feature = "password-strength")]
+mod password_strength;
 mod pending_authentication_token;
-mod provider;
 mod refresh_token;
+mod session;
 mod session_id;
 mod user;
+mod validation;
 
 pub use authenticate_error::*;
 pub use authenticate_methods::*;
@@ -22,12 +26,19 @@ pub use email_verification::*;
 pub use external_id::*;
 pub use identity::*;
 pub use impersonator::*;
+pub use invitation::*;
 pub use magic_auth::*;
 pub use organization_membership::*;
 pub use password::*;
 pub use password_reset::*;
+#[cfg(feature = "password-strength")]
+pub use password_strength::*;
 pub use pending_authentication_token::*;
-pub use provider::*;
 pub use refresh_token::*;
+pub use session::*;
 pub use session_id::*;
 pub use user::*;
+pub use validation::*;
+
+// Shared with SSO, and re-exported so that the `user_management` paths keep working.
+pub use crate::sso::{CodeChallenge, OauthProvider};
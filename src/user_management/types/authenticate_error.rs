@@ -5,11 +5,14 @@ use thiserror::Error;
 
 use crate::core::response_to_request_error;
 use crate::{
-    WorkOsError, WorkOsResult, mfa::AuthenticationFactorIdAndType,
+    OperationError, ResponseExt, WorkOsError, WorkOsResult, mfa::AuthenticationFactorIdAndType,
     organizations::OrganizationIdAndName, sso::ConnectionId,
 };
 
-use super::{AuthenticateMethods, EmailVerificationId, PendingAuthenticationToken, User};
+use super::{
+    AuthenticateMethods, AuthenticationResponse, EmailVerificationId, PendingAuthenticationToken,
+    User,
+};
 
 /// An error returned from authenticate requests.
 #[derive(Debug, Deserialize, Error)]
@@ -23,6 +26,18 @@ pub enum AuthenticateError {
     WithError(AuthenticateErrorWithError),
 }
 
+impl AuthenticateError {
+    /// The string constant that distinguishes the error type.
+    pub fn code(&self) -> &str {
+        match self {
+            AuthenticateError::WithCode(error) => error.code(),
+            AuthenticateError::WithError(error) => error.error(),
+        }
+    }
+}
+
+impl OperationError for AuthenticateError {}
+
 /// An error returned from authenticate requests tagged with a `code` field.
 #[derive(Debug, Deserialize, Error)]
 #[serde(tag = "code", rename_all = "snake_case")]
@@ -138,7 +153,7 @@ impl AuthenticateErrorWithCode {
     pub fn code(&self) -> &str {
         match self {
             AuthenticateErrorWithCode::EmailVerificationRequired { .. } => {
-                "email_verification_requried"
+                "email_verification_required"
             }
             AuthenticateErrorWithCode::InvalidCredentials { .. } => "invalid_credentials",
             AuthenticateErrorWithCode::InvalidOneTimeCode { .. } => "invalid_one_time_code",
@@ -251,7 +266,72 @@ impl AuthenticateErrorWithError {
     }
 }
 
-#[async_trait]
+/// Records the outcome of an authenticate request for login observability.
+#[cfg(feature = "tracing")]
+pub(crate) fn log_authentication_outcome(
+    method: &str,
+    result: &WorkOsResult<AuthenticationResponse, AuthenticateError>,
+) {
+    match result {
+        Ok(response) => tracing::info!(
+            target: "workos_sdk::authentication",
+            method,
+            outcome = "success",
+            user_id = tracing::field::display(&response.user.id),
+            organization_id = response.organization_id.as_deref().map(String::as_str),
+            "authentication succeeded"
+        ),
+        Err(err) => tracing::warn!(
+            target: "workos_sdk::authentication",
+            method,
+            outcome = "failure",
+            code = authentication_failure_code(err),
+            "authentication failed"
+        ),
+    }
+}
+
+/// Records the outcome of an authenticate request for login observability.
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+pub(crate) fn log_authentication_outcome(
+    method: &str,
+    result: &WorkOsResult<AuthenticationResponse, AuthenticateError>,
+) {
+    match result {
+        Ok(response) => log::info!(
+            target: "workos_sdk::authentication",
+            "authentication succeeded method={method} outcome=success user_id={} organization_id={:?}",
+            response.user.id,
+            response.organization_id.as_deref().map(String::as_str)
+        ),
+        Err(err) => log::warn!(
+            target: "workos_sdk::authentication",
+            "authentication failed method={method} outcome=failure code={}",
+            authentication_failure_code(err)
+        ),
+    }
+}
+
+#[cfg(not(any(feature = "tracing", feature = "log")))]
+pub(crate) fn log_authentication_outcome(
+    method: &str,
+    result: &WorkOsResult<AuthenticationResponse, AuthenticateError>,
+) {
+    let _ = (method, result);
+}
+
+#[cfg(any(feature = "tracing", feature = "log"))]
+fn authentication_failure_code(err: &WorkOsError<AuthenticateError>) -> &str {
+    match err {
+        WorkOsError::Operation(error) => error.code(),
+        WorkOsError::Unauthorized => "unauthorized",
+        WorkOsError::RateLimited { .. } => "rate_limited",
+        _ => "request_error",
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub(crate) trait HandleAuthenticateError
 where
     Self: Sized,
@@ -259,7 +339,8 @@ where
     async fn handle_authenticate_error(self) -> WorkOsResult<Self, AuthenticateError>;
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl HandleAuthenticateError for Response {
     async fn handle_authenticate_error(self) -> WorkOsResult<Self, AuthenticateError> {
         if self.status().is_success() {
@@ -268,7 +349,7 @@ impl HandleAuthenticateError for Response {
 
         match self.status() {
             StatusCode::BAD_REQUEST => {
-                let authenticate_error = self.json::<AuthenticateError>().await?;
+                let authenticate_error = self.decode_json::<AuthenticateError>().await?;
 
                 Err(match &authenticate_error {
                     AuthenticateError::WithError(AuthenticateErrorWithError::Other {
@@ -282,7 +363,7 @@ impl HandleAuthenticateError for Response {
                 })
             }
             StatusCode::FORBIDDEN => {
-                let authenticate_error = self.json::<AuthenticateError>().await?;
+                let authenticate_error = self.decode_json::<AuthenticateError>().await?;
 
                 Err(WorkOsError::Operation(authenticate_error))
             }
@@ -290,3 +371,38 @@ impl HandleAuthenticateError for Response {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn email_verification_required() -> AuthenticateError {
+        serde_json::from_value(json!({
+            "code": "email_verification_required",
+            "message": "Email ownership must be verified before authentication.",
+            "pending_authentication_token": "YQyCkYfuVw2mI3tzSrk2C1Y7S",
+            "email": "marcelina.davis@example.com",
+            "email_verification_id": "email_verification_01HYGGEB6FYMWQNWF3XDZG7VV3"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_returns_the_code_the_api_uses_for_email_verification_required() {
+        assert_eq!(
+            email_verification_required().code(),
+            "email_verification_required"
+        )
+    }
+
+    #[cfg(any(feature = "tracing", feature = "log"))]
+    #[test]
+    fn it_logs_the_code_the_api_uses_for_email_verification_required() {
+        assert_eq!(
+            authentication_failure_code(&WorkOsError::Operation(email_verification_required())),
+            "email_verification_required"
+        )
+    }
+}
@@ -11,7 +11,7 @@ use super::OauthProvider;
 pub struct IdentityId(String);
 
 /// The type of the identity.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum IdentityType {
     /// OAuth identity.
@@ -0,0 +1,122 @@
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::organizations::OrganizationId;
+use crate::roles::RoleSlug;
+use crate::user_management::UserId;
+use crate::{KnownOrUnknown, SecretString, Timestamp, Timestamps};
+
+/// The ID of an [`Invitation`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct InvitationId(String);
+
+/// The token of an [`Invitation`], used to accept it when authenticating.
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize, Deserialize)]
+#[from(forward)]
+pub struct InvitationToken(SecretString);
+
+/// The state of an [`Invitation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationState {
+    /// The invitation has been sent and may be accepted.
+    Pending,
+
+    /// The invitation has been accepted.
+    Accepted,
+
+    /// The invitation expired before it was accepted.
+    Expired,
+
+    /// The invitation was revoked.
+    Revoked,
+}
+
+/// [WorkOS Docs: Invitation](https://workos.com/docs/reference/user-management/invitation)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Invitation {
+    /// The unique ID of the invitation.
+    pub id: InvitationId,
+
+    /// The email address of the recipient.
+    pub email: String,
+
+    /// The state of the invitation.
+    pub state: KnownOrUnknown<InvitationState, String>,
+
+    /// The timestamp when the invitation was accepted, if it has been.
+    pub accepted_at: Option<Timestamp>,
+
+    /// The timestamp when the invitation was revoked, if it has been.
+    pub revoked_at: Option<Timestamp>,
+
+    /// The timestamp when the invitation expires.
+    pub expires_at: Timestamp,
+
+    /// The token used to accept the invitation.
+    pub token: InvitationToken,
+
+    /// The URL where the recipient can accept the invitation.
+    pub accept_invitation_url: Url,
+
+    /// The ID of the organization the recipient will join, if any.
+    pub organization_id: Option<OrganizationId>,
+
+    /// The ID of the user who sent the invitation, if any.
+    pub inviter_user_id: Option<UserId>,
+
+    /// The slug of the role the recipient will receive when they join the organization,
+    /// if one was specified.
+    pub role_slug: Option<RoleSlug>,
+
+    /// The timestamps for the invitation.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_an_invitation() {
+        let invitation: Invitation = serde_json::from_str(
+            &json!({
+                "object": "invitation",
+                "id": "invitation_01E4ZCR3C56J083X43JQXF3JK5",
+                "email": "marcelina.davis@example.com",
+                "state": "pending",
+                "accepted_at": null,
+                "revoked_at": null,
+                "expires_at": "2021-07-01T19:07:33.155Z",
+                "token": "Z1uX3RbwcIl5fIGJJJCXXisdI",
+                "accept_invitation_url": "https://your-app.com/invite?invitation_token=Z1uX3RbwcIl5fIGJJJCXXisdI",
+                "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+                "inviter_user_id": "user_01HYGBX8ZGD19949T3BM4FW1C3",
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            invitation.id,
+            InvitationId::from("invitation_01E4ZCR3C56J083X43JQXF3JK5")
+        );
+        assert_eq!(
+            invitation.state,
+            KnownOrUnknown::Known(InvitationState::Pending)
+        );
+        assert_eq!(
+            invitation.token,
+            InvitationToken::from("Z1uX3RbwcIl5fIGJJJCXXisdI")
+        );
+    }
+}
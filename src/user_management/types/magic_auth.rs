@@ -1,7 +1,12 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
-use crate::{Timestamp, Timestamps};
+use crate::core::validate_one_time_code;
+use crate::{InvalidOneTimeCode, SecretString, Timestamp, Timestamps};
 
 use super::UserId;
 
@@ -13,11 +18,22 @@ use super::UserId;
 pub struct MagicAuthId(String);
 
 /// The one-time code that was emailed to the user.
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+///
+/// Codes compare in constant time. Use [`str::parse`] to validate the format of a code
+/// entered by a user.
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct MagicAuthCode(String);
+pub struct MagicAuthCode(SecretString);
+
+impl FromStr for MagicAuthCode {
+    type Err = InvalidOneTimeCode;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        validate_one_time_code(code)?;
+
+        Ok(Self::from(code))
+    }
+}
 
 /// [WorkOS Docs: Magic Auth](https://workos.com/docs/reference/user-management/magic-auth)
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -41,3 +57,61 @@ pub struct MagicAuth {
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
+
+impl MagicAuth {
+    /// Returns whether the Magic Auth code has expired as of the provided time.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.has_passed(now)
+    }
+
+    /// Returns the time remaining until the Magic Auth code expires.
+    ///
+    /// Returns [`None`] if the Magic Auth code has already expired.
+    pub fn expires_in(&self) -> Option<Duration> {
+        self.expires_at.remaining(Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+
+    use super::{MagicAuth, MagicAuthCode};
+    use crate::InvalidOneTimeCode;
+
+    #[test]
+    fn it_is_expired_once_the_expiry_has_passed() {
+        let magic_auth: MagicAuth = serde_json::from_value(json!({
+            "id": "magic_auth_01HWXBEKR6EGW9W5MXCG2HMN9W",
+            "user_id": "user_01HWWYEH2NPT48X82ZT23K5AX4",
+            "email": "marcelina.davis@example.com",
+            "expires_at": "2021-07-01T19:07:33.155Z",
+            "code": "123456",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        }))
+        .unwrap();
+        let now = |value| {
+            DateTime::parse_from_rfc3339(value)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        assert!(!magic_auth.is_expired(now("2021-07-01T19:07:33.154Z")));
+        assert!(magic_auth.is_expired(now("2021-07-01T19:07:33.155Z")));
+        assert_eq!(magic_auth.expires_in(), None);
+    }
+
+    #[test]
+    fn it_parses_a_well_formed_code() {
+        assert_eq!(
+            "123456".parse::<MagicAuthCode>(),
+            Ok(MagicAuthCode::from("123456"))
+        );
+        assert_eq!(
+            "12a456".parse::<MagicAuthCode>(),
+            Err(InvalidOneTimeCode::InvalidCharacter)
+        );
+    }
+}
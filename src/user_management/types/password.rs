@@ -30,6 +30,9 @@ pub enum PasswordParams<'a> {
         password: &'a str,
     },
     /// Password hash.
+    ///
+    /// Used to migrate users from another system without requiring them to reset their
+    /// passwords.
     PasswordHash {
         /// The hashed password to set for the user.
         password_hash: &'a str,
@@ -1,8 +1,11 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
-use crate::Timestamp;
+use crate::{SecretString, Timestamp};
 
 use super::UserId;
 
@@ -14,11 +17,9 @@ use super::UserId;
 pub struct PasswordResetId(String);
 
 /// The one-time token that can be used to reset a user's password.
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct PasswordResetToken(String);
+pub struct PasswordResetToken(SecretString);
 
 /// [WorkOS Docs: Password Reset](https://workos.com/docs/reference/user-management/password-reset)
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -44,3 +45,71 @@ pub struct PasswordReset {
     /// The timestamp indicating when the object was created.
     pub created_at: Timestamp,
 }
+
+impl PasswordReset {
+    /// Returns whether the password reset token has expired as of the provided time.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.has_passed(now)
+    }
+
+    /// Returns the time remaining until the password reset token expires.
+    ///
+    /// Returns [`None`] if the password reset token has already expired.
+    pub fn expires_in(&self) -> Option<Duration> {
+        self.expires_at.remaining(Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Utc};
+    use serde_json::json;
+
+    use super::{PasswordReset, PasswordResetToken};
+
+    #[test]
+    fn it_parses_typed_fields() {
+        let password_reset: PasswordReset = serde_json::from_value(json!({
+            "id": "password_reset_01HYGDNK5G7FZ4YJFXYXPB5JRW",
+            "user_id": "user_01HWWYEH2NPT48X82ZT23K5AX4",
+            "email": "marcelina.davis@example.com",
+            "password_reset_token": "Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "password_reset_url": "https://your-app.com/reset-password?token=Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "expires_at": "2021-07-01T19:07:33.155Z",
+            "created_at": "2021-06-25T19:07:33.155Z"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            password_reset.password_reset_token,
+            PasswordResetToken::from("Z1uX3RbwcIl5fIGJJJCXXisdI")
+        );
+        assert_eq!(
+            password_reset.password_reset_url.query(),
+            Some("token=Z1uX3RbwcIl5fIGJJJCXXisdI")
+        );
+        assert!(
+            password_reset.is_expired(
+                DateTime::parse_from_rfc3339("2021-07-01T19:07:33.155Z")
+                    .unwrap()
+                    .with_timezone(&Utc)
+            )
+        );
+        assert_eq!(password_reset.expires_in(), None);
+    }
+
+    #[test]
+    fn it_rejects_a_malformed_password_reset_url() {
+        let result = serde_json::from_value::<PasswordReset>(json!({
+            "id": "password_reset_01HYGDNK5G7FZ4YJFXYXPB5JRW",
+            "user_id": "user_01HWWYEH2NPT48X82ZT23K5AX4",
+            "email": "marcelina.davis@example.com",
+            "password_reset_token": "Z1uX3RbwcIl5fIGJJJCXXisdI",
+            "password_reset_url": "not a url",
+            "expires_at": "2021-07-01T19:07:33.155Z",
+            "created_at": "2021-06-25T19:07:33.155Z"
+        }));
+
+        assert!(result.is_err())
+    }
+}
@@ -0,0 +1,263 @@
+use crate::user_management::PasswordResetError;
+
+/// The minimum [`PasswordStrength::score`] a password must reach to be considered acceptable.
+pub const MIN_PASSWORD_STRENGTH_SCORE: u8 = 3;
+
+/// Passwords that are rejected regardless of their length or composition.
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456789",
+    "12345678",
+    "1234567890",
+    "1q2w3e4r",
+    "abc12345",
+    "baseball",
+    "dragon123",
+    "football",
+    "iloveyou",
+    "letmein123",
+    "monkey123",
+    "password",
+    "password1",
+    "password123",
+    "passw0rd",
+    "princess",
+    "qwerty",
+    "qwerty123",
+    "qwertyuiop",
+    "sunshine",
+    "superman",
+    "trustno1",
+    "welcome1",
+    "welcome123",
+];
+
+/// An estimate of the strength of a password.
+///
+/// The estimate follows the shape of the WorkOS `password_too_weak` error, so that
+/// feedback can be shown to users before a request is made.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PasswordStrength {
+    /// A score from 0 (very weak) to 4 (very strong).
+    pub score: u8,
+
+    /// A human-readable warning explaining what makes the password weak, if anything.
+    pub warning: String,
+
+    /// Human-readable suggestions for choosing a stronger password.
+    pub suggestions: Vec<String>,
+}
+
+impl PasswordStrength {
+    /// Returns whether the password is strong enough to be accepted.
+    pub fn is_acceptable(&self) -> bool {
+        self.score >= MIN_PASSWORD_STRENGTH_SCORE
+    }
+
+    /// Converts the estimate into the equivalent WorkOS `password_too_weak` error.
+    pub fn into_error(self) -> PasswordResetError {
+        PasswordResetError::PasswordTooWeak {
+            message: "Password does not meet strength requirements.".to_string(),
+            suggestions: self.suggestions,
+            warning: self.warning,
+        }
+    }
+}
+
+/// Estimates the strength of a password.
+///
+/// `user_inputs` should contain values related to the user, such as their email address and
+/// name, which are penalized when they appear in the password.
+///
+/// This is a lightweight approximation of the estimate performed by WorkOS and may not agree
+/// with it for every password. It is advisory only: it is intended for instant client-side
+/// feedback and is never used by local validation, so it cannot reject a password that the
+/// API would accept.
+///
+/// # Examples
+///
+/// ```
+/// use workos_sdk::user_management::estimate_password_strength;
+///
+/// let strength = estimate_password_strength("password123", &[]);
+///
+/// assert!(!strength.is_acceptable());
+/// assert_eq!(strength.warning, "This is a very common password.");
+/// ```
+pub fn estimate_password_strength(password: &str, user_inputs: &[&str]) -> PasswordStrength {
+    let lowercase = password.to_lowercase();
+    let mut suggestions = Vec::new();
+
+    let stripped = lowercase.trim_end_matches(|c: char| c.is_ascii_digit() || c == '!');
+    if COMMON_PASSWORDS.contains(&lowercase.as_str()) || COMMON_PASSWORDS.contains(&stripped) {
+        suggestions.push("Add another word or two. Uncommon words are better.".to_string());
+        return weak(0, "This is a very common password.", suggestions);
+    }
+
+    let chars = lowercase.chars().collect::<Vec<_>>();
+
+    if chars.windows(2).all(|pair| pair[0] == pair[1]) {
+        suggestions.push("Avoid repeated words and characters.".to_string());
+        return weak(
+            0,
+            "Repeated characters like \"aaa\" are easy to guess.",
+            suggestions,
+        );
+    }
+
+    if chars.len() > 2 && is_sequence(&chars) {
+        suggestions.push("Avoid sequences.".to_string());
+        return weak(
+            0,
+            "Sequences like abc or 6543 are easy to guess.",
+            suggestions,
+        );
+    }
+
+    let mut score = score_for_entropy(password);
+    let mut warning = String::new();
+
+    let contains_user_input = user_inputs
+        .iter()
+        .flat_map(|input| input.split(['@', '.', ' ', '_', '-', '+']))
+        .filter(|part| part.chars().count() >= 3)
+        .any(|part| lowercase.contains(&part.to_lowercase()));
+    if contains_user_input {
+        score = score.min(1);
+        warning = "Avoid using personal information such as your name or email.".to_string();
+        suggestions.push("Avoid words and names that are related to you.".to_string());
+    }
+
+    if score < MIN_PASSWORD_STRENGTH_SCORE {
+        suggestions.push("Add another word or two. Uncommon words are better.".to_string());
+
+        if character_classes(password) < 3 {
+            suggestions.push("Use a mix of letters, numbers, and symbols.".to_string());
+        }
+    }
+
+    PasswordStrength {
+        score,
+        warning,
+        suggestions,
+    }
+}
+
+fn weak(score: u8, warning: &str, suggestions: Vec<String>) -> PasswordStrength {
+    PasswordStrength {
+        score,
+        warning: warning.to_string(),
+        suggestions,
+    }
+}
+
+/// Returns whether each character follows the previous one in ascending or descending order.
+fn is_sequence(chars: &[char]) -> bool {
+    let step = |pair: &[char]| pair[1] as i64 - pair[0] as i64;
+    let first = step(&chars[..2]);
+
+    first.abs() == 1 && chars.windows(2).all(|pair| step(pair) == first)
+}
+
+/// Returns the number of character classes (lowercase, uppercase, digits, symbols) used.
+fn character_classes(password: &str) -> usize {
+    [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|present| *present)
+    .count()
+}
+
+/// Scores a password based on the entropy of a brute-force search over its character set.
+fn score_for_entropy(password: &str) -> u8 {
+    let mut charset = 0;
+    if password.chars().any(|c| c.is_lowercase()) {
+        charset += 26;
+    }
+    if password.chars().any(|c| c.is_uppercase()) {
+        charset += 26;
+    }
+    if password.chars().any(|c| c.is_ascii_digit()) {
+        charset += 10;
+    }
+    if password.chars().any(|c| !c.is_alphanumeric()) {
+        charset += 33;
+    }
+
+    // Discount repeated characters, which add little to the search space.
+    let mut unique = password.chars().collect::<Vec<_>>();
+    unique.sort_unstable();
+    unique.dedup();
+    let effective_length = (password.chars().count() + unique.len()) as f64 / 2.0;
+
+    let bits = effective_length * f64::from(charset.max(1)).log2();
+
+    match bits {
+        bits if bits < 20.0 => 0,
+        bits if bits < 30.0 => 1,
+        bits if bits < 40.0 => 2,
+        bits if bits < 55.0 => 3,
+        _ => 4,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_rejects_common_passwords() {
+        for password in ["password", "Password123", "qwerty123!"] {
+            let strength = estimate_password_strength(password, &[]);
+
+            assert_eq!(strength.score, 0, "{password}");
+            assert_eq!(strength.warning, "This is a very common password.");
+        }
+    }
+
+    #[test]
+    fn it_rejects_repeats_and_sequences() {
+        assert_eq!(estimate_password_strength("aaaaaaaaaa", &[]).score, 0);
+        assert_eq!(estimate_password_strength("abcdefghij", &[]).score, 0);
+        assert_eq!(estimate_password_strength("9876543210", &[]).score, 0);
+    }
+
+    #[test]
+    fn it_penalizes_personal_information() {
+        let strength =
+            estimate_password_strength("Marcelina!2024xyz", &["marcelina.davis@example.com"]);
+
+        assert!(!strength.is_acceptable());
+        assert_eq!(
+            strength.warning,
+            "Avoid using personal information such as your name or email."
+        );
+    }
+
+    #[test]
+    fn it_accepts_strong_passwords() {
+        let strength = estimate_password_strength("i8uv6g34kd490s", &["marcelina@example.com"]);
+
+        assert!(strength.is_acceptable());
+        assert_eq!(strength.warning, "");
+        assert!(strength.suggestions.is_empty());
+    }
+
+    #[test]
+    fn it_converts_into_the_api_error_structure() {
+        let error = estimate_password_strength("password", &[]).into_error();
+
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({
+                "code": "password_too_weak",
+                "message": "Password does not meet strength requirements.",
+                "warning": "This is a very common password.",
+                "suggestions": ["Add another word or two. Uncommon words are better."]
+            })
+        );
+    }
+}
@@ -1,9 +1,9 @@
-use derive_more::{Deref, Display, From};
+use derive_more::{Deref, From};
 use serde::{Deserialize, Serialize};
 
+use crate::SecretString;
+
 /// The authentication token returned from a failed authentication attempt due to the corresponding error.
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct PendingAuthenticationToken(String);
+pub struct PendingAuthenticationToken(SecretString);
@@ -1,9 +1,9 @@
-use derive_more::{Deref, Display, From};
+use derive_more::{Deref, From};
 use serde::{Deserialize, Serialize};
 
+use crate::SecretString;
+
 /// A refresh token that may be exchanged for a new [`AccessToken`](crate::sso::AccessToken).
-#[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
-)]
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize, Deserialize)]
 #[from(forward)]
-pub struct RefreshToken(String);
+pub struct RefreshToken(SecretString);
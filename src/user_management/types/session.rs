@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::organizations::OrganizationId;
+use crate::{KnownOrUnknown, Timestamp, Timestamps};
+
+use super::{Impersonator, SessionId, UserId};
+
+/// The status of a [`Session`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionStatus {
+    /// The session is active.
+    Active,
+
+    /// The session expired.
+    Expired,
+
+    /// The session was revoked.
+    Revoked,
+}
+
+/// [WorkOS Docs: Session](https://workos.com/docs/reference/user-management/session)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Session {
+    /// The unique ID of the session.
+    pub id: SessionId,
+
+    /// The ID of the user the session belongs to.
+    pub user_id: UserId,
+
+    /// The ID of the organization the session is scoped to, if any.
+    pub organization_id: Option<OrganizationId>,
+
+    /// The IP address the session was created from.
+    pub ip_address: Option<String>,
+
+    /// The user agent the session was created from.
+    pub user_agent: Option<String>,
+
+    /// The impersonator, if the session was created by impersonating the user.
+    pub impersonator: Option<Impersonator>,
+
+    /// The status of the session.
+    pub status: KnownOrUnknown<SessionStatus, String>,
+
+    /// The timestamp when the session expires.
+    pub expires_at: Timestamp,
+
+    /// The timestamp when the session ended, if it has.
+    pub ended_at: Option<Timestamp>,
+
+    /// The timestamps for the session.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
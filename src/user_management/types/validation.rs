@@ -0,0 +1,77 @@
+use thiserror::Error;
+
+use super::PasswordParams;
+
+/// The minimum number of characters a password must contain to be accepted by WorkOS.
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// An error returned when parameters fail local validation.
+///
+/// Local validation is opt-in and can be enabled with
+/// [`WorkOsBuilder::local_validation`](crate::WorkOsBuilder::local_validation).
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The password is shorter than the minimum length.
+    #[error("password must be at least {min_length} characters")]
+    PasswordTooShort {
+        /// The minimum number of characters a password must contain.
+        min_length: usize,
+    },
+}
+
+/// Validates that a password meets the minimum length WorkOS requires.
+pub(crate) fn validate_password(password: &str) -> Result<(), ValidationError> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(ValidationError::PasswordTooShort {
+            min_length: MIN_PASSWORD_LENGTH,
+        });
+    }
+
+    Ok(())
+}
+
+impl PasswordParams<'_> {
+    /// Validates the password against the minimum requirements enforced by WorkOS.
+    ///
+    /// Password hashes are not validated.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            PasswordParams::Password { password } => validate_password(password),
+            PasswordParams::PasswordHash { .. } => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "password-strength")]
+    #[test]
+    fn it_does_not_reject_passwords_the_strength_estimate_considers_weak() {
+        assert_eq!(
+            PasswordParams::Password {
+                password: "password123"
+            }
+            .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn it_rejects_short_passwords() {
+        assert_eq!(
+            PasswordParams::Password { password: "short" }.validate(),
+            Err(ValidationError::PasswordTooShort {
+                min_length: MIN_PASSWORD_LENGTH
+            })
+        );
+        assert_eq!(
+            PasswordParams::Password {
+                password: "i8uv6g34kd490s"
+            }
+            .validate(),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,15 @@
+mod create_object;
+mod delete_object;
+mod describe_object;
+mod get_object_url;
+mod list_objects;
+mod read_object;
+mod update_object;
+
+pub use create_object::*;
+pub use delete_object::*;
+pub use describe_object::*;
+pub use get_object_url::*;
+pub use list_objects::*;
+pub use read_object::*;
+pub use update_object::*;
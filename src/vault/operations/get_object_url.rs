@@ -0,0 +1,57 @@
+use url::{ParseError, Url};
+
+use crate::vault::{Vault, VaultObjectId};
+
+/// Builds the API URL of a [`VaultObject`](crate::vault::VaultObject).
+pub trait GetObjectUrl {
+    /// Returns the API URL of the object with the provided ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use url::ParseError;
+    /// # use workos_sdk::vault::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # fn run() -> Result<(), ParseError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let object_url = workos
+    ///     .vault()
+    ///     .get_object_url(&VaultObjectId::from("secret_01JD5KY2ZHY5B1V4AQTGY1YVMM"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_object_url(&self, object_id: &VaultObjectId) -> Result<Url, ParseError>;
+}
+
+impl GetObjectUrl for Vault<'_> {
+    fn get_object_url(&self, object_id: &VaultObjectId) -> Result<Url, ParseError> {
+        self.workos
+            .base_url()
+            .join(&format!("/vault/v1/kv/{object_id}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    #[test]
+    fn it_builds_an_object_url() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let object_url = workos
+            .vault()
+            .get_object_url(&VaultObjectId::from("secret_01JD5KY2ZHY5B1V4AQTGY1YVMM"))
+            .unwrap();
+
+        assert_eq!(
+            object_url,
+            Url::parse("https://api.workos.com/vault/v1/kv/secret_01JD5KY2ZHY5B1V4AQTGY1YVMM")
+                .unwrap()
+        )
+    }
+}
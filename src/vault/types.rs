@@ -0,0 +1,5 @@
+mod key_context;
+mod vault_object;
+
+pub use key_context::*;
+pub use vault_object::*;
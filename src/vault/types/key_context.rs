@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The key/value pairs that determine which encryption keys protect an object.
+///
+/// Objects created with the same context are encrypted with the same keys.
+///
+/// [WorkOS Docs: Key Context](https://workos.com/docs/vault/key-context)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyContext(pub HashMap<String, String>);
@@ -0,0 +1,88 @@
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::vault::KeyContext;
+use crate::{SecretString, Timestamp};
+
+/// The ID of a [`VaultObject`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct VaultObjectId(String);
+
+/// The ID of a version of a [`VaultObject`].
+///
+/// Pass it to [`UpdateObject`](crate::vault::UpdateObject) to only update the object if
+/// it has not changed in the meantime.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct VaultObjectVersion(String);
+
+/// The actor that last updated a [`VaultObject`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultActor {
+    /// The ID of the actor.
+    pub id: String,
+
+    /// The name of the actor.
+    pub name: String,
+}
+
+/// The metadata of a [`VaultObject`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultObjectMetadata {
+    /// The unique ID of the object.
+    pub id: VaultObjectId,
+
+    /// The context the object is encrypted with.
+    pub context: KeyContext,
+
+    /// The ID of the environment the object belongs to.
+    pub environment_id: String,
+
+    /// The ID of the key the object is encrypted with.
+    pub key_id: String,
+
+    /// The timestamp indicating when the object was last updated.
+    pub updated_at: Timestamp,
+
+    /// The actor that last updated the object.
+    pub updated_by: VaultActor,
+
+    /// The ID of the current version of the object.
+    pub version_id: VaultObjectVersion,
+}
+
+/// [WorkOS Docs: Vault Object](https://workos.com/docs/reference/vault/object)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultObject {
+    /// The unique ID of the object.
+    pub id: VaultObjectId,
+
+    /// The unique name of the object.
+    pub name: String,
+
+    /// The decrypted value of the object.
+    ///
+    /// Only present when the object is read, not when it is described.
+    pub value: Option<SecretString>,
+
+    /// The metadata of the object.
+    pub metadata: VaultObjectMetadata,
+}
+
+/// A summary of a [`VaultObject`], as returned when listing objects.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultObjectSummary {
+    /// The unique ID of the object.
+    pub id: VaultObjectId,
+
+    /// The unique name of the object.
+    pub name: String,
+
+    /// The timestamp indicating when the object was last updated.
+    pub updated_at: Option<Timestamp>,
+}
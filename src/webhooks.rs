@@ -2,6 +2,10 @@
 //!
 //! When implementing webhooks, be sure to reference the [Webhooks Best Practices](https://workos.com/docs/best-practices/webhooks) guide.
 
+#[cfg(all(feature = "axum", feature = "webhooks"))]
+pub mod axum;
 mod types;
+mod verification;
 
 pub use types::*;
+pub use verification::*;
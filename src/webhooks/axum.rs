@@ -0,0 +1,176 @@
+//! [Axum](https://docs.rs/axum) integration for receiving webhooks.
+//!
+//! Requires the `axum` and `webhooks` features.
+
+use axum::body::Bytes;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use thiserror::Error;
+
+use crate::webhooks::{
+    WEBHOOK_SIGNATURE_HEADER, Webhook, WebhookVerificationError, WebhookVerifier,
+};
+
+/// An extractor for a verified [`Webhook`].
+///
+/// The [`WebhookVerifier`] is taken from the router state. Requests without a valid
+/// signature are rejected with `401 Unauthorized`, and requests whose body is not a
+/// webhook are rejected with `400 Bad Request`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::Router;
+/// use axum::routing::post;
+/// use workos_sdk::webhooks::axum::WorkOsWebhook;
+/// use workos_sdk::webhooks::{WebhookEvent, WebhookVerifier};
+///
+/// async fn handle_webhook(WorkOsWebhook(webhook): WorkOsWebhook) {
+///     if let WebhookEvent::DirectoryUserCreated(user) = webhook.event {
+///         // ...
+///     }
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/webhooks/workos", post(handle_webhook))
+///     .with_state(WebhookVerifier::new("wh_secret_123456789"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct WorkOsWebhook(pub Webhook);
+
+/// The rejection returned when [`WorkOsWebhook`] cannot be extracted.
+#[derive(Debug, Error)]
+pub enum WorkOsWebhookRejection {
+    /// The request does not have a signature header.
+    #[error("missing {WEBHOOK_SIGNATURE_HEADER} header")]
+    MissingSignature,
+
+    /// The request body could not be read.
+    #[error("failed to read the request body")]
+    InvalidBody,
+
+    /// The webhook could not be verified.
+    #[error(transparent)]
+    Verification(#[from] WebhookVerificationError),
+}
+
+impl WorkOsWebhookRejection {
+    /// Returns the status code the request is rejected with.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::MissingSignature => StatusCode::UNAUTHORIZED,
+            Self::InvalidBody => StatusCode::BAD_REQUEST,
+            Self::Verification(WebhookVerificationError::InvalidPayload(_)) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::Verification(_) => StatusCode::UNAUTHORIZED,
+        }
+    }
+}
+
+impl IntoResponse for WorkOsWebhookRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+impl<S> FromRequest<S> for WorkOsWebhook
+where
+    WebhookVerifier: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = WorkOsWebhookRejection;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let signature = request
+            .headers()
+            .get(WEBHOOK_SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .ok_or(WorkOsWebhookRejection::MissingSignature)?;
+
+        let payload = Bytes::from_request(request, state)
+            .await
+            .map_err(|_| WorkOsWebhookRejection::InvalidBody)?;
+
+        let webhook = WebhookVerifier::from_ref(state).verify(&payload, &signature)?;
+
+        Ok(Self(webhook))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::body::Body;
+    use chrono::Utc;
+    use matches::assert_matches;
+
+    use crate::webhooks::WebhookId;
+    use crate::webhooks::verification::test::{SECRET, payload, signature};
+
+    use super::*;
+
+    fn request(payload: Vec<u8>, signature: Option<String>) -> Request {
+        let mut builder = Request::builder().method("POST").uri("/webhooks/workos");
+        if let Some(signature) = signature {
+            builder = builder.header(WEBHOOK_SIGNATURE_HEADER, signature);
+        }
+        builder.body(Body::from(payload)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_extracts_a_verified_webhook() {
+        let payload = payload();
+        let signature = signature(&payload, Utc::now());
+
+        let WorkOsWebhook(webhook) = WorkOsWebhook::from_request(
+            request(payload, Some(signature)),
+            &WebhookVerifier::new(SECRET),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(webhook.id, WebhookId::from("wh_01HYGAQG9DRK0R2FN6CB3JBG4T"))
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_request_without_a_signature() {
+        let rejection =
+            WorkOsWebhook::from_request(request(payload(), None), &WebhookVerifier::new(SECRET))
+                .await
+                .unwrap_err();
+
+        assert_matches!(rejection, WorkOsWebhookRejection::MissingSignature);
+        assert_eq!(rejection.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_invalid_signature_with_unauthorized() {
+        let signature = signature(b"{}", Utc::now());
+
+        let rejection = WorkOsWebhook::from_request(
+            request(payload(), Some(signature)),
+            &WebhookVerifier::new(SECRET),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_signed_body_that_is_not_a_webhook_with_bad_request() {
+        let payload = b"not json".to_vec();
+        let signature = signature(&payload, Utc::now());
+
+        let rejection = WorkOsWebhook::from_request(
+            request(payload, Some(signature)),
+            &WebhookVerifier::new(SECRET),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
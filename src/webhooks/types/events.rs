@@ -12,6 +12,18 @@ mod directory_group_user_removed;
 mod directory_user_created;
 mod directory_user_deleted;
 mod directory_user_updated;
+#[cfg(feature = "user-management")]
+mod organization_membership_created;
+#[cfg(feature = "user-management")]
+mod organization_membership_deleted;
+#[cfg(feature = "user-management")]
+mod organization_membership_updated;
+#[cfg(feature = "user-management")]
+mod user_created;
+#[cfg(feature = "user-management")]
+mod user_deleted;
+#[cfg(feature = "user-management")]
+mod user_updated;
 
 pub use connection_activated::*;
 pub use connection_deactivated::*;
@@ -27,3 +39,15 @@ pub use directory_group_user_removed::*;
 pub use directory_user_created::*;
 pub use directory_user_deleted::*;
 pub use directory_user_updated::*;
+#[cfg(feature = "user-management")]
+pub use organization_membership_created::*;
+#[cfg(feature = "user-management")]
+pub use organization_membership_deleted::*;
+#[cfg(feature = "user-management")]
+pub use organization_membership_updated::*;
+#[cfg(feature = "user-management")]
+pub use user_created::*;
+#[cfg(feature = "user-management")]
+pub use user_deleted::*;
+#[cfg(feature = "user-management")]
+pub use user_updated::*;
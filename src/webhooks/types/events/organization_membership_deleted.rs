@@ -0,0 +1,67 @@
+use serde::Deserialize;
+
+use crate::user_management::OrganizationMembership;
+
+/// [WorkOS Docs: `organization_membership.deleted` Webhook](https://workos.com/docs/events/organization-membership)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct OrganizationMembershipDeletedWebhook(pub OrganizationMembership);
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::organizations::OrganizationId;
+    use crate::user_management::{
+        OrganizationMembershipId, OrganizationMembershipStatus, OrganizationRole, UserId,
+    };
+    use crate::webhooks::{Webhook, WebhookEvent, WebhookId};
+    use crate::{Timestamp, Timestamps};
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_a_organization_membership_deleted_webhook() {
+        let webhook: Webhook = serde_json::from_str(
+            &json!({
+              "id": "wh_01HYGAQG9DRK0R2FN6CB3JBG4S",
+              "event": "organization_membership.deleted",
+              "data": {
+                "object": "organization_membership",
+                "id": "om_01E4ZCR3C56J083X43JQXF3JK5",
+                "user_id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+                "role": {
+                  "slug": "member"
+                },
+                "status": "active",
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+              }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            webhook,
+            Webhook {
+                id: WebhookId::from("wh_01HYGAQG9DRK0R2FN6CB3JBG4S"),
+                event: WebhookEvent::OrganizationMembershipDeleted(
+                    OrganizationMembershipDeletedWebhook(OrganizationMembership {
+                        id: OrganizationMembershipId::from("om_01E4ZCR3C56J083X43JQXF3JK5"),
+                        user_id: UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
+                        organization_id: OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5"),
+                        role: OrganizationRole {
+                            slug: "member".to_string()
+                        },
+                        status: OrganizationMembershipStatus::Active,
+                        timestamps: Timestamps {
+                            created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                            updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap()
+                        }
+                    })
+                )
+            }
+        )
+    }
+}
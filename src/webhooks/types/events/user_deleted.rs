@@ -0,0 +1,64 @@
+use serde::Deserialize;
+
+use crate::user_management::User;
+
+/// [WorkOS Docs: `user.deleted` Webhook](https://workos.com/docs/events/user)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct UserDeletedWebhook(pub User);
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::user_management::UserId;
+    use crate::webhooks::{Webhook, WebhookEvent, WebhookId};
+    use crate::{Timestamp, Timestamps};
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_a_user_deleted_webhook() {
+        let webhook: Webhook = serde_json::from_str(
+            &json!({
+              "id": "wh_01HYGAQG9DRK0R2FN6CB3JBG4P",
+              "event": "user.deleted",
+              "data": {
+                "object": "user",
+                "id": "user_01E4ZCR3C56J083X43JQXF3JK5",
+                "email": "marcelina.davis@example.com",
+                "first_name": "Marcelina",
+                "last_name": "Davis",
+                "email_verified": true,
+                "profile_picture_url": null,
+                "last_sign_in_at": null,
+                "created_at": "2021-06-25T19:07:33.155Z",
+                "updated_at": "2021-06-25T19:07:33.155Z"
+              }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            webhook,
+            Webhook {
+                id: WebhookId::from("wh_01HYGAQG9DRK0R2FN6CB3JBG4P"),
+                event: WebhookEvent::UserDeleted(UserDeletedWebhook(User {
+                    id: UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
+                    email: "marcelina.davis@example.com".to_string(),
+                    first_name: Some("Marcelina".to_string()),
+                    last_name: Some("Davis".to_string()),
+                    email_verified: true,
+                    profile_picture_url: None,
+                    last_sign_in_at: None,
+                    external_id: None,
+                    metadata: None,
+                    timestamps: Timestamps {
+                        created_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap(),
+                        updated_at: Timestamp::try_from("2021-06-25T19:07:33.155Z").unwrap()
+                    }
+                }))
+            }
+        )
+    }
+}
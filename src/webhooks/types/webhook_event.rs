@@ -1,64 +1,158 @@
-use serde::Deserialize;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
 
 use super::events::*;
 
-/// The event of a [`Webhook`](crate::webhooks::Webhook).
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
-#[serde(tag = "event", content = "data")]
-pub enum WebhookEvent {
-    /// [WorkOS Docs: `connection.activated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.activated)
-    #[serde(rename = "connection.activated")]
-    ConnectionActivated(ConnectionActivatedWebhook),
+macro_rules! webhook_events {
+    ($($(#[doc = $doc:literal])* $(#[cfg($cfg:meta)])? $variant:ident($payload:ty) => $name:literal,)*) => {
+        /// The event of a [`Webhook`](crate::webhooks::Webhook).
+        ///
+        /// Events that this version of the SDK does not know about, including those whose
+        /// feature is disabled, deserialize into [`Unknown`](WebhookEvent::Unknown), so new
+        /// event types do not break webhook handlers. Known events whose payload does not
+        /// match the expected shape fail to deserialize.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum WebhookEvent {
+            $(
+                $(#[doc = $doc])*
+                $(#[cfg($cfg)])?
+                $variant($payload),
+            )*
 
-    /// [WorkOS Docs: `connection.deactivated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.deactivated)
-    #[serde(rename = "connection.deactivated")]
-    ConnectionDeactivated(ConnectionDeactivatedWebhook),
+            /// An event that is not known to this version of the SDK.
+            Unknown {
+                /// The type of the event.
+                event: String,
 
-    /// [WorkOS Docs: `connection.deleted` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.deleted)
-    #[serde(rename = "connection.deleted")]
-    ConnectionDeleted(ConnectionDeletedWebhook),
+                /// The payload of the event.
+                data: Value,
+            },
+        }
 
-    /// [WorkOS Docs: `dsync.activated` Webhook](https://workos.com/docs/reference/webhooks/directory#webhooks-dsync.activated)
-    #[serde(rename = "dsync.activated")]
-    DirectoryActivated(DirectoryActivatedWebhook),
+        impl<'de> Deserialize<'de> for WebhookEvent {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                #[derive(Deserialize)]
+                struct TaggedEvent {
+                    event: String,
+                    data: Value,
+                }
 
-    /// [WorkOS Docs: `dsync.deactivated` Webhook](https://workos.com/docs/reference/webhooks/directory#webhooks-dsync.deactivated)
-    #[serde(rename = "dsync.deactivated")]
-    DirectoryDeactivated(DirectoryDeactivatedWebhook),
+                let TaggedEvent { event, data } = TaggedEvent::deserialize(deserializer)?;
 
-    /// [WorkOS Docs: `dsync.deleted` Webhook](https://workos.com/docs/reference/webhooks/directory#webhooks-dsync.deleted)
-    #[serde(rename = "dsync.deleted")]
-    DirectoryDeleted(DirectoryDeletedWebhook),
+                match event.as_str() {
+                    $(
+                        $(#[cfg($cfg)])?
+                        $name => <$payload>::deserialize(data)
+                            .map(Self::$variant)
+                            .map_err(|err| D::Error::custom(format!("invalid `{}` payload: {}", $name, err))),
+                    )*
+                    _ => Ok(Self::Unknown { event, data }),
+                }
+            }
+        }
+    };
+}
 
+webhook_events! {
+    /// [WorkOS Docs: `connection.activated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.activated)
+    ConnectionActivated(ConnectionActivatedWebhook) => "connection.activated",
+    /// [WorkOS Docs: `connection.deactivated` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.deactivated)
+    ConnectionDeactivated(ConnectionDeactivatedWebhook) => "connection.deactivated",
+    /// [WorkOS Docs: `connection.deleted` Webhook](https://workos.com/docs/reference/webhooks/connection#webhooks-sso.connection.deleted)
+    ConnectionDeleted(ConnectionDeletedWebhook) => "connection.deleted",
+    /// [WorkOS Docs: `dsync.activated` Webhook](https://workos.com/docs/reference/webhooks/directory#webhooks-dsync.activated)
+    DirectoryActivated(DirectoryActivatedWebhook) => "dsync.activated",
+    /// [WorkOS Docs: `dsync.deactivated` Webhook](https://workos.com/docs/reference/webhooks/directory#webhooks-dsync.deactivated)
+    DirectoryDeactivated(DirectoryDeactivatedWebhook) => "dsync.deactivated",
+    /// [WorkOS Docs: `dsync.deleted` Webhook](https://workos.com/docs/reference/webhooks/directory#webhooks-dsync.deleted)
+    DirectoryDeleted(DirectoryDeletedWebhook) => "dsync.deleted",
     /// [WorkOS Docs: `dsync.user.created` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.created)
-    #[serde(rename = "dsync.user.created")]
-    DirectoryUserCreated(DirectoryUserCreatedWebhook),
-
+    DirectoryUserCreated(DirectoryUserCreatedWebhook) => "dsync.user.created",
     /// [WorkOS Docs: `dsync.user.created` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.updated)
-    #[serde(rename = "dsync.user.updated")]
-    DirectoryUserUpdated(DirectoryUserUpdatedWebhook),
-
+    DirectoryUserUpdated(DirectoryUserUpdatedWebhook) => "dsync.user.updated",
     /// [WorkOS Docs: `dsync.user.deleted` Webhook](https://workos.com/docs/reference/webhooks/directory-user#webhooks-dsync.user.deleted)
-    #[serde(rename = "dsync.user.deleted")]
-    DirectoryUserDeleted(DirectoryUserDeletedWebhook),
-
+    DirectoryUserDeleted(DirectoryUserDeletedWebhook) => "dsync.user.deleted",
     /// [WorkOS Docs: `dsync.group.created` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.created)
-    #[serde(rename = "dsync.group.created")]
-    DirectoryGroupCreated(DirectoryGroupCreatedWebhook),
-
+    DirectoryGroupCreated(DirectoryGroupCreatedWebhook) => "dsync.group.created",
     /// [WorkOS Docs: `dsync.group.updated` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.updated)
-    #[serde(rename = "dsync.group.updated")]
-    DirectoryGroupUpdated(DirectoryGroupUpdatedWebhook),
-
+    DirectoryGroupUpdated(DirectoryGroupUpdatedWebhook) => "dsync.group.updated",
     /// [WorkOS Docs: `dsync.group.deleted` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.deleted)
-    #[serde(rename = "dsync.group.deleted")]
-    DirectoryGroupDeleted(DirectoryGroupDeletedWebhook),
-
+    DirectoryGroupDeleted(DirectoryGroupDeletedWebhook) => "dsync.group.deleted",
     /// [WorkOS Docs: `dsync.group.user_added` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.user_added)
-    #[serde(rename = "dsync.group.user_added")]
-    DirectoryUserAddedToGroup(DirectoryUserAddedToGroupWebhook),
-
+    DirectoryUserAddedToGroup(DirectoryUserAddedToGroupWebhook) => "dsync.group.user_added",
     /// [WorkOS Docs: `dsync.group.user_removed` Webhook](https://workos.com/docs/reference/webhooks/directory-group#webhooks-dsync.group.user_removed)
-    #[serde(rename = "dsync.group.user_removed")]
-    DirectoryUserRemovedFromGroup(DirectoryUserRemovedFromGroupWebhook),
+    DirectoryUserRemovedFromGroup(DirectoryUserRemovedFromGroupWebhook) => "dsync.group.user_removed",
+    /// [WorkOS Docs: `organization_membership.created` Webhook](https://workos.com/docs/events/organization-membership)
+    #[cfg(feature = "user-management")]
+    OrganizationMembershipCreated(OrganizationMembershipCreatedWebhook) => "organization_membership.created",
+    /// [WorkOS Docs: `organization_membership.updated` Webhook](https://workos.com/docs/events/organization-membership)
+    #[cfg(feature = "user-management")]
+    OrganizationMembershipUpdated(OrganizationMembershipUpdatedWebhook) => "organization_membership.updated",
+    /// [WorkOS Docs: `organization_membership.deleted` Webhook](https://workos.com/docs/events/organization-membership)
+    #[cfg(feature = "user-management")]
+    OrganizationMembershipDeleted(OrganizationMembershipDeletedWebhook) => "organization_membership.deleted",
+    /// [WorkOS Docs: `user.created` Webhook](https://workos.com/docs/events/user)
+    #[cfg(feature = "user-management")]
+    UserCreated(UserCreatedWebhook) => "user.created",
+    /// [WorkOS Docs: `user.updated` Webhook](https://workos.com/docs/events/user)
+    #[cfg(feature = "user-management")]
+    UserUpdated(UserUpdatedWebhook) => "user.updated",
+    /// [WorkOS Docs: `user.deleted` Webhook](https://workos.com/docs/events/user)
+    #[cfg(feature = "user-management")]
+    UserDeleted(UserDeletedWebhook) => "user.deleted",
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::webhooks::{Webhook, WebhookId};
+
+    use super::*;
+
+    #[test]
+    fn it_deserializes_an_unknown_webhook() {
+        let webhook: Webhook = serde_json::from_value(json!({
+            "id": "wh_01HYGAQG9DRK0R2FN6CB3JBG4T",
+            "event": "widget.exploded",
+            "data": {
+                "id": "widget_01HYGAQG9DRK0R2FN6CB3JBG4T"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            webhook,
+            Webhook {
+                id: WebhookId::from("wh_01HYGAQG9DRK0R2FN6CB3JBG4T"),
+                event: WebhookEvent::Unknown {
+                    event: "widget.exploded".to_string(),
+                    data: json!({
+                        "id": "widget_01HYGAQG9DRK0R2FN6CB3JBG4T"
+                    })
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn it_fails_to_deserialize_a_known_webhook_with_a_malformed_payload() {
+        let result = serde_json::from_value::<Webhook>(json!({
+            "id": "wh_01HYGAQG9DRK0R2FN6CB3JBG4T",
+            "event": "connection.activated",
+            "data": {
+                "id": 42
+            }
+        }));
+
+        let error = result.unwrap_err().to_string();
+        assert!(
+            error.contains("invalid `connection.activated` payload"),
+            "{error}"
+        );
+    }
 }
@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::webhooks::Webhook;
+use crate::{SecretString, constant_time_eq};
+
+/// The name of the header that carries the signature of a webhook.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "WorkOS-Signature";
+
+/// The default maximum age of a webhook, to protect against replay attacks.
+const DEFAULT_TOLERANCE: Duration = Duration::from_secs(180);
+
+/// An error returned from [`WebhookVerifier`].
+#[derive(Debug, Error)]
+pub enum WebhookVerificationError {
+    /// The signature header is not in the `t=<timestamp>, v1=<signature>` format.
+    #[error("malformed signature header")]
+    MalformedSignatureHeader,
+
+    /// The webhook was signed too long ago, or too far in the future.
+    #[error("webhook timestamp is outside the tolerance")]
+    TimestampOutsideTolerance,
+
+    /// The signature does not match the payload.
+    #[error("webhook signature does not match the payload")]
+    SignatureMismatch,
+
+    /// The payload is not a valid webhook.
+    #[error("invalid webhook payload: {0}")]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+/// Verifies the signatures of webhooks sent by WorkOS.
+///
+/// [WorkOS Docs: Webhooks](https://workos.com/docs/events/data-syncing/webhooks)
+///
+/// # Examples
+///
+/// ```
+/// use workos_sdk::webhooks::{WebhookVerificationError, WebhookVerifier};
+///
+/// # fn run(payload: &[u8], signature: &str) -> Result<(), WebhookVerificationError> {
+/// let verifier = WebhookVerifier::new("wh_secret_123456789");
+///
+/// let webhook = verifier.verify(payload, signature)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebhookVerifier {
+    secret: SecretString,
+    tolerance: Duration,
+}
+
+impl WebhookVerifier {
+    /// Returns a new [`WebhookVerifier`] for the provided webhook secret.
+    pub fn new(secret: impl Into<SecretString>) -> Self {
+        Self {
+            secret: secret.into(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Sets the maximum age of a webhook. Defaults to 3 minutes.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Verifies the signature of the payload and deserializes it into a [`Webhook`].
+    ///
+    /// `signature` is the value of the [`WEBHOOK_SIGNATURE_HEADER`] header.
+    pub fn verify(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<Webhook, WebhookVerificationError> {
+        self.verify_at(payload, signature, Utc::now())
+    }
+
+    /// Verifies the signature of the payload as of the provided time and deserializes it
+    /// into a [`Webhook`].
+    pub fn verify_at(
+        &self,
+        payload: &[u8],
+        signature: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Webhook, WebhookVerificationError> {
+        self.verify_signature(payload, signature, now)?;
+
+        Ok(serde_json::from_slice(payload)?)
+    }
+
+    fn verify_signature(
+        &self,
+        payload: &[u8],
+        signature: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), WebhookVerificationError> {
+        let (timestamp, signature) = parse_signature_header(signature)
+            .ok_or(WebhookVerificationError::MalformedSignatureHeader)?;
+
+        let signed_at = timestamp
+            .parse::<i64>()
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or(WebhookVerificationError::MalformedSignatureHeader)?;
+
+        let age = (now - signed_at).abs().to_std().unwrap_or(Duration::MAX);
+        if age > self.tolerance {
+            return Err(WebhookVerificationError::TimestampOutsideTolerance);
+        }
+
+        let expected = self.sign(timestamp, payload);
+        if !constant_time_eq(expected.as_bytes(), signature.as_bytes()) {
+            return Err(WebhookVerificationError::SignatureMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the hex-encoded signature of the payload for the provided timestamp.
+    fn sign(&self, timestamp: &str, payload: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.expose_secret().as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(timestamp.as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+/// Splits a `t=<timestamp>, v1=<signature>` header into its timestamp and signature.
+fn parse_signature_header(header: &str) -> Option<(&str, &str)> {
+    let mut timestamp = None;
+    let mut signature = None;
+
+    for part in header.split(',') {
+        match part.trim().split_once('=')? {
+            ("t", value) => timestamp = Some(value),
+            ("v1", value) => signature = Some(value),
+            _ => {}
+        }
+    }
+
+    Some((timestamp?, signature?))
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::webhooks::{WebhookEvent, WebhookId};
+
+    use super::*;
+
+    pub(crate) const SECRET: &str = "wh_secret_123456789";
+
+    pub(crate) fn payload() -> Vec<u8> {
+        json!({
+            "id": "wh_01HYGAQG9DRK0R2FN6CB3JBG4T",
+            "event": "widget.exploded",
+            "data": {}
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    pub(crate) fn signature(payload: &[u8], signed_at: DateTime<Utc>) -> String {
+        let timestamp = signed_at.timestamp_millis().to_string();
+        let signature = WebhookVerifier::new(SECRET).sign(&timestamp, payload);
+
+        format!("t={timestamp}, v1={signature}")
+    }
+
+    #[test]
+    fn it_verifies_a_signed_webhook() {
+        let now = Utc::now();
+        let payload = payload();
+
+        let webhook = WebhookVerifier::new(SECRET)
+            .verify_at(&payload, &signature(&payload, now), now)
+            .unwrap();
+
+        assert_eq!(webhook.id, WebhookId::from("wh_01HYGAQG9DRK0R2FN6CB3JBG4T"));
+        assert_matches!(webhook.event, WebhookEvent::Unknown { .. });
+    }
+
+    #[test]
+    fn it_rejects_a_tampered_payload() {
+        let now = Utc::now();
+        let signature = signature(&payload(), now);
+
+        assert_matches!(
+            WebhookVerifier::new(SECRET).verify_at(b"{}", &signature, now),
+            Err(WebhookVerificationError::SignatureMismatch)
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_webhook_signed_with_another_secret() {
+        let now = Utc::now();
+        let payload = payload();
+
+        assert_matches!(
+            WebhookVerifier::new("wh_secret_other").verify_at(
+                &payload,
+                &signature(&payload, now),
+                now
+            ),
+            Err(WebhookVerificationError::SignatureMismatch)
+        );
+    }
+
+    #[test]
+    fn it_rejects_an_old_webhook() {
+        let now = Utc::now();
+        let payload = payload();
+        let signature = signature(&payload, now - chrono::Duration::minutes(5));
+
+        assert_matches!(
+            WebhookVerifier::new(SECRET).verify_at(&payload, &signature, now),
+            Err(WebhookVerificationError::TimestampOutsideTolerance)
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_malformed_signature_header() {
+        assert_matches!(
+            WebhookVerifier::new(SECRET).verify(&payload(), "v1=abc"),
+            Err(WebhookVerificationError::MalformedSignatureHeader)
+        );
+    }
+}
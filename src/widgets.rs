@@ -0,0 +1,25 @@
+//! A module for interacting with the WorkOS Widgets API.
+//!
+//! [WorkOS Docs: Widgets](https://workos.com/docs/widgets)
+
+mod operations;
+mod types;
+
+pub use operations::*;
+pub use types::*;
+
+use crate::WorkOs;
+
+/// Widgets.
+///
+/// [WorkOS Docs: Widgets](https://workos.com/docs/widgets)
+pub struct Widgets<'a> {
+    workos: &'a WorkOs,
+}
+
+impl<'a> Widgets<'a> {
+    /// Returns a new [`Widgets`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self { workos }
+    }
+}
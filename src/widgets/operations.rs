@@ -0,0 +1,3 @@
+mod get_token;
+
+pub use get_token::*;
@@ -0,0 +1,5 @@
+mod widget_scope;
+mod widget_token;
+
+pub use widget_scope::*;
+pub use widget_token::*;
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// A permission granted to a widget token.
+///
+/// [WorkOS Docs: Widgets](https://workos.com/docs/widgets)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WidgetScope {
+    /// Allows the widget to manage the users of the organization.
+    #[serde(rename = "widgets:users-table:manage")]
+    UsersTableManage,
+
+    /// Allows the widget to manage the SSO connections of the organization.
+    #[serde(rename = "widgets:sso:manage")]
+    SsoManage,
+
+    /// Allows the widget to manage the domains of the organization.
+    #[serde(rename = "widgets:domain-verification:manage")]
+    DomainVerificationManage,
+}
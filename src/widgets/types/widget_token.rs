@@ -0,0 +1,9 @@
+use derive_more::{Deref, From};
+use serde::{Deserialize, Serialize};
+
+use crate::SecretString;
+
+/// A short-lived token that authorizes a frontend to render WorkOS widgets.
+#[derive(Clone, Debug, Deref, From, PartialEq, Eq, Serialize, Deserialize)]
+#[from(forward)]
+pub struct WidgetToken(SecretString);
@@ -1,34 +1,94 @@
-use std::time::Instant;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
 
-use reqwest::{RequestBuilder, Response, StatusCode, header::RETRY_AFTER};
+use reqwest::{Request, RequestBuilder, Response, StatusCode, header::RETRY_AFTER};
+use thiserror::Error;
 use url::{ParseError, Url};
 
+#[cfg(feature = "admin-portal")]
 use crate::admin_portal::AdminPortal;
+#[cfg(feature = "audit-logs")]
+use crate::audit_logs::AuditLogs;
 use crate::core::{
-    ResponseLogContext, extract_request_body, log_request, log_response_status,
-    log_response_success, sanitize_headers, store_response_context,
+    Instant, LoggedRequestBody, PendingAuditEntry, ResponseCapture, ResponseLogContext,
+    log_audit_sink_failure, log_request, log_response_status, log_response_success, log_retry,
+    sanitize_headers, sleep, spawn_blocking, store_response_context, warn_if_deprecated,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use crate::core::{
+    execute_with_backend, log_backend_request_failure, response_from_http, simulate_response,
+};
+#[cfg(feature = "directory-sync")]
 use crate::directory_sync::DirectorySync;
+#[cfg(feature = "events")]
+use crate::events::Events;
+#[cfg(feature = "fga")]
+use crate::fga::Fga;
+#[cfg(feature = "mfa")]
 use crate::mfa::Mfa;
+#[cfg(feature = "organization-domains")]
+use crate::organization_domains::OrganizationDomains;
+#[cfg(feature = "organizations")]
 use crate::organizations::Organizations;
+#[cfg(feature = "passwordless")]
 use crate::passwordless::Passwordless;
+#[cfg(feature = "roles")]
 use crate::roles::Roles;
+#[cfg(feature = "sso")]
 use crate::sso::Sso;
+#[cfg(feature = "user-management")]
 use crate::user_management::UserManagement;
-use crate::{ApiKey, WorkOsError, WorkOsResult};
+#[cfg(feature = "vault")]
+use crate::vault::Vault;
+#[cfg(feature = "widgets")]
+use crate::widgets::Widgets;
+use crate::{
+    ApiKey, AuditSink, ErrorClassification, OnRetry, RetryEvent, RetryPolicy, WithMetadata,
+    WorkOsError, WorkOsResult,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{DryRunResponder, HttpBackend, Middleware, RequestError, WithRaw};
 
 /// The WorkOS client.
+///
+/// The client is cheap to clone: all clones share the same configuration and
+/// connection pool.
 #[derive(Clone)]
 pub struct WorkOs {
+    inner: Arc<WorkOsInner>,
+}
+
+#[derive(Clone)]
+struct WorkOsInner {
     base_url: Url,
     key: ApiKey,
     client: reqwest::Client,
+    #[cfg(not(target_arch = "wasm32"))]
+    backend: Option<Arc<dyn HttpBackend>>,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    audit_actor: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    dry_run: Option<Arc<dyn DryRunResponder>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    middleware: Vec<Arc<dyn Middleware>>,
+    response_capture: Option<ResponseCapture>,
+    retry_policy: Option<RetryPolicy>,
+    on_retry: Option<Arc<OnRetry>>,
+    local_validation: bool,
+    timeout: Option<Duration>,
 }
 
 impl WorkOs {
     /// Returns a new instance of the WorkOS client using the provided API key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be initialized, e.g. because the TLS backend
+    /// cannot be loaded. Use [`WorkOs::builder`] to handle this as an error instead.
     pub fn new(key: &ApiKey) -> Self {
-        WorkOsBuilder::new(key).build()
+        #[allow(deprecated)]
+        WorkOsBuilder::new(key).build_unchecked()
     }
 
     /// Returns a [`WorkOsBuilder`] that may be used to construct a WorkOS client.
@@ -36,33 +96,325 @@ impl WorkOs {
         WorkOsBuilder::new(key)
     }
 
-    pub(crate) fn base_url(&self) -> &Url {
-        &self.base_url
+    /// Returns the base URL of the WorkOS API that requests are sent to.
+    pub fn base_url(&self) -> &Url {
+        &self.inner.base_url
     }
 
     pub(crate) fn key(&self) -> &ApiKey {
-        &self.key
+        &self.inner.key
     }
 
     pub(crate) fn client(&self) -> &reqwest::Client {
-        &self.client
+        &self.inner.client
+    }
+
+    #[cfg_attr(not(feature = "user-management"), allow(dead_code))]
+    pub(crate) fn local_validation(&self) -> bool {
+        self.inner.local_validation
+    }
+
+    /// Returns a client that attributes requests to the provided actor in audit entries.
+    ///
+    /// The returned client shares its configuration and connection pool with this one.
+    /// Has no effect unless an [`AuditSink`] was configured through
+    /// [`WorkOsBuilder::audit_sink`].
+    pub fn with_audit_actor(&self, actor: impl Into<String>) -> WorkOs {
+        let mut inner = (*self.inner).clone();
+        inner.audit_actor = Some(actor.into());
+
+        WorkOs {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Returns a client that sends each request once, without retrying it under the
+    /// [`RetryPolicy`].
+    ///
+    /// The returned client shares its configuration and connection pool with this one.
+    /// This is useful for requests that must not be repeated, or that are already retried
+    /// by the caller.
+    pub fn without_retries(&self) -> WorkOs {
+        let mut inner = (*self.inner).clone();
+        inner.retry_policy = None;
+
+        WorkOs {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Runs an operation and returns its result along with the raw response it was
+    /// deserialized from.
+    ///
+    /// The operation is given a client that shares its configuration and connection
+    /// pool with this one. If the operation sends more than one request, the raw
+    /// response is the last one received.
+    ///
+    /// This is useful for persisting or forwarding the exact payload returned by the
+    /// WorkOS API, including fields not yet known to this SDK, without sending a
+    /// second request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::organizations::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), GetOrganizationError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let organization = workos
+    ///     .with_raw(|workos| async move {
+    ///         workos
+    ///             .organizations()
+    ///             .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+    ///             .await
+    ///     })
+    ///     .await?;
+    ///
+    /// println!("{}", organization.body);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Not available on `wasm32`, where a response cannot be rebuilt once its body has
+    /// been read.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn with_raw<F, Fut, T, E>(&self, operation: F) -> WorkOsResult<WithRaw<T>, E>
+    where
+        F: FnOnce(WorkOs) -> Fut,
+        Fut: Future<Output = WorkOsResult<T, E>>,
+    {
+        let capture = ResponseCapture::raw();
+        let mut inner = (*self.inner).clone();
+        inner.response_capture = Some(capture.clone());
+
+        let value = operation(WorkOs {
+            inner: Arc::new(inner),
+        })
+        .await?;
+
+        capture.attach_raw(value).ok_or_else(|| {
+            WorkOsError::RequestError(RequestError::new(
+                "the operation completed without receiving a response",
+            ))
+        })
+    }
+
+    /// Runs an operation and returns its result along with metadata about the response
+    /// it came from, such as the WorkOS request ID.
+    ///
+    /// The operation is given a client that shares its configuration and connection
+    /// pool with this one. Metadata is returned for failed operations too, so the
+    /// request ID can be logged or included when contacting WorkOS support. If the
+    /// operation sends more than one request, the metadata is that of the last
+    /// response received.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::organizations::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), GetOrganizationError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let result = workos
+    ///     .with_metadata(|workos| async move {
+    ///         workos
+    ///             .organizations()
+    ///             .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+    ///             .await
+    ///     })
+    ///     .await;
+    ///
+    /// if let Some(request_id) = result.metadata.and_then(|metadata| metadata.request_id) {
+    ///     println!("WorkOS request ID: {request_id}");
+    /// }
+    ///
+    /// let organization = result.value?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_metadata<F, Fut, T, E>(
+        &self,
+        operation: F,
+    ) -> WithMetadata<WorkOsResult<T, E>>
+    where
+        F: FnOnce(WorkOs) -> Fut,
+        Fut: Future<Output = WorkOsResult<T, E>>,
+    {
+        let capture = ResponseCapture::metadata();
+        let mut inner = (*self.inner).clone();
+        inner.response_capture = Some(capture.clone());
+
+        let result = operation(WorkOs {
+            inner: Arc::new(inner),
+        })
+        .await;
+
+        capture.attach_metadata(result)
     }
 
     pub(crate) async fn send<E>(&self, builder: RequestBuilder) -> WorkOsResult<Response, E> {
-        let timer = Instant::now();
         let request = builder.build()?;
+
+        let Some(audit_sink) = &self.inner.audit_sink else {
+            return self
+                .execute_with_retries(request)
+                .await
+                .map_err(|err| err.map_operation(|never| match never {}));
+        };
+
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let pending = PendingAuditEntry::for_request(&request, self.inner.audit_actor.as_deref());
+
+        let result = self
+            .execute_with_retries(request)
+            .await
+            .map_err(|err| err.map_operation(|never| match never {}));
+
+        if let Some(pending) = pending {
+            let entry = match &result {
+                Ok(response) => pending.complete(Some(response), None),
+                Err(WorkOsError::RateLimited { .. }) => {
+                    pending.complete(None, Some(StatusCode::TOO_MANY_REQUESTS))
+                }
+                Err(_) => pending.complete(None, None),
+            };
+
+            let audit_sink = audit_sink.clone();
+            let recorded = spawn_blocking(move || audit_sink.record(&entry)).await;
+            if let Err(err) = recorded {
+                log_audit_sink_failure(method.as_str(), &url, err.as_ref());
+            }
+        }
+
+        result
+    }
+
+    async fn execute_with_retries(&self, request: Request) -> WorkOsResult<Response, Infallible> {
+        let Some(policy) = &self.inner.retry_policy else {
+            return self.execute(request).await;
+        };
+
+        let idempotent = crate::core::is_idempotent(&request);
+        let retry_rate_limits = !crate::core::rate_limits_retried_by_caller();
+        let mut attempt = 1;
+        loop {
+            let retry = match request.try_clone() {
+                Some(retry) if attempt < policy.max_attempts() => retry,
+                _ => return self.execute(request).await,
+            };
+
+            let result = self.execute(retry).await;
+            let (classification, status, retry_after) = match &result {
+                Ok(response) => (
+                    ErrorClassification::from_status(response.status()),
+                    Some(response.status()),
+                    None,
+                ),
+                Err(WorkOsError::RateLimited { retry_after }) => (
+                    ErrorClassification::Transient,
+                    Some(StatusCode::TOO_MANY_REQUESTS),
+                    *retry_after,
+                ),
+                Err(err) => (err.classification(), None, None),
+            };
+
+            let succeeded = matches!(&result, Ok(response) if response.status().is_success());
+            let rate_limited = status == Some(StatusCode::TOO_MANY_REQUESTS);
+            if succeeded
+                || (rate_limited && !retry_rate_limits)
+                || !(idempotent || rate_limited)
+                || !policy.should_retry(classification, status)
+            {
+                return result;
+            }
+            let Some(delay) = policy.retry_delay(attempt, retry_after) else {
+                return result;
+            };
+
+            let event = RetryEvent {
+                attempt,
+                delay,
+                classification,
+                status,
+                method: request.method(),
+                url: request.url(),
+            };
+            log_retry(&event);
+            if let Some(on_retry) = &self.inner.on_retry {
+                on_retry(&event);
+            }
+
+            sleep(event.delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn execute(&self, mut request: Request) -> WorkOsResult<Response, Infallible> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut intercepted = None;
+        #[cfg(not(target_arch = "wasm32"))]
+        for middleware in &self.inner.middleware {
+            if let Some(response) = middleware.before_request(&mut request).await {
+                intercepted = Some(response_from_http(response, request.url().clone()));
+                break;
+            }
+        }
+
+        let timer = Instant::now();
         let method = request.method().clone();
         let url = request.url().clone();
         let request_headers = sanitize_headers(request.headers());
-        let request_body = request.body().and_then(extract_request_body);
-        log_request(
-            method.as_str(),
-            &url,
-            &request_headers,
-            request_body.as_deref(),
-        );
+        let request_body = LoggedRequestBody::capture(&mut request);
+        log_request(method.as_str(), &url, &request_headers, &request_body);
+        #[cfg(feature = "debug-curl")]
+        crate::core::log_curl_command(&request);
+
+        // The `reqwest` client for `wasm32` has no client-wide timeout.
+        #[cfg(target_arch = "wasm32")]
+        if let Some(timeout) = self.inner.timeout {
+            request.timeout_mut().get_or_insert(timeout);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let simulated = intercepted.or_else(|| {
+            self.inner
+                .dry_run
+                .as_ref()
+                .and_then(|responder| simulate_response(responder.as_ref(), &request))
+        });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let result = match (simulated, &self.inner.backend) {
+            (Some(response), _) => Ok(response),
+            (None, Some(backend)) => {
+                match execute_with_backend(backend.as_ref(), request, self.inner.timeout).await {
+                    Ok(response) => Ok(response),
+                    Err(err) => {
+                        log_backend_request_failure(
+                            method.as_str(),
+                            &url,
+                            &request_headers,
+                            &request_body,
+                            timer.elapsed(),
+                            &err,
+                        );
+                        return Err(WorkOsError::RequestError(err));
+                    }
+                }
+            }
+            (None, None) => self.client().execute(request).await,
+        };
+        #[cfg(target_arch = "wasm32")]
+        let result = self.client().execute(request).await;
 
-        let mut response = match self.client.execute(request).await {
+        let mut response = match result {
             Ok(response) => response,
             Err(err) => {
                 let duration = timer.elapsed();
@@ -72,7 +424,7 @@ impl WorkOs {
                     method.as_str(),
                     &url,
                     &request_headers,
-                    request_body.as_deref(),
+                    &request_body,
                     duration,
                     &err,
                     &error_chain,
@@ -81,10 +433,19 @@ impl WorkOs {
                 return Err(WorkOsError::from(err));
             }
         };
+        #[cfg(not(target_arch = "wasm32"))]
+        for middleware in self.inner.middleware.iter().rev() {
+            middleware.after_response(&method, &mut response).await;
+        }
+
         let duration = timer.elapsed();
         let status = response.status();
         let response_headers = sanitize_headers(response.headers());
 
+        if let Some(capture) = &self.inner.response_capture {
+            response = capture.record(response, duration).await?;
+        }
+
         store_response_context(
             &mut response,
             ResponseLogContext {
@@ -95,6 +456,8 @@ impl WorkOs {
             },
         );
 
+        warn_if_deprecated(&method, &url, response.headers());
+
         if status.is_success() {
             log_response_success(method.as_str(), &url, status, &response_headers, duration);
         } else {
@@ -114,51 +477,108 @@ impl WorkOs {
         Ok(response)
     }
 
+    #[cfg(feature = "admin-portal")]
     /// Returns an [`AdminPortal`] instance.
     pub fn admin_portal(&self) -> AdminPortal<'_> {
         AdminPortal::new(self)
     }
 
+    #[cfg(feature = "audit-logs")]
+    /// Returns an [`AuditLogs`] instance.
+    pub fn audit_logs(&self) -> AuditLogs<'_> {
+        AuditLogs::new(self)
+    }
+
+    #[cfg(feature = "directory-sync")]
     /// Returns a [`DirectorySync`] instance.
     pub fn directory_sync(&self) -> DirectorySync<'_> {
         DirectorySync::new(self)
     }
 
+    #[cfg(feature = "events")]
+    /// Returns an [`Events`] instance.
+    pub fn events(&self) -> Events<'_> {
+        Events::new(self)
+    }
+
+    #[cfg(feature = "fga")]
+    /// Returns an [`Fga`] instance.
+    pub fn fga(&self) -> Fga<'_> {
+        Fga::new(self)
+    }
+
+    #[cfg(feature = "mfa")]
     /// Returns an [`Mfa`] instance.
     pub fn mfa(&self) -> Mfa<'_> {
         Mfa::new(self)
     }
 
+    #[cfg(feature = "organization-domains")]
+    /// Returns an [`OrganizationDomains`] instance.
+    pub fn organization_domains(&self) -> OrganizationDomains<'_> {
+        OrganizationDomains::new(self)
+    }
+
+    #[cfg(feature = "organizations")]
     /// Returns an [`Organizations`] instance.
     pub fn organizations(&self) -> Organizations<'_> {
         Organizations::new(self)
     }
 
+    #[cfg(feature = "passwordless")]
     /// Returns a [`Passwordless`] instance.
     pub fn passwordless(&self) -> Passwordless<'_> {
         Passwordless::new(self)
     }
 
+    #[cfg(feature = "roles")]
     /// Returns a [`Roles`] instance.
     pub fn roles(&self) -> Roles<'_> {
         Roles::new(self)
     }
 
+    #[cfg(feature = "sso")]
     /// Returns an [`Sso`] instance.
     pub fn sso(&self) -> Sso<'_> {
         Sso::new(self)
     }
 
+    #[cfg(feature = "user-management")]
     /// Returns a [`UserManagement`] instance.
     pub fn user_management(&self) -> UserManagement<'_> {
         UserManagement::new(self)
     }
+
+    #[cfg(feature = "vault")]
+    /// Returns a [`Vault`] instance.
+    pub fn vault(&self) -> Vault<'_> {
+        Vault::new(self)
+    }
+
+    #[cfg(feature = "widgets")]
+    /// Returns a [`Widgets`] instance.
+    pub fn widgets(&self) -> Widgets<'_> {
+        Widgets::new(self)
+    }
 }
 
 /// A builder for a WorkOS client.
 pub struct WorkOsBuilder<'a> {
     base_url: Url,
     key: &'a ApiKey,
+    #[cfg(not(target_arch = "wasm32"))]
+    backend: Option<Arc<dyn HttpBackend>>,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    dry_run: Option<Arc<dyn DryRunResponder>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    middleware: Vec<Arc<dyn Middleware>>,
+    retry_policy: Option<RetryPolicy>,
+    on_retry: Option<Arc<OnRetry>>,
+    local_validation: bool,
+    timeout: Option<Duration>,
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    connect_timeout: Option<Duration>,
 }
 
 impl<'a> WorkOsBuilder<'a> {
@@ -167,6 +587,18 @@ impl<'a> WorkOsBuilder<'a> {
         Self {
             base_url: Url::parse("https://api.workos.com").unwrap(),
             key,
+            #[cfg(not(target_arch = "wasm32"))]
+            backend: None,
+            audit_sink: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            dry_run: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            middleware: Vec::new(),
+            retry_policy: None,
+            on_retry: None,
+            local_validation: false,
+            timeout: None,
+            connect_timeout: None,
         }
     }
 
@@ -182,21 +614,163 @@ impl<'a> WorkOsBuilder<'a> {
         self
     }
 
+    /// Sets the [`HttpBackend`] used to send requests to the WorkOS API.
+    ///
+    /// Defaults to the client's own [`reqwest::Client`]. Not available on `wasm32`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn http_backend(mut self, backend: impl HttpBackend) -> Self {
+        self.backend = Some(Arc::new(backend));
+        self
+    }
+
+    /// Sets the [`AuditSink`] that records every mutating request made by the client.
+    ///
+    /// Use [`WorkOs::with_audit_actor`] to attribute requests to an actor.
+    pub fn audit_sink(mut self, sink: impl AuditSink) -> Self {
+        self.audit_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Enables dry-run mode, in which create, update, and delete requests are not sent to the
+    /// API and instead receive a simulated response from the provided [`DryRunResponder`].
+    ///
+    /// Read requests are still sent, as are requests that only authenticate or check access,
+    /// such as authenticating a user or checking an FGA warrant, so provisioning pipelines can
+    /// be exercised safely against a real environment.
+    ///
+    /// Not available on `wasm32`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn dry_run(mut self, responder: impl DryRunResponder) -> Self {
+        self.dry_run = Some(Arc::new(responder));
+        self
+    }
+
+    /// Installs a [`Middleware`] that runs around every request sent by the client.
+    ///
+    /// May be called multiple times to install several middleware, which run in the
+    /// order they were installed.
+    ///
+    /// Not available on `wasm32`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the [`RetryPolicy`] used to retry requests that failed with a transient error.
+    ///
+    /// Requests are not retried by default.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Registers a hook that is called before each retry made under the [`RetryPolicy`].
+    ///
+    /// The hook receives a [`RetryEvent`] describing the failed attempt, so applications can
+    /// log or alert on retry storms.
+    pub fn on_retry(mut self, hook: impl Fn(&RetryEvent<'_>) + Send + Sync + 'static) -> Self {
+        self.on_retry = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets whether parameters should be validated locally before being sent to the API.
+    ///
+    /// When enabled, inputs that would certainly be rejected by WorkOS, such as a malformed
+    /// email address, fail fast with a [`ValidationError`](crate::user_management::ValidationError)
+    /// instead of costing a request. Disabled by default.
+    pub fn local_validation(mut self, enabled: bool) -> Self {
+        self.local_validation = enabled;
+        self
+    }
+
+    /// Sets the total time allowed for each request, from sending it until the response
+    /// body has been received.
+    ///
+    /// A request that times out fails with a transient error, so it is retried under the
+    /// [`RetryPolicy`] if it is safe to repeat. Requests do not time out by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the time allowed for establishing a connection to the WorkOS API.
+    ///
+    /// Connections do not time out by default. This is not applied to a custom
+    /// [`HttpBackend`], which must apply its own connect timeout.
+    ///
+    /// Ignored on `wasm32`, where connections are managed by the JavaScript host.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
     /// Consumes the builder and returns the constructed client.
-    pub fn build(self) -> WorkOs {
-        let client = reqwest::Client::builder()
-            .user_agent(concat!("workos-rust/", env!("CARGO_PKG_VERSION")))
-            .build()
-            .unwrap();
+    ///
+    /// Returns an error if the HTTP client cannot be initialized, e.g. because the TLS
+    /// backend cannot be loaded or a proxy is misconfigured.
+    pub fn build(self) -> Result<WorkOs, BuildError> {
+        #[cfg_attr(target_arch = "wasm32", allow(unused_mut))]
+        let mut client = reqwest::Client::builder()
+            .user_agent(concat!("workos-rust/", env!("CARGO_PKG_VERSION")));
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(timeout) = self.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        let client = client.build().map_err(BuildError::HttpClient)?;
+
+        Ok(WorkOs {
+            inner: Arc::new(WorkOsInner {
+                base_url: self.base_url,
+                key: self.key.to_owned(),
+                client,
+                #[cfg(not(target_arch = "wasm32"))]
+                backend: self.backend,
+                audit_sink: self.audit_sink,
+                audit_actor: None,
+                #[cfg(not(target_arch = "wasm32"))]
+                dry_run: self.dry_run,
+                #[cfg(not(target_arch = "wasm32"))]
+                middleware: self.middleware,
+                response_capture: None,
+                retry_policy: self.retry_policy,
+                on_retry: self.on_retry,
+                local_validation: self.local_validation,
+                timeout: self.timeout,
+            }),
+        })
+    }
 
-        WorkOs {
-            base_url: self.base_url,
-            key: self.key.to_owned(),
-            client,
+    /// Consumes the builder and returns the constructed client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be initialized.
+    #[deprecated(
+        since = "0.3.0",
+        note = "use `build`, which returns an error instead of panicking"
+    )]
+    pub fn build_unchecked(self) -> WorkOs {
+        match self.build() {
+            Ok(workos) => workos,
+            Err(err) => panic!("failed to build WorkOS client: {err}"),
         }
     }
 }
 
+/// An error returned when building a [`WorkOs`] client.
+#[derive(Debug, Error)]
+pub enum BuildError {
+    /// The HTTP client could not be initialized, e.g. because the TLS backend could not
+    /// be loaded or a proxy is misconfigured.
+    #[error("failed to initialize the HTTP client")]
+    HttpClient(#[source] reqwest::Error),
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -207,7 +781,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url("https://auth.your-app.com")
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(
             workos.base_url(),
@@ -219,11 +794,21 @@ mod test {
     fn it_supports_setting_the_api_key_through_the_builder() {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .key(&ApiKey::from("sk_another_api_key"))
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(workos.key(), &ApiKey::from("sk_another_api_key"))
     }
 
+    #[test]
+    fn it_shares_internals_between_clones() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+        let clone = workos.clone();
+
+        assert!(Arc::ptr_eq(&workos.inner, &clone.inner));
+        assert_eq!(std::mem::size_of::<WorkOs>(), std::mem::size_of::<usize>())
+    }
+
     #[tokio::test]
     async fn it_sets_the_user_agent_header_on_the_client() {
         let mut server = mockito::Server::new_async().await;
@@ -231,7 +816,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/health")
@@ -258,7 +844,8 @@ mod test {
         let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
             .base_url(&server.url())
             .unwrap()
-            .build();
+            .build()
+            .unwrap();
 
         server
             .mock("GET", "/rate-limited")
@@ -277,4 +864,44 @@ mod test {
             }) if (value - 1.5).abs() < f32::EPSILON
         );
     }
+
+    #[tokio::test]
+    async fn it_classifies_a_refused_connection_as_transient() {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url("http://127.0.0.1:1")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let result = workos.send::<()>(workos.client().get(url)).await;
+
+        assert_eq!(
+            result.unwrap_err().classification(),
+            crate::ErrorClassification::Transient
+        );
+    }
+
+    #[tokio::test]
+    async fn it_times_out_requests_that_take_too_long() {
+        // Accepts connections but never responds.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&base_url)
+            .unwrap()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let url = workos.base_url().join("/health").unwrap();
+        let result = workos.send::<()>(workos.client().get(url)).await;
+
+        assert_eq!(
+            result.unwrap_err().classification(),
+            crate::ErrorClassification::Transient
+        );
+        drop(listener);
+    }
 }